@@ -3,7 +3,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use diedeadcode::analysis::Analyzer;
 use diedeadcode::config::Config;
-use std::path::PathBuf;
 use tempfile::TempDir;
 
 fn create_test_files(dir: &std::path::Path, count: usize) {
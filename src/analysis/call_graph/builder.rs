@@ -1,10 +1,15 @@
 //! Call graph builder coordinating parsing and semantic analysis.
 
-use super::references::{ImportInfo, ReferenceExtractor};
+use super::references::{Dependency, ImportInfo, ReferenceExtractor};
+use super::side_effects::{SideEffectCollector, SideEffectStatement};
+use super::strings::{StringCollector, StringUse};
 use super::symbols::SymbolExtractor;
 use crate::analysis::project::get_source_type;
 use crate::config::Config;
-use crate::core::{CallGraph, DddError, FileId, FileInfo, Result};
+use crate::core::{
+    CallGraph, DddError, DynamicPattern, DynamicPatternKind, FileId, FileInfo, ImportEdge, Result,
+    SideEffectRoot, SymbolId,
+};
 use dashmap::DashMap;
 use indicatif::ProgressBar;
 use oxc::allocator::Allocator;
@@ -46,7 +51,10 @@ pub fn build_call_graph(
         .collect();
 
     if let Some(pb) = progress {
-        pb.set_message(format!("Building call graph from {} files...", file_analyses.len()));
+        pb.set_message(format!(
+            "Building call graph from {} files...",
+            file_analyses.len()
+        ));
     }
 
     // Build the call graph from analyzed files
@@ -72,8 +80,12 @@ pub fn build_call_graph(
 
         // Resolve imports to symbols in other files
         for import in &analysis.imports {
-            if let Some(target_file_id) = resolve_import_path(&import.resolved_path, &path_to_file_id) {
-                if let Some(target_symbol_id) = graph.find_export(target_file_id, &import.imported_name) {
+            if let Some(target_file_id) =
+                resolve_import_path(&import.resolved_path, &path_to_file_id)
+            {
+                if let Some(target_symbol_id) =
+                    graph.find_export(target_file_id, &import.imported_name)
+                {
                     let reference = crate::core::SymbolReference {
                         from_id: import.local_symbol_id,
                         to_id: target_symbol_id,
@@ -85,6 +97,65 @@ pub fn build_call_graph(
                 }
             }
         }
+
+        // Link files in the module graph
+        for dependency in &analysis.dependencies {
+            if let Some(target_file_id) =
+                resolve_import_path(&dependency.resolved_path, &path_to_file_id)
+            {
+                graph.add_import_edge(ImportEdge {
+                    from: analysis.file_info.id,
+                    to: target_file_id,
+                    location: dependency.location.clone(),
+                    eager: dependency.is_eager(),
+                });
+            }
+        }
+
+        // Record side-effecting statements with the symbols they keep alive
+        for statement in &analysis.side_effects {
+            let mut references = statement.references.clone();
+            for binding in &statement.imports {
+                let target = resolve_import_path(&binding.resolved_path, &path_to_file_id)
+                    .and_then(|file_id| graph.find_export(file_id, &binding.imported_name));
+                if let Some(id) = target {
+                    if !references.contains(&id) {
+                        references.push(id);
+                    }
+                }
+            }
+
+            graph.add_side_effect_root(SideEffectRoot {
+                file_id: analysis.file_info.id,
+                location: statement.location.clone(),
+                references,
+                detected: statement.detected,
+            });
+        }
+    }
+
+    // Strings naming a symbol may access it dynamically
+    let mut symbols_by_name: HashMap<&str, Vec<SymbolId>> = HashMap::new();
+    for symbol in graph.symbols.values() {
+        symbols_by_name
+            .entry(symbol.name.as_str())
+            .or_default()
+            .push(symbol.id);
+    }
+    let mut patterns = Vec::new();
+    for analysis in &file_analyses {
+        for string in &analysis.strings {
+            if let Some(ids) = symbols_by_name.get(string.value.as_str()) {
+                patterns.push(DynamicPattern {
+                    kind: DynamicPatternKind::StringPropertyAccess,
+                    location: string.location.clone(),
+                    affected_symbols: ids.clone(),
+                });
+            }
+        }
+    }
+    for pattern in patterns {
+        graph.add_dynamic_pattern(pattern);
     }
 
     Ok(graph)
@@ -96,6 +167,9 @@ struct FileAnalysis {
     symbols: Vec<crate::core::TrackedSymbol>,
     references: Vec<crate::core::SymbolReference>,
     imports: Vec<ImportInfo>,
+    dependencies: Vec<Dependency>,
+    side_effects: Vec<SideEffectStatement>,
+    strings: Vec<StringUse>,
 }
 
 /// Analyze a single file.
@@ -105,8 +179,8 @@ fn analyze_file(
     symbol_id_counter: &AtomicU32,
     _config: &Config,
 ) -> Result<FileAnalysis> {
-    let source = std::fs::read_to_string(path)
-        .map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+    let source =
+        std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
 
     let source_type = get_source_type(path);
     let allocator = Allocator::default();
@@ -125,36 +199,42 @@ fn analyze_file(
     }
 
     // Build semantic information
-    let semantic_ret = SemanticBuilder::new()
-        .build(&parser_ret.program);
+    let semantic_ret = SemanticBuilder::new().build(&parser_ret.program);
 
     let semantic = semantic_ret.semantic;
 
     // Extract symbols
-    let symbol_extractor = SymbolExtractor::new(
-        path.to_path_buf(),
-        file_id,
-        symbol_id_counter,
-        &source,
-    );
-    let (symbols, symbol_map, has_side_effects) = symbol_extractor.extract(&parser_ret.program, &semantic);
+    let symbol_extractor =
+        SymbolExtractor::new(path.to_path_buf(), file_id, symbol_id_counter, &source);
+    let (mut symbols, symbol_map, suppressions) =
+        symbol_extractor.extract(&parser_ret.program, &semantic);
+
+    // Find top-level statements that run on load
+    let side_effects =
+        SideEffectCollector::new(path.to_path_buf(), &source, &semantic, &symbol_map)
+            .collect(&parser_ret.program);
+
+    for symbol in &mut symbols {
+        symbol.has_side_effects = side_effects.iter().any(|s| s.declared.contains(&symbol.id));
+    }
+
+    // Collect strings that could name symbols
+    let strings = StringCollector::new(path.to_path_buf(), &source).collect(&parser_ret.program);
 
     // Extract references
-    let reference_extractor = ReferenceExtractor::new(
-        path.to_path_buf(),
-        file_id,
-        &symbol_map,
-        &source,
-    );
-    let (references, imports, has_dynamic_eval) = reference_extractor.extract(&parser_ret.program, &semantic, path);
+    let reference_extractor =
+        ReferenceExtractor::new(path.to_path_buf(), file_id, &symbol_map, &source);
+    let (references, imports, dependencies, has_dynamic_eval) =
+        reference_extractor.extract(&parser_ret.program, &semantic, path);
 
     // Build file info
     let file_info = FileInfo {
         id: file_id,
         path: path.to_path_buf(),
-        has_side_effects,
+        has_side_effects: side_effects.iter().any(|s| s.detected),
         has_dynamic_eval,
         symbols: symbols.iter().map(|s| s.id).collect(),
+        suppressions,
     };
 
     Ok(FileAnalysis {
@@ -162,6 +242,9 @@ fn analyze_file(
         symbols,
         references,
         imports,
+        dependencies,
+        side_effects,
+        strings,
     })
 }
 
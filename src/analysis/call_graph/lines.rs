@@ -0,0 +1,56 @@
+//! Byte offset to line and column lookups.
+
+/// Line starts of a source file, so each lookup is a binary search rather
+/// than a scan from the start of the file.
+pub struct LineIndex<'a> {
+    source: &'a str,
+    /// Byte offset of each line start.
+    line_starts: Vec<u32>,
+}
+
+impl<'a> LineIndex<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(i, _)| i as u32 + 1))
+            .collect();
+
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    /// Get the 1-based line and column of a byte offset, counting columns in
+    /// characters.
+    pub fn line_col(&self, offset: u32) -> (u32, u32) {
+        let offset = offset.min(self.source.len() as u32);
+        let line = self.line_starts.partition_point(|&start| start <= offset);
+        let line_start = self.line_starts[line - 1] as usize;
+        let column = self
+            .source
+            .get(line_start..offset as usize)
+            .map_or(0, |prefix| prefix.chars().count());
+
+        (line as u32, column as u32 + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line_col() {
+        let lines = LineIndex::new("let a = 1;\nconst é = 'x';\n");
+
+        assert_eq!(lines.line_col(0), (1, 1));
+        assert_eq!(lines.line_col(4), (1, 5));
+        // The newline ends its line
+        assert_eq!(lines.line_col(10), (1, 11));
+        assert_eq!(lines.line_col(11), (2, 1));
+        // Columns count characters, not bytes
+        assert_eq!(lines.line_col(20), (2, 9));
+        assert_eq!(lines.line_col(27), (3, 1));
+        assert_eq!(lines.line_col(100), (3, 1));
+    }
+}
@@ -1,7 +1,10 @@
 //! Call graph construction from TypeScript/JavaScript files.
 
 mod builder;
+mod lines;
 mod references;
+mod side_effects;
+mod strings;
 mod symbols;
 
 pub use builder::build_call_graph;
@@ -1,5 +1,6 @@
 //! Reference extraction from AST.
 
+use super::lines::LineIndex;
 use crate::core::{FileId, Location, SymbolId, SymbolReference};
 use oxc::ast::ast::*;
 use oxc::ast_visit::walk;
@@ -12,9 +13,11 @@ use std::path::{Path, PathBuf};
 /// Extracts references between symbols from an AST.
 pub struct ReferenceExtractor<'a> {
     file_path: PathBuf,
-    source: &'a str,
+    lines: LineIndex<'a>,
     references: Vec<SymbolReference>,
     imports: Vec<ImportInfo>,
+    /// Every module this file loads, including bare and re-export imports.
+    dependencies: Vec<Dependency>,
     /// Whether dynamic eval was detected.
     pub has_dynamic_eval: bool,
 }
@@ -29,6 +32,24 @@ pub struct ImportInfo {
     pub location: Location,
 }
 
+/// A module loaded by a file, with the statement that loads it.
+#[derive(Debug, Clone)]
+pub struct Dependency {
+    pub resolved_path: PathBuf,
+    pub location: Location,
+    /// Loaded by `import()` rather than when the file is evaluated.
+    pub is_dynamic: bool,
+    /// An `import type` or `export type ... from`, erased at compile time.
+    pub type_only: bool,
+}
+
+impl Dependency {
+    /// Whether the module is loaded as soon as the importing file is evaluated.
+    pub fn is_eager(&self) -> bool {
+        !self.is_dynamic && !self.type_only
+    }
+}
+
 impl<'a> ReferenceExtractor<'a> {
     pub fn new(
         file_path: PathBuf,
@@ -38,9 +59,10 @@ impl<'a> ReferenceExtractor<'a> {
     ) -> Self {
         Self {
             file_path,
-            source,
+            lines: LineIndex::new(source),
             references: Vec::new(),
             imports: Vec::new(),
+            dependencies: Vec::new(),
             has_dynamic_eval: false,
         }
     }
@@ -50,49 +72,63 @@ impl<'a> ReferenceExtractor<'a> {
         program: &Program<'a>,
         _semantic: &Semantic<'a>,
         file_path: &Path,
-    ) -> (Vec<SymbolReference>, Vec<ImportInfo>, bool) {
-        // First, collect imports
+    ) -> (Vec<SymbolReference>, Vec<ImportInfo>, Vec<Dependency>, bool) {
+        // First, collect imports and the modules they load
         for stmt in &program.body {
-            if let Statement::ImportDeclaration(import) = stmt {
-                self.process_import(import, file_path);
+            let source = match stmt {
+                Statement::ImportDeclaration(import) => {
+                    self.process_import(import, file_path);
+                    Some((&import.source, import.span, import.import_kind))
+                }
+                Statement::ExportNamedDeclaration(export) => export
+                    .source
+                    .as_ref()
+                    .map(|source| (source, export.span, export.export_kind)),
+                Statement::ExportAllDeclaration(export) => {
+                    Some((&export.source, export.span, export.export_kind))
+                }
+                _ => None,
+            };
+            if let Some((source, span, kind)) = source {
+                self.add_dependency(Dependency {
+                    resolved_path: resolve_import_specifier(source.value.as_str(), file_path),
+                    location: self.span_to_location(span),
+                    is_dynamic: false,
+                    type_only: kind.is_type(),
+                });
             }
         }
 
         // Then walk the AST for references
         self.visit_program(program);
 
-        (self.references, self.imports, self.has_dynamic_eval)
-    }
-
-    fn span_to_location(&self, span: Span) -> Location {
-        let (line, column) = self.offset_to_line_col(span.start);
-        Location::new(
-            self.file_path.clone(),
-            span.start,
-            span.end,
-            line,
-            column,
+        (
+            self.references,
+            self.imports,
+            self.dependencies,
+            self.has_dynamic_eval,
         )
     }
 
-    fn offset_to_line_col(&self, offset: u32) -> (u32, u32) {
-        let offset = offset as usize;
-        let mut line = 1u32;
-        let mut col = 1u32;
-
-        for (i, ch) in self.source.char_indices() {
-            if i >= offset {
-                break;
-            }
-            if ch == '\n' {
-                line += 1;
-                col = 1;
-            } else {
-                col += 1;
+    fn add_dependency(&mut self, dependency: Dependency) {
+        let existing = self
+            .dependencies
+            .iter_mut()
+            .find(|d| d.resolved_path == dependency.resolved_path);
+        match existing {
+            // Keep the import that loads the module first
+            Some(existing) => {
+                if !existing.is_eager() && dependency.is_eager() {
+                    *existing = dependency;
+                }
             }
+            None => self.dependencies.push(dependency),
         }
+    }
 
-        (line, col)
+    fn span_to_location(&self, span: Span) -> Location {
+        let (line, column) = self.lines.line_col(span.start);
+        Location::new(self.file_path.clone(), span.start, span.end, line, column)
     }
 
     fn process_import(&mut self, import: &ImportDeclaration<'_>, file_path: &Path) {
@@ -148,7 +184,6 @@ impl<'a> ReferenceExtractor<'a> {
             }
         }
     }
-
 }
 
 impl<'a> Visit<'a> for ReferenceExtractor<'a> {
@@ -184,6 +219,12 @@ impl<'a> Visit<'a> for ReferenceExtractor<'a> {
         if let Expression::StringLiteral(lit) = &expr.source {
             let resolved = resolve_import_specifier(&lit.value, &self.file_path);
             let local_id = SymbolId::new(u32::MAX - self.imports.len() as u32);
+            self.add_dependency(Dependency {
+                resolved_path: resolved.clone(),
+                location: self.span_to_location(expr.span),
+                is_dynamic: true,
+                type_only: false,
+            });
 
             self.imports.push(ImportInfo {
                 local_symbol_id: local_id,
@@ -202,7 +243,10 @@ impl<'a> Visit<'a> for ReferenceExtractor<'a> {
 
     fn visit_computed_member_expression(&mut self, expr: &ComputedMemberExpression<'a>) {
         // Bracket access like obj[key] where key is not a literal
-        if !matches!(&expr.expression, Expression::StringLiteral(_) | Expression::NumericLiteral(_)) {
+        if !matches!(
+            &expr.expression,
+            Expression::StringLiteral(_) | Expression::NumericLiteral(_)
+        ) {
             // This is dynamic property access - reduces confidence
             // We note this but don't treat it as eval-level danger
         }
@@ -213,7 +257,7 @@ impl<'a> Visit<'a> for ReferenceExtractor<'a> {
         // JSX element usage creates a reference
         if let JSXElementName::Identifier(id) = &elem.opening_element.name {
             // Only uppercase identifiers are components
-            if id.name.chars().next().map_or(false, |c| c.is_uppercase()) {
+            if id.name.chars().next().is_some_and(|c| c.is_uppercase()) {
                 // This would be a reference to the component
                 // We'd need to resolve it through scope
             }
@@ -223,7 +267,7 @@ impl<'a> Visit<'a> for ReferenceExtractor<'a> {
 }
 
 /// Resolve an import specifier to a file path.
-fn resolve_import_specifier(specifier: &str, from_file: &Path) -> PathBuf {
+pub(super) fn resolve_import_specifier(specifier: &str, from_file: &Path) -> PathBuf {
     let from_dir = from_file.parent().unwrap_or(Path::new("."));
 
     if specifier.starts_with('.') {
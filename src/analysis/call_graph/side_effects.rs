@@ -0,0 +1,429 @@
+//! Statement-level side-effect analysis.
+//!
+//! Only top-level statements that do something observable when the module is
+//! loaded are treated as roots. Each root keeps alive exactly the symbols it
+//! references, so `const logger = createLogger()` keeps `createLogger` alive
+//! without making the rest of the file live. Statements that run on load but
+//! look pure are collected too, for packages whose `sideEffects` field says
+//! the file has side effects anyway.
+
+use super::lines::LineIndex;
+use super::references::resolve_import_specifier;
+use crate::core::{Location, SymbolId};
+use oxc::ast::ast::*;
+use oxc::ast_visit::Visit;
+use oxc::semantic::{Scoping, Semantic};
+use oxc::span::{GetSpan, Span};
+use oxc::syntax::operator::UnaryOperator;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// A top-level statement that runs when the module is loaded.
+#[derive(Debug, Clone)]
+pub struct SideEffectStatement {
+    pub location: Location,
+    /// Whether the statement has side effects syntactically.
+    pub detected: bool,
+    /// Local symbols referenced by the statement.
+    pub references: Vec<SymbolId>,
+    /// Imported bindings referenced by the statement.
+    pub imports: Vec<ImportBinding>,
+    /// Symbols declared with a side-effecting initializer.
+    pub declared: Vec<SymbolId>,
+}
+
+/// An imported binding, resolved later against the target file's exports.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportBinding {
+    pub imported_name: String,
+    pub resolved_path: PathBuf,
+}
+
+/// Collects side-effecting top-level statements from a module.
+pub struct SideEffectCollector<'s, 'a> {
+    file_path: PathBuf,
+    lines: LineIndex<'a>,
+    scoping: &'s Scoping,
+    symbol_map: &'s HashMap<oxc::semantic::SymbolId, SymbolId>,
+    import_bindings: HashMap<oxc::semantic::SymbolId, ImportBinding>,
+}
+
+impl<'s, 'a> SideEffectCollector<'s, 'a> {
+    pub fn new(
+        file_path: PathBuf,
+        source: &'a str,
+        semantic: &'s Semantic<'a>,
+        symbol_map: &'s HashMap<oxc::semantic::SymbolId, SymbolId>,
+    ) -> Self {
+        Self {
+            file_path,
+            lines: LineIndex::new(source),
+            scoping: semantic.scoping(),
+            symbol_map,
+            import_bindings: HashMap::new(),
+        }
+    }
+
+    /// Collect every statement that runs on load, with or without detected
+    /// side effects. Declarations that only bind a name are left out.
+    pub fn collect(mut self, program: &Program<'a>) -> Vec<SideEffectStatement> {
+        for stmt in &program.body {
+            if let Statement::ImportDeclaration(import) = stmt {
+                self.record_import_bindings(import);
+            }
+        }
+
+        let mut statements = Vec::new();
+        for stmt in &program.body {
+            if let Some(statement) = self.analyze_statement(stmt) {
+                statements.push(statement);
+            }
+        }
+        statements
+    }
+
+    fn record_import_bindings(&mut self, import: &ImportDeclaration<'a>) {
+        let resolved_path = resolve_import_specifier(import.source.value.as_str(), &self.file_path);
+
+        for specifier in import.specifiers.iter().flatten() {
+            let (local, imported_name) = match specifier {
+                ImportDeclarationSpecifier::ImportSpecifier(spec) => {
+                    (&spec.local, spec.imported.name().to_string())
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(spec) => {
+                    (&spec.local, "default".to_string())
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => {
+                    (&spec.local, "*".to_string())
+                }
+            };
+
+            if let Some(symbol_id) = local.symbol_id.get() {
+                self.import_bindings.insert(
+                    symbol_id,
+                    ImportBinding {
+                        imported_name,
+                        resolved_path: resolved_path.clone(),
+                    },
+                );
+            }
+        }
+    }
+
+    fn analyze_statement(&self, stmt: &Statement<'a>) -> Option<SideEffectStatement> {
+        match stmt {
+            Statement::ExpressionStatement(expr_stmt) => {
+                let mut refs = self.reference_collector();
+                refs.visit_expression(&expr_stmt.expression);
+                let detected = expression_has_side_effects(&expr_stmt.expression);
+                Some(self.statement(expr_stmt.span, detected, refs, Vec::new()))
+            }
+            Statement::VariableDeclaration(decl) => self.analyze_variable_declaration(decl),
+            Statement::ExportNamedDeclaration(export) => match &export.declaration {
+                Some(Declaration::VariableDeclaration(decl)) => {
+                    self.analyze_variable_declaration(decl)
+                }
+                _ => None,
+            },
+            Statement::ExportDefaultDeclaration(export) => {
+                let expr = export.declaration.as_expression()?;
+                let mut refs = self.reference_collector();
+                refs.visit_expression(expr);
+                let detected = expression_has_side_effects(expr);
+                Some(self.statement(export.span, detected, refs, Vec::new()))
+            }
+            // Control flow at module level runs on load; treat it as a root
+            Statement::BlockStatement(_)
+            | Statement::DoWhileStatement(_)
+            | Statement::ForInStatement(_)
+            | Statement::ForOfStatement(_)
+            | Statement::ForStatement(_)
+            | Statement::IfStatement(_)
+            | Statement::LabeledStatement(_)
+            | Statement::SwitchStatement(_)
+            | Statement::ThrowStatement(_)
+            | Statement::TryStatement(_)
+            | Statement::WhileStatement(_)
+            | Statement::WithStatement(_) => {
+                let mut refs = self.reference_collector();
+                refs.visit_statement(stmt);
+                Some(self.statement(stmt.span(), true, refs, Vec::new()))
+            }
+            _ => None,
+        }
+    }
+
+    fn analyze_variable_declaration(
+        &self,
+        decl: &VariableDeclaration<'a>,
+    ) -> Option<SideEffectStatement> {
+        let mut refs = self.reference_collector();
+        let mut declared = Vec::new();
+        let mut has_effects = false;
+
+        let inits: Vec<_> = decl
+            .declarations
+            .iter()
+            .filter_map(|d| d.init.as_ref())
+            .collect();
+        if inits.is_empty() {
+            return None;
+        }
+
+        for declarator in &decl.declarations {
+            if let Some(init) = &declarator.init {
+                if expression_has_side_effects(init) {
+                    has_effects = true;
+                    refs.visit_expression(init);
+                    for binding in declarator.id.get_binding_identifiers() {
+                        if let Some(id) = binding
+                            .symbol_id
+                            .get()
+                            .and_then(|s| self.symbol_map.get(&s))
+                        {
+                            declared.push(*id);
+                        }
+                    }
+                }
+            }
+        }
+
+        if !has_effects {
+            // Nothing detected: the statement only runs its initializers
+            for init in inits {
+                refs.visit_expression(init);
+            }
+        }
+        Some(self.statement(decl.span, has_effects, refs, declared))
+    }
+
+    fn reference_collector(&self) -> ReferenceCollector<'_> {
+        ReferenceCollector {
+            scoping: self.scoping,
+            symbol_map: self.symbol_map,
+            import_bindings: &self.import_bindings,
+            references: Vec::new(),
+            imports: Vec::new(),
+        }
+    }
+
+    fn statement(
+        &self,
+        span: Span,
+        detected: bool,
+        refs: ReferenceCollector<'_>,
+        declared: Vec<SymbolId>,
+    ) -> SideEffectStatement {
+        SideEffectStatement {
+            location: self.span_to_location(span),
+            detected,
+            references: refs.references,
+            imports: refs.imports,
+            declared,
+        }
+    }
+
+    fn span_to_location(&self, span: Span) -> Location {
+        let (line, column) = self.lines.line_col(span.start);
+        Location::new(self.file_path.clone(), span.start, span.end, line, column)
+    }
+}
+
+/// Collects the symbols referenced within a subtree.
+struct ReferenceCollector<'s> {
+    scoping: &'s Scoping,
+    symbol_map: &'s HashMap<oxc::semantic::SymbolId, SymbolId>,
+    import_bindings: &'s HashMap<oxc::semantic::SymbolId, ImportBinding>,
+    references: Vec<SymbolId>,
+    imports: Vec<ImportBinding>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|r| self.scoping.get_reference(r).symbol_id())
+        else {
+            return;
+        };
+
+        if let Some(&id) = self.symbol_map.get(&symbol_id) {
+            if !self.references.contains(&id) {
+                self.references.push(id);
+            }
+        } else if let Some(binding) = self.import_bindings.get(&symbol_id) {
+            if !self.imports.contains(binding) {
+                self.imports.push(binding.clone());
+            }
+        }
+    }
+}
+
+/// Check whether evaluating an expression has observable side effects.
+///
+/// Calls and `new` expressions annotated with `/*#__PURE__*/` are treated as
+/// pure, matching bundler semantics; their arguments are still checked.
+pub fn expression_has_side_effects(expr: &Expression<'_>) -> bool {
+    match expr {
+        Expression::CallExpression(call) => {
+            !call.pure || call.arguments.iter().any(argument_has_side_effects)
+        }
+        Expression::NewExpression(new) => {
+            !new.pure || new.arguments.iter().any(argument_has_side_effects)
+        }
+        Expression::AssignmentExpression(_)
+        | Expression::UpdateExpression(_)
+        | Expression::AwaitExpression(_)
+        | Expression::YieldExpression(_)
+        | Expression::TaggedTemplateExpression(_)
+        | Expression::ImportExpression(_)
+        | Expression::ChainExpression(_) => true,
+        Expression::UnaryExpression(unary) => {
+            unary.operator == UnaryOperator::Delete || expression_has_side_effects(&unary.argument)
+        }
+        Expression::ParenthesizedExpression(paren) => {
+            expression_has_side_effects(&paren.expression)
+        }
+        Expression::SequenceExpression(seq) => {
+            seq.expressions.iter().any(expression_has_side_effects)
+        }
+        Expression::ConditionalExpression(cond) => {
+            expression_has_side_effects(&cond.test)
+                || expression_has_side_effects(&cond.consequent)
+                || expression_has_side_effects(&cond.alternate)
+        }
+        Expression::LogicalExpression(logical) => {
+            expression_has_side_effects(&logical.left)
+                || expression_has_side_effects(&logical.right)
+        }
+        Expression::BinaryExpression(binary) => {
+            expression_has_side_effects(&binary.left) || expression_has_side_effects(&binary.right)
+        }
+        Expression::TemplateLiteral(template) => {
+            template.expressions.iter().any(expression_has_side_effects)
+        }
+        Expression::ArrayExpression(array) => array.elements.iter().any(|element| match element {
+            ArrayExpressionElement::SpreadElement(_) => true,
+            ArrayExpressionElement::Elision(_) => false,
+            _ => element
+                .as_expression()
+                .is_some_and(expression_has_side_effects),
+        }),
+        Expression::ObjectExpression(object) => {
+            object.properties.iter().any(|property| match property {
+                ObjectPropertyKind::ObjectProperty(prop) => {
+                    expression_has_side_effects(&prop.value)
+                        || prop
+                            .key
+                            .as_expression()
+                            .is_some_and(expression_has_side_effects)
+                }
+                ObjectPropertyKind::SpreadProperty(_) => true,
+            })
+        }
+        _ => false,
+    }
+}
+
+fn argument_has_side_effects(argument: &Argument<'_>) -> bool {
+    match argument {
+        Argument::SpreadElement(_) => true,
+        _ => argument
+            .as_expression()
+            .is_some_and(expression_has_side_effects),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::call_graph::symbols::SymbolExtractor;
+    use crate::core::{FileId, TrackedSymbol};
+    use oxc::allocator::Allocator;
+    use oxc::parser::Parser;
+    use oxc::semantic::SemanticBuilder;
+    use std::sync::atomic::AtomicU32;
+
+    fn collect_all(source: &str) -> (Vec<SideEffectStatement>, Vec<TrackedSymbol>) {
+        let allocator = Allocator::default();
+        let path = PathBuf::from("/project/src/test.ts");
+        let program = Parser::new(&allocator, source, oxc::span::SourceType::ts())
+            .parse()
+            .program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+
+        let counter = AtomicU32::new(0);
+        let (symbols, symbol_map, _) =
+            SymbolExtractor::new(path.clone(), FileId::new(0), &counter, source)
+                .extract(&program, &semantic);
+        let statements =
+            SideEffectCollector::new(path, source, &semantic, &symbol_map).collect(&program);
+
+        (statements, symbols)
+    }
+
+    fn collect(source: &str) -> (Vec<SideEffectStatement>, Vec<TrackedSymbol>) {
+        let (statements, symbols) = collect_all(source);
+        (
+            statements.into_iter().filter(|s| s.detected).collect(),
+            symbols,
+        )
+    }
+
+    fn symbol_id(symbols: &[TrackedSymbol], name: &str) -> SymbolId {
+        symbols.iter().find(|s| s.name == name).unwrap().id
+    }
+
+    #[test]
+    fn test_initializer_call_keeps_callee_alive() {
+        let (statements, symbols) = collect(
+            "function createLogger() {}\nfunction unused() {}\nconst logger = createLogger();",
+        );
+
+        assert_eq!(statements.len(), 1);
+        assert_eq!(
+            statements[0].references,
+            vec![symbol_id(&symbols, "createLogger")]
+        );
+        assert_eq!(statements[0].declared, vec![symbol_id(&symbols, "logger")]);
+        assert_eq!(statements[0].location.line, 3);
+    }
+
+    #[test]
+    fn test_pure_annotation_is_not_a_side_effect() {
+        let (statements, _) = collect("function create() {}\nconst x = /*#__PURE__*/ create();");
+        assert!(statements.is_empty());
+    }
+
+    #[test]
+    fn test_declarations_without_effects() {
+        let (statements, _) = collect("function f() {}\nconst y = 1;\nconst z = () => f();");
+        assert!(statements.is_empty());
+    }
+
+    #[test]
+    fn test_collect_all_includes_undetected_statements() {
+        let (statements, symbols) =
+            collect_all("function f() {}\nfunction g() {}\nconst y = f;\nf();\ntype T = number;");
+
+        assert_eq!(statements.len(), 2);
+        assert!(!statements[0].detected);
+        assert_eq!(statements[0].references, vec![symbol_id(&symbols, "f")]);
+        assert!(statements[1].detected);
+    }
+
+    #[test]
+    fn test_imported_call_records_binding() {
+        let (statements, _) = collect("import { setup } from './setup';\nsetup();");
+
+        assert_eq!(statements.len(), 1);
+        assert_eq!(
+            statements[0].imports,
+            vec![ImportBinding {
+                imported_name: "setup".to_string(),
+                resolved_path: PathBuf::from("/project/src/./setup"),
+            }]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+//! String literal collection.
+//!
+//! Names used as strings, like `handlers['onSave']` or
+//! `registry.get('PaymentService')`, can keep symbols alive in ways the
+//! reference graph cannot see. Only strings that are valid identifiers are
+//! kept, since only those can name a symbol.
+
+use super::lines::LineIndex;
+use crate::core::Location;
+use oxc::ast::ast::*;
+use oxc::ast_visit::walk;
+use oxc::ast_visit::Visit;
+use oxc::span::Span;
+use std::path::PathBuf;
+
+/// A string literal or static template part that could name a symbol.
+#[derive(Debug, Clone)]
+pub struct StringUse {
+    pub value: String,
+    pub location: Location,
+}
+
+/// Collects identifier-like strings from a module.
+pub struct StringCollector<'a> {
+    file_path: PathBuf,
+    lines: LineIndex<'a>,
+    strings: Vec<StringUse>,
+}
+
+impl<'a> StringCollector<'a> {
+    pub fn new(file_path: PathBuf, source: &'a str) -> Self {
+        Self {
+            file_path,
+            lines: LineIndex::new(source),
+            strings: Vec::new(),
+        }
+    }
+
+    pub fn collect(mut self, program: &Program<'a>) -> Vec<StringUse> {
+        self.visit_program(program);
+        self.strings
+    }
+
+    fn add(&mut self, value: &str, span: Span) {
+        if is_identifier(value) {
+            let location = self.span_to_location(span);
+            self.strings.push(StringUse {
+                value: value.to_string(),
+                location,
+            });
+        }
+    }
+
+    fn span_to_location(&self, span: Span) -> Location {
+        let (line, column) = self.lines.line_col(span.start);
+        Location::new(self.file_path.clone(), span.start, span.end, line, column)
+    }
+}
+
+impl<'a> Visit<'a> for StringCollector<'a> {
+    fn visit_string_literal(&mut self, lit: &StringLiteral<'a>) {
+        self.add(&lit.value, lit.span);
+    }
+
+    fn visit_template_literal(&mut self, lit: &TemplateLiteral<'a>) {
+        for quasi in &lit.quasis {
+            if let Some(cooked) = &quasi.value.cooked {
+                self.add(cooked, quasi.span);
+            }
+        }
+        walk::walk_template_literal(self, lit);
+    }
+
+    // Module specifiers, directives and literal types never name a symbol at runtime
+
+    fn visit_import_declaration(&mut self, _decl: &ImportDeclaration<'a>) {}
+
+    fn visit_export_all_declaration(&mut self, _decl: &ExportAllDeclaration<'a>) {}
+
+    fn visit_export_named_declaration(&mut self, decl: &ExportNamedDeclaration<'a>) {
+        if let Some(declaration) = &decl.declaration {
+            self.visit_declaration(declaration);
+        }
+    }
+
+    fn visit_directive(&mut self, _directive: &Directive<'a>) {}
+
+    fn visit_ts_literal_type(&mut self, _ty: &TSLiteralType<'a>) {}
+}
+
+/// Check whether a string is a valid JavaScript identifier.
+fn is_identifier(value: &str) -> bool {
+    let mut chars = value.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc::allocator::Allocator;
+    use oxc::parser::Parser;
+    use oxc::span::SourceType;
+
+    fn collect(source: &str) -> Vec<StringUse> {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::ts())
+            .parse()
+            .program;
+        StringCollector::new(PathBuf::from("test.ts"), source).collect(&program)
+    }
+
+    #[test]
+    fn test_collects_identifier_strings() {
+        let source = "'use strict';\nimport { a } from 'lib';\ntype T = 'Skipped';\nhandlers['onSave']();\nregistry.get(`PaymentService`);\nconst msg = 'not an identifier';\nconst key = `on${name}Click`;\n";
+        let strings = collect(source);
+
+        let values: Vec<_> = strings.iter().map(|s| s.value.as_str()).collect();
+        assert_eq!(values, ["onSave", "PaymentService", "on", "Click"]);
+        assert_eq!(strings[0].location.line, 4);
+        assert_eq!(strings[0].location.column, 10);
+    }
+}
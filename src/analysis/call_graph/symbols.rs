@@ -1,11 +1,14 @@
 //! Symbol extraction from AST.
 
-use crate::core::{FileId, Location, SymbolId, SymbolKind, TrackedSymbol};
+use super::lines::LineIndex;
+use crate::core::{
+    DocTags, FileId, Location, Suppression, SuppressionKind, SymbolId, SymbolKind, TrackedSymbol,
+};
 use oxc::ast::ast::*;
 use oxc::ast_visit::walk;
 use oxc::ast_visit::Visit;
 use oxc::semantic::Semantic;
-use oxc::span::Span;
+use oxc::span::{GetSpan, Span};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -16,11 +19,10 @@ pub struct SymbolExtractor<'a> {
     file_id: FileId,
     symbol_id_counter: &'a AtomicU32,
     source: &'a str,
+    lines: LineIndex<'a>,
     symbols: Vec<TrackedSymbol>,
     /// Map from oxc symbol ID to our symbol ID.
     pub symbol_map: HashMap<oxc::semantic::SymbolId, SymbolId>,
-    /// Whether side effects were detected.
-    pub has_side_effects: bool,
     /// Current scope depth (0 = module level).
     scope_depth: u32,
 }
@@ -37,9 +39,9 @@ impl<'a> SymbolExtractor<'a> {
             file_id,
             symbol_id_counter,
             source,
+            lines: LineIndex::new(source),
             symbols: Vec::new(),
             symbol_map: HashMap::new(),
-            has_side_effects: false,
             scope_depth: 0,
         }
     }
@@ -47,51 +49,175 @@ impl<'a> SymbolExtractor<'a> {
     pub fn extract(
         mut self,
         program: &Program<'a>,
-        _semantic: &Semantic<'a>,
-    ) -> (Vec<TrackedSymbol>, HashMap<oxc::semantic::SymbolId, SymbolId>, bool) {
+        semantic: &Semantic<'a>,
+    ) -> (
+        Vec<TrackedSymbol>,
+        HashMap<oxc::semantic::SymbolId, SymbolId>,
+        Vec<Suppression>,
+    ) {
         self.visit_program(program);
+        self.map_semantic_symbols(semantic);
+        self.apply_doc_tags(program);
+        let suppressions = self.apply_suppressions(program);
         // Note: Decorator detection would require additional AST traversal
-        (self.symbols, self.symbol_map, self.has_side_effects)
+        (self.symbols, self.symbol_map, suppressions)
     }
 
-    fn alloc_symbol_id(&self) -> SymbolId {
-        SymbolId::new(self.symbol_id_counter.fetch_add(1, Ordering::SeqCst))
-    }
+    /// Attach API tags from JSDoc comments to the declarations they document.
+    fn apply_doc_tags(&mut self, program: &Program<'a>) {
+        for comment in program.comments.iter().filter(|c| c.is_jsdoc()) {
+            let tags = parse_doc_tags(comment.content_span().source_text(self.source));
+            if tags == DocTags::default() {
+                continue;
+            }
 
-    fn span_to_location(&self, span: Span) -> Location {
-        let (line, column) = self.offset_to_line_col(span.start);
-        Location::new(
-            self.file_path.clone(),
-            span.start,
-            span.end,
-            line,
-            column,
-        )
+            // The documented symbol follows, separated only by keywords like `export const`
+            let end = comment.span.end;
+            let documented = self
+                .symbols
+                .iter_mut()
+                .filter(|s| s.location.start_offset >= end)
+                .min_by_key(|s| s.location.start_offset);
+            if let Some(symbol) = documented {
+                let gap = &self.source[end as usize..symbol.location.start_offset as usize];
+                if gap
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c.is_whitespace() || c == '*')
+                {
+                    symbol.doc_tags = tags;
+                }
+            }
+        }
     }
 
-    fn offset_to_line_col(&self, offset: u32) -> (u32, u32) {
-        let offset = offset as usize;
-        let mut line = 1u32;
-        let mut col = 1u32;
+    /// Mark symbols covered by `ddd-ignore` comments as suppressed.
+    fn apply_suppressions(&mut self, program: &Program<'a>) -> Vec<Suppression> {
+        let mut suppressions = Vec::new();
+        let body_start = program
+            .body
+            .first()
+            .map_or(u32::MAX, |stmt| stmt.span().start);
+
+        for comment in program.comments.iter() {
+            let text = comment.content_span().source_text(self.source);
+            let Some((kind, reason)) = parse_suppression(text) else {
+                continue;
+            };
+            // File-level directives belong in the header, before any code
+            if kind == SuppressionKind::File && comment.span.start > body_start {
+                continue;
+            }
+            let location = self.span_to_location(comment.span);
+
+            let covered: Vec<&TrackedSymbol> = match kind {
+                SuppressionKind::File => self.symbols.iter().collect(),
+                SuppressionKind::NextLine => self
+                    .symbols
+                    .iter()
+                    .filter(|s| s.location.line == location.end_line + 1)
+                    .collect(),
+                SuppressionKind::Declaration => self.suppressed_declaration(&location),
+            };
+            let symbols: Vec<SymbolId> = covered.iter().map(|s| s.id).collect();
 
-        for (i, ch) in self.source.char_indices() {
-            if i >= offset {
-                break;
+            for symbol in &mut self.symbols {
+                if symbols.contains(&symbol.id) {
+                    symbol.suppressed = true;
+                }
             }
-            if ch == '\n' {
-                line += 1;
-                col = 1;
-            } else {
-                col += 1;
+            suppressions.push(Suppression {
+                kind,
+                location,
+                reason,
+                symbols,
+            });
+        }
+
+        suppressions
+    }
+
+    /// Find the declaration a `ddd-ignore` comment applies to, with its nested symbols.
+    ///
+    /// A trailing comment applies to the declaration on its own line, otherwise the
+    /// comment applies to the next declaration.
+    fn suppressed_declaration(&self, comment: &Location) -> Vec<&TrackedSymbol> {
+        let trailing = self
+            .symbols
+            .iter()
+            .filter(|s| {
+                s.location.line == comment.line && s.location.start_offset < comment.start_offset
+            })
+            .min_by_key(|s| s.location.start_offset);
+        let next = || {
+            self.symbols
+                .iter()
+                .filter(|s| s.location.start_offset >= comment.end_offset)
+                .min_by_key(|s| s.location.start_offset)
+        };
+
+        let Some(declaration) = trailing.or_else(next) else {
+            return Vec::new();
+        };
+        let (start, end) = (
+            declaration.location.start_offset,
+            declaration.location.end_offset,
+        );
+        self.symbols
+            .iter()
+            .filter(|s| start <= s.location.start_offset && s.location.end_offset <= end)
+            .collect()
+    }
+
+    /// Link oxc's semantic symbols to ours by declaration start offset.
+    fn map_semantic_symbols(&mut self, semantic: &Semantic<'a>) {
+        let by_start: HashMap<u32, SymbolId> = self
+            .symbols
+            .iter()
+            .map(|s| (s.location.start_offset, s.id))
+            .collect();
+
+        let scoping = semantic.scoping();
+        for symbol_id in scoping.symbol_ids() {
+            if let Some(&id) = by_start.get(&scoping.symbol_span(symbol_id).start) {
+                self.symbol_map.insert(symbol_id, id);
             }
         }
+    }
+
+    fn alloc_symbol_id(&self) -> SymbolId {
+        SymbolId::new(self.symbol_id_counter.fetch_add(1, Ordering::SeqCst))
+    }
 
-        (line, col)
+    fn span_to_location(&self, span: Span) -> Location {
+        let (line, column) = self.lines.line_col(span.start);
+        let (end_line, _) = self.lines.line_col(span.end);
+        Location::new(self.file_path.clone(), span.start, span.end, line, column)
+            .with_end_line(end_line)
+    }
+
+    fn add_symbol(
+        &mut self,
+        name: String,
+        kind: SymbolKind,
+        span: Span,
+        exported: bool,
+    ) -> SymbolId {
+        self.add_declaration(name, kind, span, span, exported)
     }
 
-    fn add_symbol(&mut self, name: String, kind: SymbolKind, span: Span, exported: bool) -> SymbolId {
+    /// Add a symbol named at `id_span` whose declaration spans `declaration`.
+    fn add_declaration(
+        &mut self,
+        name: String,
+        kind: SymbolKind,
+        id_span: Span,
+        declaration: Span,
+        exported: bool,
+    ) -> SymbolId {
         let id = self.alloc_symbol_id();
-        let location = self.span_to_location(span);
+        let location = self
+            .span_to_location(Span::new(id_span.start, declaration.end))
+            .with_declaration_start(declaration.start);
 
         let mut symbol = TrackedSymbol::new(id, name, kind, location, self.file_id);
         symbol.exported = exported;
@@ -99,17 +225,13 @@ impl<'a> SymbolExtractor<'a> {
         self.symbols.push(symbol);
         id
     }
-
-    fn is_module_level(&self) -> bool {
-        self.scope_depth == 0
-    }
 }
 
 impl<'a> Visit<'a> for SymbolExtractor<'a> {
     fn visit_function(&mut self, func: &Function<'a>, flags: oxc::semantic::ScopeFlags) {
         if let Some(id) = &func.id {
             let kind = SymbolKind::Function;
-            self.add_symbol(id.name.to_string(), kind, id.span, false);
+            self.add_declaration(id.name.to_string(), kind, id.span, func.span, false);
         }
 
         self.scope_depth += 1;
@@ -119,7 +241,13 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
 
     fn visit_class(&mut self, class: &Class<'a>) {
         if let Some(id) = &class.id {
-            self.add_symbol(id.name.to_string(), SymbolKind::Class, id.span, false);
+            self.add_declaration(
+                id.name.to_string(),
+                SymbolKind::Class,
+                id.span,
+                class.span,
+                false,
+            );
         }
 
         self.scope_depth += 1;
@@ -134,46 +262,41 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
         };
 
         for declarator in &decl.declarations {
-            self.extract_binding_pattern_names(&declarator.id, kind);
-
-            // Check for side effects in initializer at module level
-            if self.is_module_level() {
-                if let Some(init) = &declarator.init {
-                    if self.expression_has_side_effects(init) {
-                        self.has_side_effects = true;
-                    }
-                }
-            }
+            let declaration = Span::new(decl.span.start, declarator.span.end);
+            self.extract_binding_pattern_names(&declarator.id, kind, declaration);
         }
 
         walk::walk_variable_declaration(self, decl);
     }
 
     fn visit_ts_type_alias_declaration(&mut self, decl: &TSTypeAliasDeclaration<'a>) {
-        self.add_symbol(
+        self.add_declaration(
             decl.id.name.to_string(),
             SymbolKind::Type,
             decl.id.span,
+            decl.span,
             false,
         );
         walk::walk_ts_type_alias_declaration(self, decl);
     }
 
     fn visit_ts_interface_declaration(&mut self, decl: &TSInterfaceDeclaration<'a>) {
-        self.add_symbol(
+        self.add_declaration(
             decl.id.name.to_string(),
             SymbolKind::Interface,
             decl.id.span,
+            decl.span,
             false,
         );
         walk::walk_ts_interface_declaration(self, decl);
     }
 
     fn visit_ts_enum_declaration(&mut self, decl: &TSEnumDeclaration<'a>) {
-        let enum_id = self.add_symbol(
+        let enum_id = self.add_declaration(
             decl.id.name.to_string(),
             SymbolKind::Enum,
             decl.id.span,
+            decl.span,
             false,
         );
 
@@ -210,10 +333,11 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
             match declaration {
                 Declaration::FunctionDeclaration(f) => {
                     if let Some(id) = &f.id {
-                        let sym_id = self.add_symbol(
+                        let sym_id = self.add_declaration(
                             id.name.to_string(),
                             SymbolKind::Function,
                             id.span,
+                            Span::new(decl.span.start, f.span.end),
                             true,
                         );
                         let _ = sym_id;
@@ -221,10 +345,11 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
                 }
                 Declaration::ClassDeclaration(c) => {
                     if let Some(id) = &c.id {
-                        self.add_symbol(
+                        self.add_declaration(
                             id.name.to_string(),
                             SymbolKind::Class,
                             id.span,
+                            Span::new(decl.span.start, c.span.end),
                             true,
                         );
                     }
@@ -235,30 +360,37 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
                         _ => SymbolKind::Variable,
                     };
                     for declarator in &v.declarations {
-                        self.extract_binding_pattern_names_exported(&declarator.id, kind);
+                        self.extract_binding_pattern_names_exported(
+                            &declarator.id,
+                            kind,
+                            Span::new(decl.span.start, declarator.span.end),
+                        );
                     }
                 }
                 Declaration::TSTypeAliasDeclaration(t) => {
-                    self.add_symbol(
+                    self.add_declaration(
                         t.id.name.to_string(),
                         SymbolKind::Type,
                         t.id.span,
+                        Span::new(decl.span.start, t.span.end),
                         true,
                     );
                 }
                 Declaration::TSInterfaceDeclaration(i) => {
-                    self.add_symbol(
+                    self.add_declaration(
                         i.id.name.to_string(),
                         SymbolKind::Interface,
                         i.id.span,
+                        Span::new(decl.span.start, i.span.end),
                         true,
                     );
                 }
                 Declaration::TSEnumDeclaration(e) => {
-                    self.add_symbol(
+                    self.add_declaration(
                         e.id.name.to_string(),
                         SymbolKind::Enum,
                         e.id.span,
+                        Span::new(decl.span.start, e.span.end),
                         true,
                     );
                 }
@@ -270,7 +402,8 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
                     self.add_symbol(name, SymbolKind::Namespace, m.span, true);
                 }
                 // These are less common declarations, skip for now
-                Declaration::TSGlobalDeclaration(_) | Declaration::TSImportEqualsDeclaration(_) => {}
+                Declaration::TSGlobalDeclaration(_) | Declaration::TSImportEqualsDeclaration(_) => {
+                }
             }
         }
 
@@ -284,16 +417,20 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
     fn visit_export_default_declaration(&mut self, decl: &ExportDefaultDeclaration<'a>) {
         match &decl.declaration {
             ExportDefaultDeclarationKind::FunctionDeclaration(f) => {
-                let name = f.id.as_ref()
-                    .map(|id| id.name.to_string())
-                    .unwrap_or_else(|| "default".to_string());
-                self.add_symbol(name, SymbolKind::Function, f.span, true);
+                let name =
+                    f.id.as_ref()
+                        .map(|id| id.name.to_string())
+                        .unwrap_or_else(|| "default".to_string());
+                let declaration = Span::new(decl.span.start, f.span.end);
+                self.add_declaration(name, SymbolKind::Function, f.span, declaration, true);
             }
             ExportDefaultDeclarationKind::ClassDeclaration(c) => {
-                let name = c.id.as_ref()
-                    .map(|id| id.name.to_string())
-                    .unwrap_or_else(|| "default".to_string());
-                self.add_symbol(name, SymbolKind::Class, c.span, true);
+                let name =
+                    c.id.as_ref()
+                        .map(|id| id.name.to_string())
+                        .unwrap_or_else(|| "default".to_string());
+                let declaration = Span::new(decl.span.start, c.span.end);
+                self.add_declaration(name, SymbolKind::Class, c.span, declaration, true);
             }
             ExportDefaultDeclarationKind::TSInterfaceDeclaration(i) => {
                 self.add_symbol(i.id.name.to_string(), SymbolKind::Interface, i.span, true);
@@ -307,63 +444,258 @@ impl<'a> Visit<'a> for SymbolExtractor<'a> {
 }
 
 impl<'a> SymbolExtractor<'a> {
-    fn extract_binding_pattern_names(&mut self, pattern: &BindingPattern<'a>, kind: SymbolKind) {
+    fn extract_binding_pattern_names(
+        &mut self,
+        pattern: &BindingPattern<'a>,
+        kind: SymbolKind,
+        declaration: Span,
+    ) {
         match &pattern.kind {
             BindingPatternKind::BindingIdentifier(id) => {
-                self.add_symbol(id.name.to_string(), kind, id.span, false);
+                self.add_declaration(id.name.to_string(), kind, id.span, declaration, false);
             }
             BindingPatternKind::ObjectPattern(obj) => {
                 for prop in &obj.properties {
-                    self.extract_binding_pattern_names(&prop.value, kind);
+                    self.extract_binding_pattern_names(&prop.value, kind, declaration);
                 }
                 if let Some(rest) = &obj.rest {
-                    self.extract_binding_pattern_names(&rest.argument, kind);
+                    self.extract_binding_pattern_names(&rest.argument, kind, declaration);
                 }
             }
             BindingPatternKind::ArrayPattern(arr) => {
                 for elem in arr.elements.iter().flatten() {
-                    self.extract_binding_pattern_names(elem, kind);
+                    self.extract_binding_pattern_names(elem, kind, declaration);
                 }
                 if let Some(rest) = &arr.rest {
-                    self.extract_binding_pattern_names(&rest.argument, kind);
+                    self.extract_binding_pattern_names(&rest.argument, kind, declaration);
                 }
             }
             BindingPatternKind::AssignmentPattern(assign) => {
-                self.extract_binding_pattern_names(&assign.left, kind);
+                self.extract_binding_pattern_names(&assign.left, kind, declaration);
             }
         }
     }
 
-    fn extract_binding_pattern_names_exported(&mut self, pattern: &BindingPattern<'a>, kind: SymbolKind) {
+    fn extract_binding_pattern_names_exported(
+        &mut self,
+        pattern: &BindingPattern<'a>,
+        kind: SymbolKind,
+        declaration: Span,
+    ) {
         match &pattern.kind {
             BindingPatternKind::BindingIdentifier(id) => {
-                self.add_symbol(id.name.to_string(), kind, id.span, true);
+                self.add_declaration(id.name.to_string(), kind, id.span, declaration, true);
             }
             BindingPatternKind::ObjectPattern(obj) => {
                 for prop in &obj.properties {
-                    self.extract_binding_pattern_names_exported(&prop.value, kind);
+                    self.extract_binding_pattern_names_exported(&prop.value, kind, declaration);
                 }
             }
             BindingPatternKind::ArrayPattern(arr) => {
                 for elem in arr.elements.iter().flatten() {
-                    self.extract_binding_pattern_names_exported(elem, kind);
+                    self.extract_binding_pattern_names_exported(elem, kind, declaration);
                 }
             }
             BindingPatternKind::AssignmentPattern(assign) => {
-                self.extract_binding_pattern_names_exported(&assign.left, kind);
+                self.extract_binding_pattern_names_exported(&assign.left, kind, declaration);
             }
         }
     }
+}
 
-    fn expression_has_side_effects(&self, expr: &Expression<'a>) -> bool {
-        match expr {
-            Expression::CallExpression(_) => true,
-            Expression::NewExpression(_) => true,
-            Expression::AssignmentExpression(_) => true,
-            Expression::UpdateExpression(_) => true,
-            Expression::AwaitExpression(_) => true,
-            Expression::YieldExpression(_) => true,
-            _ => false,
+/// Parse the API tags from JSDoc comment text.
+fn parse_doc_tags(text: &str) -> DocTags {
+    let mut tags = DocTags::default();
+
+    for line in text.lines() {
+        // Block tags start a line, after the leading `*`
+        let line = line.trim().trim_start_matches('*').trim_start();
+        let Some(tag_line) = line.strip_prefix('@') else {
+            continue;
+        };
+        let (tag, rest) = tag_line
+            .split_once(char::is_whitespace)
+            .unwrap_or((tag_line, ""));
+
+        match tag {
+            "public" | "api" => tags.public = Some(tag.to_string()),
+            "internal" => tags.internal = true,
+            "deprecated" => tags.deprecated = Some(rest.trim().to_string()),
+            _ => {}
         }
     }
+
+    tags
+}
+
+/// Parse a `ddd-ignore` directive from comment text, returning its kind and reason.
+fn parse_suppression(text: &str) -> Option<(SuppressionKind, Option<String>)> {
+    // Allow JSDoc-style `/** ddd-ignore */` comments
+    let text = text.trim().trim_start_matches('*').trim_start();
+    let rest = text.strip_prefix("ddd-ignore")?;
+
+    let (kind, rest) = if let Some(rest) = rest.strip_prefix("-next-line") {
+        (SuppressionKind::NextLine, rest)
+    } else if let Some(rest) = rest.strip_prefix("-file") {
+        (SuppressionKind::File, rest)
+    } else {
+        (SuppressionKind::Declaration, rest)
+    };
+
+    // The directive must be a whole word
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    // Accept eslint-style `-- reason` separators
+    let reason = rest.trim().trim_start_matches("--").trim();
+    Some((kind, (!reason.is_empty()).then(|| reason.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oxc::allocator::Allocator;
+    use oxc::parser::Parser;
+    use oxc::semantic::SemanticBuilder;
+    use oxc::span::SourceType;
+
+    fn extract(source: &str) -> (Vec<TrackedSymbol>, Vec<Suppression>) {
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source, SourceType::ts())
+            .parse()
+            .program;
+        let semantic = SemanticBuilder::new().build(&program).semantic;
+        let counter = AtomicU32::new(0);
+        let extractor =
+            SymbolExtractor::new(PathBuf::from("test.ts"), FileId::new(0), &counter, source);
+        let (symbols, _, suppressions) = extractor.extract(&program, &semantic);
+        (symbols, suppressions)
+    }
+
+    fn suppressed(symbols: &[TrackedSymbol]) -> Vec<&str> {
+        symbols
+            .iter()
+            .filter(|s| s.suppressed)
+            .map(|s| s.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_declaration_start_includes_keywords() {
+        let source = "export async function run() {}\nconst x = 1;\nexport default class {}\n";
+        let (symbols, _) = extract(source);
+        let starts: Vec<_> = symbols
+            .iter()
+            .map(|s| {
+                (
+                    s.name.as_str(),
+                    s.location.declaration_start,
+                    s.location.start_offset,
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            starts,
+            vec![("run", 0, 22), ("x", 31, 37), ("default", 44, 59)]
+        );
+    }
+
+    #[test]
+    fn test_parse_suppression() {
+        assert_eq!(
+            parse_suppression(" ddd-ignore-next-line"),
+            Some((SuppressionKind::NextLine, None))
+        );
+        assert_eq!(
+            parse_suppression(" ddd-ignore -- used by plugins"),
+            Some((
+                SuppressionKind::Declaration,
+                Some("used by plugins".to_string())
+            ))
+        );
+        assert_eq!(
+            parse_suppression(" ddd-ignore-file "),
+            Some((SuppressionKind::File, None))
+        );
+        assert_eq!(parse_suppression(" ddd-ignored"), None);
+        assert_eq!(parse_suppression(" unrelated"), None);
+    }
+
+    #[test]
+    fn test_suppressions_cover_symbols() {
+        let source = "\
+// ddd-ignore-next-line
+function a() {}
+function b() {}
+// ddd-ignore loaded by name
+function c() {
+  function inner() {}
+}
+function d() {} // ddd-ignore
+";
+        let (symbols, suppressions) = extract(source);
+
+        assert_eq!(suppressed(&symbols), ["a", "c", "inner", "d"]);
+        assert_eq!(suppressions.len(), 3);
+        assert_eq!(suppressions[1].reason.as_deref(), Some("loaded by name"));
+    }
+
+    #[test]
+    fn test_doc_tags_attach_to_declarations() {
+        let source = "\
+/**
+ * Stable entry.
+ * @api
+ */
+export function stable() {}
+
+/** @internal */
+export const helper = () => {};
+
+/**
+ * @deprecated Use `stable` instead.
+ */
+export class Old {}
+
+/** @public */
+foo();
+function unrelated() {}
+";
+        let (symbols, _) = extract(source);
+        let tags = |name: &str| &symbols.iter().find(|s| s.name == name).unwrap().doc_tags;
+
+        assert_eq!(tags("stable").public.as_deref(), Some("api"));
+        assert!(tags("helper").internal);
+        assert!(!symbols
+            .iter()
+            .find(|s| s.name == "helper")
+            .unwrap()
+            .is_public_export());
+        assert_eq!(
+            tags("Old").deprecated.as_deref(),
+            Some("Use `stable` instead.")
+        );
+        // Separated from its comment by a statement
+        assert_eq!(tags("unrelated"), &DocTags::default());
+    }
+
+    #[test]
+    fn test_file_suppression() {
+        let (symbols, suppressions) =
+            extract("/* ddd-ignore-file */\nfunction a() {}\nconst b = 1;\n");
+
+        assert_eq!(suppressed(&symbols), ["a", "b"]);
+        assert_eq!(suppressions[0].kind, SuppressionKind::File);
+    }
+
+    #[test]
+    fn test_file_suppression_only_in_header() {
+        let (symbols, suppressions) =
+            extract("function a() {}\n/* ddd-ignore-file */\nconst b = 1;\n");
+
+        assert!(suppressed(&symbols).is_empty());
+        assert!(suppressions.is_empty());
+    }
 }
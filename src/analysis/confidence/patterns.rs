@@ -1,9 +1,165 @@
 //! Dynamic pattern detection for confidence scoring.
 
-use crate::core::CallGraph;
+use crate::config::{Config, ConfigMatcher};
+use crate::core::{CallGraph, FileId};
+use std::collections::{HashMap, HashSet};
 
-/// Detect global dynamic patterns that affect overall confidence.
-pub fn detect_dynamic_patterns(call_graph: &CallGraph) -> bool {
-    // Check if any file has dynamic eval
-    call_graph.files.values().any(|f| f.has_dynamic_eval)
+/// Find files whose own `eval` usage should lower confidence.
+///
+/// Files matching `analysis.allowEval`, relative to the config file's
+/// directory, are skipped.
+pub fn eval_files(call_graph: &CallGraph, config: &Config) -> HashSet<FileId> {
+    let matcher = ConfigMatcher::new(config);
+    call_graph
+        .files
+        .values()
+        .filter(|f| f.has_dynamic_eval)
+        .filter(|f| !matcher.allows_eval(&f.path))
+        .map(|f| f.id)
+        .collect()
+}
+
+/// Find files connected to any of `sources` through the import graph.
+///
+/// A file is connected if it transitively imports a source, or a source
+/// transitively imports it. Unrelated branches of the graph are not affected,
+/// and a source only counts as connected through another source.
+pub fn files_connected_to(call_graph: &CallGraph, sources: &HashSet<FileId>) -> HashSet<FileId> {
+    let mut importers: HashMap<FileId, Vec<FileId>> = HashMap::new();
+    for (&from, targets) in &call_graph.file_imports {
+        for &to in targets {
+            importers.entry(to).or_default().push(from);
+        }
+    }
+
+    let imported = reached_from(sources, |file| call_graph.get_file_imports(file));
+    let importing = reached_from(sources, |file| {
+        importers.get(&file).map_or(&[], |v| v.as_slice())
+    });
+
+    let mut connected: HashSet<FileId> = imported.keys().chain(importing.keys()).copied().collect();
+    // A source is only connected if reached from a different source
+    connected.retain(|file| {
+        let mut origins = imported
+            .get(file)
+            .into_iter()
+            .chain(importing.get(file))
+            .flatten();
+        !sources.contains(file) || origins.any(|origin| origin != file)
+    });
+    connected
+}
+
+/// Follow `next` from every source at once, recording for each file reached
+/// up to two distinct sources it was reached from.
+///
+/// Two are enough to tell whether a file is reached from a source other than
+/// itself, and capping them keeps the walk linear in the size of the graph.
+fn reached_from<'g>(
+    sources: &HashSet<FileId>,
+    next: impl Fn(FileId) -> &'g [FileId],
+) -> HashMap<FileId, Vec<FileId>> {
+    let mut origins: HashMap<FileId, Vec<FileId>> = HashMap::new();
+    let mut stack: Vec<(FileId, FileId)> = sources.iter().map(|&s| (s, s)).collect();
+
+    while let Some((file, origin)) = stack.pop() {
+        for &neighbor in next(file) {
+            let seen = origins.entry(neighbor).or_default();
+            if seen.len() < 2 && !seen.contains(&origin) {
+                seen.push(origin);
+                stack.push((neighbor, origin));
+            }
+        }
+    }
+
+    origins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FileInfo;
+    use std::path::PathBuf;
+
+    fn make_graph(edges: &[(u32, u32)], files: u32) -> CallGraph {
+        let mut graph = CallGraph::new();
+        for id in 0..files {
+            graph.add_file(FileInfo {
+                id: FileId::new(id),
+                path: PathBuf::from(format!("/project/src/f{}.ts", id)),
+                has_side_effects: false,
+                has_dynamic_eval: id == 1,
+                symbols: Vec::new(),
+                suppressions: Vec::new(),
+            });
+        }
+        for &(from, to) in edges {
+            graph.add_file_import(FileId::new(from), FileId::new(to));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_connected_files_follow_both_directions() {
+        // 0 -> 1 -> 2, and 3 -> 2 is unrelated to 1
+        let graph = make_graph(&[(0, 1), (1, 2), (3, 2)], 5);
+        let sources: HashSet<_> = [FileId::new(1)].into_iter().collect();
+
+        let connected = files_connected_to(&graph, &sources);
+
+        for id in [0, 2] {
+            assert!(connected.contains(&FileId::new(id)));
+        }
+        for id in [1, 3, 4] {
+            assert!(!connected.contains(&FileId::new(id)));
+        }
+    }
+
+    #[test]
+    fn test_source_connected_through_another_source() {
+        // 1 -> 2 -> 4, with sources 1 and 4
+        let graph = make_graph(&[(1, 2), (2, 4)], 5);
+        let sources: HashSet<_> = [FileId::new(1), FileId::new(4)].into_iter().collect();
+
+        let connected = files_connected_to(&graph, &sources);
+
+        for id in [1, 2, 4] {
+            assert!(connected.contains(&FileId::new(id)));
+        }
+    }
+
+    #[test]
+    fn test_source_is_not_connected_through_itself() {
+        // 1 -> 2 -> 1, with only source 1; then 0 -> 3 -> 1 with source 0
+        let graph = make_graph(&[(1, 2), (2, 1), (0, 3), (3, 1)], 4);
+        let sources: HashSet<_> = [FileId::new(1)].into_iter().collect();
+        let connected = files_connected_to(&graph, &sources);
+        assert!(!connected.contains(&FileId::new(1)));
+        assert!(connected.contains(&FileId::new(2)));
+
+        let sources: HashSet<_> = [FileId::new(0), FileId::new(1)].into_iter().collect();
+        let connected = files_connected_to(&graph, &sources);
+        for id in [0, 1, 2, 3] {
+            assert!(connected.contains(&FileId::new(id)));
+        }
+    }
+
+    #[test]
+    fn test_allow_eval_skips_files() {
+        let graph = make_graph(&[], 2);
+        let mut config = Config {
+            base_dir: Some(PathBuf::from("/project")),
+            ..Default::default()
+        };
+        assert_eq!(eval_files(&graph, &config).len(), 1);
+
+        config.analysis.allow_eval = vec!["src/f1.ts".to_string()];
+        assert!(eval_files(&graph, &config).is_empty());
+
+        // Globs are anchored at the config file's directory
+        config.base_dir = Some(PathBuf::from("/"));
+        assert_eq!(eval_files(&graph, &config).len(), 1);
+        config.analysis.allow_eval = vec!["f1.ts".to_string()];
+        assert!(eval_files(&graph, &config).is_empty());
+    }
 }
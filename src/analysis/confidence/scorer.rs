@@ -2,112 +2,353 @@
 //!
 //! Applies conservative rules to reduce false positives.
 
-use super::patterns::detect_dynamic_patterns;
-use crate::config::Config;
-use crate::core::{CallGraph, Confidence, DeadSymbol, DynamicPatternKind};
-
-/// Score dead symbols for confidence.
+use super::patterns::{eval_files, files_connected_to};
+use crate::config::{ConfidenceRule, Config, FileConfigs};
+use crate::core::{
+    CallGraph, ConfidenceFactor, DeadSymbol, DynamicPatternKind, Execution, FileInfo, Freshness,
+    Location, Result, SymbolId, SymbolKind, TrackedSymbol,
+};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Score dead symbols for confidence, given the symbols reachable from the
+/// call graph's entry points.
 pub fn score_dead_symbols(
     mut dead_symbols: Vec<DeadSymbol>,
     call_graph: &CallGraph,
+    reachable: &HashSet<SymbolId>,
     config: &Config,
 ) -> Vec<DeadSymbol> {
     // Detect file-level dynamic patterns
-    let file_has_eval: std::collections::HashSet<_> = call_graph
-        .files
-        .values()
-        .filter(|f| f.has_dynamic_eval)
-        .map(|f| f.id)
+    let file_has_eval = eval_files(call_graph, config);
+
+    // Dynamic code can only touch modules linked to it through imports
+    let connected_to_eval = files_connected_to(call_graph, &file_has_eval);
+
+    // Names used as strings only count in reachable code
+    let live_strings = reachable_string_patterns(call_graph, reachable, &dead_symbols);
+
+    let weights = &config.confidence.weights;
+    let custom_rules: Vec<_> = config
+        .confidence
+        .rules
+        .iter()
+        .map(|rule| {
+            CompiledRule::new(rule)
+                .expect("confidence rules are checked by Config::validate before analysis")
+        })
         .collect();
 
-    // Check for global dynamic patterns
-    let has_global_dynamic = detect_dynamic_patterns(call_graph);
-
     for dead in &mut dead_symbols {
-        let mut score = dead.confidence_score as i32;
+        let mut factors = Factors::default();
 
         // Rule 1: Decorators reduce confidence (frameworks might use them)
         if dead.symbol.has_decorators {
-            score -= 20;
+            factors.add("decorated", "decorated", weights.decorated);
         }
 
         // Rule 2: Exported symbols are more likely to be used externally
-        if dead.symbol.exported {
-            score -= 10;
+        if dead.symbol.is_public_export() {
+            factors.add("exported", "exported", weights.exported);
         }
 
         // Rule 3: File has eval() - major confidence reduction
         if file_has_eval.contains(&dead.symbol.file_id) {
-            score -= 30;
+            factors.add("fileEval", "file uses eval", weights.file_eval);
         }
 
-        // Rule 4: Global dynamic patterns affect all symbols
-        if has_global_dynamic {
-            score -= 15;
+        // Rule 4: Modules connected to an eval-using module are less certain
+        if connected_to_eval.contains(&dead.symbol.file_id) {
+            factors.add(
+                "connectedEval",
+                "import-connected to a module using eval",
+                weights.connected_eval,
+            );
         }
 
         // Rule 5: Transitive dead code is slightly less certain
         if matches!(dead.reason, crate::core::DeadnessReason::Transitive { .. }) {
-            score -= 5;
+            factors.add("transitive", "transitively dead", weights.transitive);
         }
 
         // Rule 6: Type-only symbols (interfaces, types) are less risky to remove
         if is_type_only(&dead.symbol.kind) {
-            score += 5;
+            factors.add("typeOnly", "type-only", weights.type_only);
         }
 
         // Rule 7: Private-by-convention (starts with _) is more likely dead
         if dead.symbol.name.starts_with('_') && !dead.symbol.name.starts_with("__") {
-            score += 5;
+            factors.add(
+                "privateConvention",
+                "private by naming convention",
+                weights.private_convention,
+            );
         }
 
         // Rule 8: Check for dynamic pattern matches in the call graph
-        for pattern in &call_graph.dynamic_patterns {
-            if pattern.affected_symbols.contains(&dead.symbol.id) {
-                match pattern.kind {
-                    DynamicPatternKind::Eval | DynamicPatternKind::FunctionConstructor => {
-                        score -= 40; // Very uncertain
-                    }
-                    DynamicPatternKind::Reflect => {
-                        score -= 30;
-                    }
-                    DynamicPatternKind::BracketAccess | DynamicPatternKind::StringPropertyAccess => {
-                        score -= 20;
-                    }
-                    DynamicPatternKind::ObjectIteration => {
-                        score -= 15;
-                    }
-                    DynamicPatternKind::DynamicImport | DynamicPatternKind::DynamicRequire => {
-                        score -= 25;
+        let mut string_locations: Vec<&Location> = Vec::new();
+        for (i, pattern) in call_graph.dynamic_patterns.iter().enumerate() {
+            if !pattern.affected_symbols.contains(&dead.symbol.id) {
+                continue;
+            }
+            let (rule, description, delta) = match pattern.kind {
+                DynamicPatternKind::Eval | DynamicPatternKind::FunctionConstructor => {
+                    // Very uncertain
+                    ("evalPattern", "affected by eval", weights.eval_pattern)
+                }
+                DynamicPatternKind::Reflect => (
+                    "reflectPattern",
+                    "affected by Reflect",
+                    weights.reflect_pattern,
+                ),
+                DynamicPatternKind::BracketAccess => (
+                    "propertyAccessPattern",
+                    "affected by dynamic property access",
+                    weights.property_access_pattern,
+                ),
+                // Collected into a single factor below
+                DynamicPatternKind::StringPropertyAccess => {
+                    if live_strings.contains(&i) {
+                        string_locations.push(&pattern.location);
                     }
+                    continue;
                 }
-            }
+                DynamicPatternKind::ObjectIteration => (
+                    "objectIterationPattern",
+                    "affected by object iteration",
+                    weights.object_iteration_pattern,
+                ),
+                DynamicPatternKind::DynamicImport | DynamicPatternKind::DynamicRequire => (
+                    "dynamicImportPattern",
+                    "affected by dynamic import",
+                    weights.dynamic_import_pattern,
+                ),
+            };
+            factors.add(rule, description, delta);
+        }
+
+        // Rule 8b: Name appears as a string, e.g. `handlers['onSave']`; every
+        // such string counts once
+        if !string_locations.is_empty() {
+            string_locations.sort_by(|a, b| (&a.file_path, a.line).cmp(&(&b.file_path, b.line)));
+            factors.add(
+                "propertyAccessPattern",
+                format!(
+                    "name used as a string at {}",
+                    format_locations(&string_locations)
+                ),
+                weights.property_access_pattern,
+            );
         }
 
         // Rule 9: Default exports without names are harder to track
         if dead.symbol.name == "default" {
-            score -= 10;
+            factors.add(
+                "defaultExport",
+                "anonymous default export",
+                weights.default_export,
+            );
         }
 
         // Rule 10: Class methods vs standalone functions
         // Methods are more likely to be called dynamically
         if dead.symbol.kind == crate::core::SymbolKind::Method {
-            score -= 5;
+            factors.add("method", "class method", weights.method);
+        }
+
+        // Rule 11: Removing a side-effecting initializer also drops its effect
+        if dead.symbol.has_side_effects {
+            factors.add(
+                "sideEffects",
+                "side-effecting initializer",
+                weights.side_effects,
+            );
+        }
+
+        // Rule 12: Runtime coverage confirms or contradicts the static result
+        match dead.execution {
+            Some(Execution::NeverExecuted) => {
+                factors.add("neverExecuted", "never executed", weights.never_executed);
+            }
+            Some(Execution::Executed { hits }) => {
+                factors.add(
+                    "executed",
+                    format!("executed {} times", hits),
+                    weights.executed,
+                );
+            }
+            None => {}
+        }
+
+        // Rule 13: Never seen in production (seen symbols are entry points)
+        if dead.trace == Some(Execution::NeverExecuted) {
+            factors.add(
+                "neverTraced",
+                "never executed in production",
+                weights.never_traced,
+            );
+        }
+
+        // Rule 14: Git history
+        if let Some(history) = &dead.history {
+            match history.freshness {
+                Freshness::Stale => factors.add(
+                    "stale",
+                    format!("untouched for {} days", history.age_days),
+                    weights.stale,
+                ),
+                Freshness::New => factors.add(
+                    "newCode",
+                    format!("changed {} days ago", history.age_days),
+                    weights.new_code,
+                ),
+                Freshness::Active => {}
+            }
+        }
+
+        // Custom rules from config
+        for rule in &custom_rules {
+            if rule.matches(&dead.symbol, config) {
+                factors.add("custom", rule.description.clone(), rule.delta);
+            }
         }
 
         // Clamp score to valid range
+        let score = dead.confidence_score as i32 + factors.total();
         dead.confidence_score = score.clamp(0, 100) as u8;
-        dead.confidence = Confidence::from_score(dead.confidence_score);
+        dead.confidence = config.confidence.level(dead.confidence_score);
+        dead.confidence_factors = factors.0;
     }
 
-    // Apply config-based filtering
-    if !config.analysis.include_types {
-        dead_symbols.retain(|d| !is_type_only(&d.symbol.kind));
-    }
+    // Apply config-based filtering, which overrides can change per file
+    let mut configs = FileConfigs::new(config);
+    dead_symbols.retain(|d| {
+        !is_type_only(&d.symbol.kind)
+            || configs
+                .get(&d.symbol.location.file_path)
+                .analysis
+                .include_types
+    });
 
     dead_symbols
 }
 
+/// Confidence rules applied to one symbol.
+#[derive(Default)]
+struct Factors(Vec<ConfidenceFactor>);
+
+impl Factors {
+    /// Record a rule, skipping those whose weight is configured to zero.
+    fn add(&mut self, rule: &str, description: impl Into<String>, delta: i32) {
+        if delta != 0 {
+            self.0.push(ConfidenceFactor::new(rule, description, delta));
+        }
+    }
+
+    fn total(&self) -> i32 {
+        self.0.iter().map(|f| f.delta).sum()
+    }
+}
+
+/// A custom confidence rule with its patterns compiled.
+struct CompiledRule {
+    description: String,
+    path: Option<glob::Pattern>,
+    name: Option<regex_lite::Regex>,
+    kind: Option<SymbolKind>,
+    delta: i32,
+}
+
+impl CompiledRule {
+    /// Compile a rule's patterns.
+    fn new(rule: &ConfidenceRule) -> Result<Self> {
+        let (path, name) = rule.compile()?;
+
+        Ok(Self {
+            description: rule.description(),
+            path,
+            name,
+            kind: rule.kind,
+            delta: rule.delta,
+        })
+    }
+
+    /// Check if the rule applies to a symbol, matching its path relative to
+    /// the config file's directory.
+    fn matches(&self, symbol: &TrackedSymbol, config: &Config) -> bool {
+        let relative = config.relative_path(&symbol.location.file_path);
+        let path_matches = |pattern: &glob::Pattern| {
+            relative
+                .as_ref()
+                .is_some_and(|path| pattern.matches(&path.to_string_lossy()))
+        };
+
+        self.path.as_ref().is_none_or(path_matches)
+            && self
+                .name
+                .as_ref()
+                .is_none_or(|re| re.is_match(&symbol.name))
+            && self.kind.is_none_or(|kind| kind == symbol.kind)
+    }
+}
+
+/// Find the `StringPropertyAccess` patterns naming a dead symbol that are in
+/// reachable code, by index. A string is reachable unless the innermost
+/// symbol around it is not.
+fn reachable_string_patterns(
+    call_graph: &CallGraph,
+    reachable: &HashSet<SymbolId>,
+    dead_symbols: &[DeadSymbol],
+) -> HashSet<usize> {
+    let dead: HashSet<SymbolId> = dead_symbols.iter().map(|d| d.symbol.id).collect();
+    let strings: Vec<_> = call_graph
+        .dynamic_patterns
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.kind == DynamicPatternKind::StringPropertyAccess)
+        .filter(|(_, p)| p.affected_symbols.iter().any(|id| dead.contains(id)))
+        .collect();
+    if strings.is_empty() {
+        return HashSet::new();
+    }
+
+    let files: HashMap<&Path, &FileInfo> = call_graph
+        .files
+        .values()
+        .map(|f| (f.path.as_path(), f))
+        .collect();
+    let in_reachable_code = |location: &Location| {
+        let symbols = files
+            .get(location.file_path.as_path())
+            .map_or(&[][..], |f| &f.symbols);
+        symbols
+            .iter()
+            .filter_map(|id| call_graph.symbols.get(id))
+            .filter(|s| {
+                s.location.start_offset <= location.start_offset
+                    && location.end_offset <= s.location.end_offset
+            })
+            .max_by_key(|s| s.location.start_offset)
+            .is_none_or(|s| reachable.contains(&s.id))
+    };
+
+    strings
+        .into_iter()
+        .filter(|(_, p)| in_reachable_code(&p.location))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Format up to three locations, e.g. "a.ts:3:5, b.ts:7:1 and 2 more".
+fn format_locations(locations: &[&Location]) -> String {
+    const SHOWN: usize = 3;
+    let shown: Vec<_> = locations.iter().take(SHOWN).map(|l| l.display()).collect();
+    match locations.len().saturating_sub(SHOWN) {
+        0 => shown.join(", "),
+        more => format!("{} and {} more", shown.join(", "), more),
+    }
+}
+
 /// Check if a symbol kind is type-only (no runtime impact).
 fn is_type_only(kind: &crate::core::SymbolKind) -> bool {
     matches!(
@@ -119,7 +360,12 @@ fn is_type_only(kind: &crate::core::SymbolKind) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{DeadnessReason, FileId, Location, SymbolId, SymbolKind, TrackedSymbol};
+    use crate::analysis::deadness::mark_reachable_symbols;
+    use crate::core::{Confidence, DeadnessReason, DynamicPattern, FileId, GitHistory};
+
+    fn score(dead_symbols: Vec<DeadSymbol>, graph: &CallGraph, config: &Config) -> Vec<DeadSymbol> {
+        score_dead_symbols(dead_symbols, graph, &mark_reachable_symbols(graph), config)
+    }
     use std::path::PathBuf;
 
     fn make_dead_symbol(name: &str, exported: bool, has_decorators: bool) -> DeadSymbol {
@@ -155,7 +401,7 @@ mod tests {
         let config = Config::default();
         let dead_symbols = vec![make_dead_symbol("foo", true, false)];
 
-        let scored = score_dead_symbols(dead_symbols, &graph, &config);
+        let scored = score(dead_symbols, &graph, &config);
 
         assert_eq!(scored[0].confidence_score, 90);
     }
@@ -166,7 +412,7 @@ mod tests {
         let config = Config::default();
         let dead_symbols = vec![make_dead_symbol("foo", false, true)];
 
-        let scored = score_dead_symbols(dead_symbols, &graph, &config);
+        let scored = score(dead_symbols, &graph, &config);
 
         assert_eq!(scored[0].confidence_score, 80);
     }
@@ -178,11 +424,222 @@ mod tests {
         // Exported + decorators
         let dead_symbols = vec![make_dead_symbol("foo", true, true)];
 
-        let scored = score_dead_symbols(dead_symbols, &graph, &config);
+        let scored = score(dead_symbols, &graph, &config);
 
         // 100 - 10 (exported) - 20 (decorators) = 70
         assert_eq!(scored[0].confidence_score, 70);
         assert_eq!(scored[0].confidence, Confidence::Medium);
+
+        let factors: Vec<_> = scored[0]
+            .confidence_factors
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        assert_eq!(factors, vec!["decorated -20", "exported -10"]);
+    }
+
+    #[test]
+    fn test_side_effecting_initializer_reduces_confidence() {
+        let graph = CallGraph::new();
+        let config = Config::default();
+        let mut dead = make_dead_symbol("logger", false, false);
+        dead.symbol.has_side_effects = true;
+
+        let scored = score(vec![dead], &graph, &config);
+
+        assert_eq!(scored[0].confidence_score, 90);
+    }
+
+    #[test]
+    fn test_configured_weights_and_thresholds() {
+        let graph = CallGraph::new();
+        let mut config = Config::default();
+        config.confidence.weights.exported = -30;
+        config.confidence.high_threshold = 75;
+
+        let scored = score(vec![make_dead_symbol("foo", true, false)], &graph, &config);
+
+        assert_eq!(scored[0].confidence_score, 70);
+        assert_eq!(scored[0].confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_custom_rules() {
+        let graph = CallGraph::new();
+        let mut config = Config {
+            base_dir: Some(PathBuf::from("/project")),
+            ..Default::default()
+        };
+        config.confidence.rules = vec![
+            ConfidenceRule {
+                path: Some("src/legacy/**".to_string()),
+                name: None,
+                kind: None,
+                delta: -25,
+                description: None,
+            },
+            ConfidenceRule {
+                path: None,
+                name: Some("^handle".to_string()),
+                kind: Some(SymbolKind::Function),
+                delta: -10,
+                description: None,
+            },
+        ];
+
+        let mut legacy = make_dead_symbol("handleClick", false, false);
+        legacy.symbol.location.file_path = PathBuf::from("/project/src/legacy/old.ts");
+        let other = make_dead_symbol("render", false, false);
+        // Path globs are anchored at the config file's directory
+        let mut nested = make_dead_symbol("render", false, false);
+        nested.symbol.location.file_path = PathBuf::from("/project/lib/src/legacy/old.ts");
+
+        let scored = score(vec![legacy, other, nested], &graph, &config);
+
+        // 100 - 25 (legacy path) - 10 (handler name) = 65
+        assert_eq!(scored[0].confidence_score, 65);
+        assert_eq!(
+            scored[0].confidence_factors[0].description,
+            "path `src/legacy/**`"
+        );
+        assert_eq!(scored[1].confidence_score, 100);
+        assert_eq!(scored[2].confidence_score, 100);
+    }
+
+    #[test]
+    fn test_coverage_adjusts_confidence() {
+        let graph = CallGraph::new();
+        let config = Config::default();
+
+        let mut never = make_dead_symbol("foo", true, false);
+        never.execution = Some(Execution::NeverExecuted);
+        let mut executed = make_dead_symbol("bar", false, false);
+        executed.execution = Some(Execution::Executed { hits: 3 });
+
+        let scored = score(vec![never, executed], &graph, &config);
+
+        // 100 - 10 (exported) + 10 (never executed) = 100
+        assert_eq!(scored[0].confidence_score, 100);
+        // 100 - 40 (executed) = 60
+        assert_eq!(scored[1].confidence_score, 60);
+        assert_eq!(
+            scored[1].confidence_factors[0].to_string(),
+            "executed 3 times -40"
+        );
+    }
+
+    #[test]
+    fn test_string_use_lowers_confidence() {
+        let mut graph = CallGraph::new();
+        let config = Config::default();
+        let dead = make_dead_symbol("onSave", false, false);
+        let symbol = |id: u32, name: &str, start: u32, entry: bool| {
+            let mut symbol = TrackedSymbol::new(
+                SymbolId::new(id),
+                name.to_string(),
+                SymbolKind::Function,
+                Location::new(PathBuf::from("test.ts"), start, start + 30, 1, 1),
+                FileId::new(0),
+            );
+            symbol.is_entry_point = entry;
+            symbol
+        };
+        graph.add_symbol(dead.symbol.clone());
+        graph.add_symbol(symbol(1, "main", 30, true));
+        graph.add_symbol(symbol(2, "unusedCaller", 70, false));
+        graph.add_file(FileInfo {
+            id: FileId::new(0),
+            path: PathBuf::from("test.ts"),
+            has_side_effects: false,
+            has_dynamic_eval: false,
+            symbols: (0..3).map(SymbolId::new).collect(),
+            suppressions: Vec::new(),
+        });
+        let pattern = |kind: DynamicPatternKind, start: u32, line: u32| DynamicPattern {
+            kind,
+            location: Location::new(PathBuf::from("test.ts"), start, start + 8, line, 10),
+            affected_symbols: vec![dead.symbol.id],
+        };
+        // Inside the dead symbol itself, in live `main`, and in dead `unusedCaller`
+        for (start, line) in [(2, 1), (40, 4), (75, 8)] {
+            let string = pattern(DynamicPatternKind::StringPropertyAccess, start, line);
+            graph.add_dynamic_pattern(string);
+        }
+
+        let scored = score(vec![dead.clone()], &graph, &config);
+
+        // 100 - 20 (property access) = 80
+        assert_eq!(scored[0].confidence_score, 80);
+        assert_eq!(
+            scored[0].confidence_factors[0].description,
+            "name used as a string at test.ts:4:10"
+        );
+
+        // Bracket access is scored on its own
+        graph.add_dynamic_pattern(pattern(DynamicPatternKind::BracketAccess, 50, 5));
+        let scored = score(vec![dead], &graph, &config);
+        assert_eq!(scored[0].confidence_score, 60);
+        assert_eq!(scored[0].confidence_factors.len(), 2);
+    }
+
+    #[test]
+    fn test_eval_patterns_stack() {
+        let mut graph = CallGraph::new();
+        let config = Config::default();
+        let dead = make_dead_symbol("foo", false, false);
+        for line in [3, 9] {
+            graph.add_dynamic_pattern(DynamicPattern {
+                kind: DynamicPatternKind::Eval,
+                location: Location::new(PathBuf::from("test.ts"), 0, 10, line, 1),
+                affected_symbols: vec![dead.symbol.id],
+            });
+        }
+
+        let scored = score(vec![dead], &graph, &config);
+
+        // Each eval pattern applies its own weight
+        let factors: Vec<_> = scored[0]
+            .confidence_factors
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        assert_eq!(
+            factors,
+            vec!["affected by eval -40", "affected by eval -40"]
+        );
+        assert_eq!(scored[0].confidence_score, 20);
+    }
+
+    #[test]
+    fn test_git_history_adjusts_confidence() {
+        let graph = CallGraph::new();
+        let config = Config::default();
+        let history = |age_days| GitHistory {
+            last_modified: 0,
+            age_days,
+            authors: 1,
+            freshness: config.history.freshness(age_days),
+        };
+
+        let mut stale = make_dead_symbol("old", true, false);
+        stale.history = Some(history(400));
+        let mut new = make_dead_symbol("added", true, false);
+        new.history = Some(history(2));
+        let mut active = make_dead_symbol("recent", true, false);
+        active.history = Some(history(60));
+
+        let scored = score(vec![stale, new, active], &graph, &config);
+
+        // 100 - 10 (exported) + 10 (stale) = 100
+        assert_eq!(scored[0].confidence_score, 100);
+        assert_eq!(
+            scored[0].confidence_factors[1].to_string(),
+            "untouched for 400 days +10"
+        );
+        // 100 - 10 (exported) - 10 (new code) = 80
+        assert_eq!(scored[1].confidence_score, 80);
+        // 100 - 10 (exported) = 90
+        assert_eq!(scored[2].confidence_score, 90);
     }
 
     #[test]
@@ -191,7 +648,7 @@ mod tests {
         let config = Config::default();
         let dead_symbols = vec![make_dead_symbol("_privateHelper", false, false)];
 
-        let scored = score_dead_symbols(dead_symbols, &graph, &config);
+        let scored = score(dead_symbols, &graph, &config);
 
         // 100 + 5 (private convention) = 100 (clamped)
         assert_eq!(scored[0].confidence_score, 100);
@@ -0,0 +1,95 @@
+//! Istanbul `coverage-final.json` parsing.
+
+use super::{CoverageData, CoverageRange, FunctionCoverage};
+use serde_json::Value;
+use std::path::Path;
+
+/// Parse Istanbul's per-file `fnMap`/`f` function coverage.
+pub fn parse(json: &Value, root: &Path, data: &mut CoverageData) -> Result<(), String> {
+    let files = json
+        .as_object()
+        .ok_or("expected an object keyed by file path")?;
+
+    for (key, file) in files {
+        let path = file.get("path").and_then(Value::as_str).unwrap_or(key);
+        let (Some(fn_map), Some(counts)) = (
+            file.get("fnMap").and_then(Value::as_object),
+            file.get("f").and_then(Value::as_object),
+        ) else {
+            return Err(format!("missing fnMap or f for {}", path));
+        };
+
+        for (id, function) in fn_map {
+            let line = |field: &str, end: &str| {
+                function
+                    .pointer(&format!("/{}/{}/line", field, end))
+                    .and_then(Value::as_u64)
+                    .map(|l| l as u32)
+            };
+
+            let Some(start) = line("loc", "start").or_else(|| line("decl", "start")) else {
+                continue;
+            };
+
+            data.add(
+                root,
+                path,
+                FunctionCoverage {
+                    name: function
+                        .get("name")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                    range: CoverageRange::Lines {
+                        start,
+                        end: line("loc", "end"),
+                    },
+                    hits: counts.get(id).and_then(Value::as_u64).unwrap_or(0),
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_istanbul() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "/project/src/a.ts": {
+                    "path": "/project/src/a.ts",
+                    "fnMap": {
+                        "0": {
+                            "name": "used",
+                            "decl": { "start": { "line": 1, "column": 16 }, "end": { "line": 1, "column": 20 } },
+                            "loc": { "start": { "line": 1, "column": 0 }, "end": { "line": 3, "column": 1 } }
+                        }
+                    },
+                    "f": { "0": 7 }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mut data = CoverageData::default();
+        parse(&json, Path::new("/project"), &mut data).unwrap();
+
+        assert_eq!(
+            data.files[&PathBuf::from("/project/src/a.ts")],
+            vec![FunctionCoverage {
+                name: "used".to_string(),
+                range: CoverageRange::Lines {
+                    start: 1,
+                    end: Some(3)
+                },
+                hits: 7,
+            }]
+        );
+    }
+}
@@ -0,0 +1,75 @@
+//! lcov tracefile parsing.
+
+use super::{CoverageData, CoverageRange, FunctionCoverage};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Parse `FN`/`FNDA` records from an lcov tracefile.
+///
+/// Both `FN:<line>,<name>` and the lcov 2 form `FN:<start>,<end>,<name>` are
+/// accepted.
+pub fn parse(content: &str, root: &Path, data: &mut CoverageData) -> Result<(), String> {
+    let mut file: Option<&str> = None;
+    let mut functions: Vec<(String, CoverageRange)> = Vec::new();
+    let mut hits: HashMap<String, u64> = HashMap::new();
+
+    for (number, line) in content.lines().enumerate() {
+        let line = line.trim();
+        let invalid = || format!("invalid record on line {}: {}", number + 1, line);
+
+        if let Some(path) = line.strip_prefix("SF:") {
+            file = Some(path);
+        } else if let Some(record) = line.strip_prefix("FN:") {
+            let mut parts = record.splitn(3, ',');
+            let start = parts
+                .next()
+                .and_then(|p| p.parse().ok())
+                .ok_or_else(invalid)?;
+            let (end, name) = match (parts.next(), parts.next()) {
+                (Some(end), Some(name)) => (Some(end.parse().map_err(|_| invalid())?), name),
+                (Some(name), None) => (None, name),
+                _ => return Err(invalid()),
+            };
+            functions.push((name.to_string(), CoverageRange::Lines { start, end }));
+        } else if let Some(record) = line.strip_prefix("FNDA:") {
+            let (count, name) = record.split_once(',').ok_or_else(invalid)?;
+            let count: u64 = count.parse().map_err(|_| invalid())?;
+            *hits.entry(name.to_string()).or_default() += count;
+        } else if line == "end_of_record" {
+            let path = file.take().ok_or_else(invalid)?;
+            for (name, range) in functions.drain(..) {
+                let hits = hits.get(&name).copied().unwrap_or(0);
+                data.add(root, path, FunctionCoverage { name, range, hits });
+            }
+            hits.clear();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_lcov() {
+        let content = "TN:\nSF:src/a.ts\nFN:1,used\nFN:5,9,unused\nFNDA:3,used\nFNDA:0,unused\nDA:1,3\nend_of_record\n";
+
+        let mut data = CoverageData::default();
+        parse(content, Path::new("/project"), &mut data).unwrap();
+
+        let functions = &data.files[&PathBuf::from("/project/src/a.ts")];
+        assert_eq!(functions.len(), 2);
+        assert_eq!(functions[0].hits, 3);
+        assert_eq!(
+            functions[1].range,
+            CoverageRange::Lines {
+                start: 5,
+                end: Some(9)
+            }
+        );
+        assert_eq!(functions[1].hits, 0);
+    }
+}
@@ -0,0 +1,254 @@
+//! Runtime coverage ingestion.
+//!
+//! Function-level coverage from Istanbul, lcov or V8, and production runtime
+//! traces, are mapped onto symbol locations so that executed and
+//! never-executed code can adjust confidence.
+
+mod istanbul;
+mod lcov;
+mod trace;
+mod v8;
+
+pub use trace::{mark_traced_entry_points, never_executed_symbols, trace_execution};
+
+use crate::core::{normalize_path, DddError, DeadSymbol, Execution, Result, TrackedSymbol};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Where a covered function lives in its source file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CoverageRange {
+    /// 1-based lines; the end line is unknown for plain lcov.
+    Lines { start: u32, end: Option<u32> },
+    /// Source offsets.
+    Offsets { start: u32, end: u32 },
+    /// A single source offset anywhere in the function's declaration.
+    Offset(u32),
+    /// Matched by symbol name only.
+    Named,
+}
+
+/// Execution count for a single function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FunctionCoverage {
+    pub name: String,
+    pub range: CoverageRange,
+    pub hits: u64,
+}
+
+/// Function coverage for a set of files.
+#[derive(Debug, Clone, Default)]
+pub struct CoverageData {
+    files: HashMap<PathBuf, Vec<FunctionCoverage>>,
+}
+
+impl CoverageData {
+    /// Load coverage from a file, detecting its format from the content.
+    ///
+    /// Relative source paths in the coverage data are resolved against `root`.
+    pub fn load(path: &Path, root: &Path) -> Result<Self> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+
+        Self::parse(&content, root)
+            .map_err(|message| DddError::invalid_coverage(path.to_path_buf(), message))
+    }
+
+    /// Parse coverage data in any supported format.
+    pub fn parse(content: &str, root: &Path) -> std::result::Result<Self, String> {
+        let mut data = Self::default();
+        let trimmed = content.trim_start();
+
+        if trimmed.starts_with('{') || trimmed.starts_with('[') {
+            let json: serde_json::Value =
+                serde_json::from_str(content).map_err(|e| format!("invalid JSON: {}", e))?;
+
+            if v8::is_v8(&json) {
+                v8::parse(&json, root, &mut data)?;
+            } else {
+                istanbul::parse(&json, root, &mut data)?;
+            }
+        } else if trimmed.starts_with("TN:") || trimmed.starts_with("SF:") {
+            lcov::parse(content, root, &mut data)?;
+        } else {
+            return Err("unrecognized coverage format".to_string());
+        }
+
+        Ok(data)
+    }
+
+    /// Load a runtime trace in the JSON Lines format documented in `trace.rs`.
+    pub fn load_trace(path: &Path, root: &Path) -> Result<Self> {
+        let content =
+            std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+
+        let mut data = Self::default();
+        trace::parse(&content, root, &mut data)
+            .map_err(|message| DddError::invalid_trace(path.to_path_buf(), message))?;
+        Ok(data)
+    }
+
+    /// Number of files with coverage data.
+    pub fn file_count(&self) -> usize {
+        self.files.len()
+    }
+
+    /// Check whether the data has any records for a file.
+    pub fn covers_file(&self, path: &Path) -> bool {
+        self.files.contains_key(&normalize_path(path))
+    }
+
+    /// Record coverage for a function in a file.
+    fn add(&mut self, root: &Path, file: &str, function: FunctionCoverage) {
+        let path = resolve_source_path(root, file);
+        self.files.entry(path).or_default().push(function);
+    }
+
+    /// Determine whether a symbol's code ran.
+    ///
+    /// Returns `None` when no covered function falls within the symbol, e.g.
+    /// for types or files missing from the coverage data.
+    pub fn execution(&self, symbol: &TrackedSymbol) -> Option<Execution> {
+        let functions = self
+            .files
+            .get(&normalize_path(&symbol.location.file_path))?;
+        let location = &symbol.location;
+
+        let mut covered = false;
+        let mut hits = 0u64;
+
+        for function in functions {
+            let within = match function.range {
+                CoverageRange::Lines { start, end } => {
+                    (location.line..=location.end_line).contains(&start)
+                        && end.is_none_or(|end| end <= location.end_line)
+                }
+                // Offset ranges start at the `function` keyword, which may
+                // precede the symbol's name, so match on where they end
+                CoverageRange::Offsets { start, end } => {
+                    start < location.end_offset
+                        && end > location.start_offset
+                        && end <= location.end_offset
+                }
+                CoverageRange::Offset(offset) => {
+                    (location.declaration_start..location.end_offset).contains(&offset)
+                }
+                CoverageRange::Named => function.name == symbol.name,
+            };
+
+            if within {
+                covered = true;
+                hits = hits.max(function.hits);
+            }
+        }
+
+        match (covered, hits) {
+            (false, _) => None,
+            (true, 0) => Some(Execution::NeverExecuted),
+            (true, hits) => Some(Execution::Executed { hits }),
+        }
+    }
+}
+
+/// Attach coverage evidence to dead symbols.
+pub fn annotate_dead_symbols(dead_symbols: &mut [DeadSymbol], coverage: &CoverageData) {
+    for dead in dead_symbols {
+        dead.execution = coverage.execution(&dead.symbol);
+    }
+}
+
+/// Resolve a path or `file://` URL from coverage data to a normalized path.
+fn resolve_source_path(root: &Path, file: &str) -> PathBuf {
+    let path = match file.strip_prefix("file://") {
+        Some(rest) => url::Url::parse(file)
+            .ok()
+            .and_then(|url| url.to_file_path().ok())
+            .unwrap_or_else(|| PathBuf::from(rest)),
+        None => PathBuf::from(file),
+    };
+    normalize_path(&root.join(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileId, Location, SymbolId, SymbolKind};
+
+    fn make_symbol(line: u32, end_line: u32, start: u32, end: u32) -> TrackedSymbol {
+        TrackedSymbol::new(
+            SymbolId::new(0),
+            "handler".to_string(),
+            SymbolKind::Function,
+            Location::new(PathBuf::from("/project/./src/a.ts"), start, end, line, 10)
+                .with_end_line(end_line),
+            FileId::new(0),
+        )
+    }
+
+    fn coverage(functions: Vec<FunctionCoverage>) -> CoverageData {
+        let mut data = CoverageData::default();
+        for function in functions {
+            data.add(Path::new("/project"), "src/a.ts", function);
+        }
+        data
+    }
+
+    fn function(range: CoverageRange, hits: u64) -> FunctionCoverage {
+        FunctionCoverage {
+            name: "handler".to_string(),
+            range,
+            hits,
+        }
+    }
+
+    #[test]
+    fn test_line_ranges_map_to_symbols() {
+        let data = coverage(vec![function(
+            CoverageRange::Lines {
+                start: 3,
+                end: Some(5),
+            },
+            0,
+        )]);
+
+        assert_eq!(
+            data.execution(&make_symbol(3, 5, 0, 0)),
+            Some(Execution::NeverExecuted)
+        );
+        assert_eq!(data.execution(&make_symbol(7, 9, 0, 0)), None);
+    }
+
+    #[test]
+    fn test_offset_ranges_map_to_symbols() {
+        // `function handler() {}` at offset 20; the name starts at 29
+        let data = coverage(vec![
+            function(CoverageRange::Offsets { start: 0, end: 200 }, 1),
+            function(CoverageRange::Offsets { start: 20, end: 41 }, 4),
+        ]);
+
+        assert_eq!(
+            data.execution(&make_symbol(2, 2, 29, 41)),
+            Some(Execution::Executed { hits: 4 })
+        );
+    }
+
+    #[test]
+    fn test_file_urls_are_percent_decoded() {
+        assert_eq!(
+            resolve_source_path(
+                Path::new("/root"),
+                "file:///project/my%20app/caf%C3%A9%23.ts"
+            ),
+            PathBuf::from("/project/my app/café#.ts")
+        );
+        assert_eq!(
+            resolve_source_path(Path::new("/project"), "./src/a%20b.ts"),
+            PathBuf::from("/project/src/a%20b.ts")
+        );
+    }
+
+    #[test]
+    fn test_unknown_format_is_rejected() {
+        assert!(CoverageData::parse("hello", Path::new("/project")).is_err());
+    }
+}
@@ -0,0 +1,267 @@
+//! Runtime trace ingestion.
+//!
+//! A trace is a JSON Lines file with one record per observed function:
+//!
+//! ```text
+//! {"file": "src/api/users.ts", "line": 42, "hits": 1200}
+//! {"file": "src/api/users.ts", "offset": 1893, "hits": 3}
+//! {"file": "src/util/format.ts", "name": "formatDate", "hits": 87}
+//! ```
+//!
+//! `file` is resolved against the project root. A record locates its symbol by
+//! the 1-based `line` it starts on, an `offset` anywhere in its declaration
+//! from the first keyword such as `export` or `async` to its end, or its
+//! `name`. `hits` defaults to 1; a record with `"hits": 0` states that the
+//! function was instrumented but never ran. Blank lines are ignored.
+//!
+//! Files that appear in the trace are treated as fully instrumented: their
+//! functions without a matching record were never executed.
+
+use super::{CoverageData, CoverageRange, FunctionCoverage};
+use crate::core::{CallGraph, EntryPointSource, Execution, SymbolId, SymbolKind, TrackedSymbol};
+use std::collections::HashSet;
+use std::path::Path;
+
+/// A single trace record.
+#[derive(Debug, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TraceRecord {
+    file: String,
+    line: Option<u32>,
+    offset: Option<u32>,
+    name: Option<String>,
+    hits: Option<u64>,
+}
+
+/// Parse a JSON Lines runtime trace.
+pub fn parse(content: &str, root: &Path, data: &mut CoverageData) -> Result<(), String> {
+    for (number, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let record: TraceRecord =
+            serde_json::from_str(line).map_err(|e| format!("line {}: {}", number + 1, e))?;
+
+        let range = match (record.line, record.offset, &record.name) {
+            (Some(line), _, _) => CoverageRange::Lines {
+                start: line,
+                end: None,
+            },
+            (None, Some(offset), _) => CoverageRange::Offset(offset),
+            (None, None, Some(_)) => CoverageRange::Named,
+            (None, None, None) => {
+                return Err(format!(
+                    "line {}: record needs a line, offset or name",
+                    number + 1
+                ))
+            }
+        };
+
+        data.add(
+            root,
+            &record.file,
+            FunctionCoverage {
+                name: record.name.unwrap_or_default(),
+                range,
+                hits: record.hits.unwrap_or(1),
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Check whether a symbol is code that a runtime trace would record.
+fn is_traceable(symbol: &TrackedSymbol) -> bool {
+    matches!(
+        symbol.kind,
+        SymbolKind::Function | SymbolKind::ArrowFunction | SymbolKind::Method
+    )
+}
+
+/// Determine a symbol's execution according to a trace.
+///
+/// Traceable symbols in traced files that match no record never ran.
+pub fn trace_execution(trace: &CoverageData, symbol: &TrackedSymbol) -> Option<Execution> {
+    trace.execution(symbol).or_else(|| {
+        (is_traceable(symbol) && trace.covers_file(&symbol.location.file_path))
+            .then_some(Execution::NeverExecuted)
+    })
+}
+
+/// Mark every symbol observed executing as an entry point.
+pub fn mark_traced_entry_points(call_graph: &mut CallGraph, trace: &CoverageData) {
+    let observed: Vec<_> = call_graph
+        .symbols
+        .values()
+        .filter_map(|symbol| match trace.execution(symbol) {
+            Some(Execution::Executed { hits }) => Some((symbol.id, hits)),
+            _ => None,
+        })
+        .collect();
+
+    for (id, hits) in observed {
+        call_graph.mark_entry_point(id, EntryPointSource::RuntimeTrace { hits });
+    }
+}
+
+/// Find statically alive symbols that the trace never saw execute.
+pub fn never_executed_symbols(
+    call_graph: &CallGraph,
+    trace: &CoverageData,
+    dead: &HashSet<SymbolId>,
+) -> Vec<TrackedSymbol> {
+    let mut symbols: Vec<_> = call_graph
+        .symbols
+        .values()
+        .filter(|symbol| !dead.contains(&symbol.id) && is_traceable(symbol))
+        .filter(|symbol| trace_execution(trace, symbol) == Some(Execution::NeverExecuted))
+        .cloned()
+        .collect();
+
+    symbols.sort_by(|a, b| {
+        a.location
+            .file_path
+            .cmp(&b.location.file_path)
+            .then(a.location.line.cmp(&b.location.line))
+    });
+    symbols
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileId, Location};
+    use std::path::PathBuf;
+
+    fn make_symbol(id: u32, name: &str, line: u32) -> TrackedSymbol {
+        TrackedSymbol::new(
+            SymbolId::new(id),
+            name.to_string(),
+            SymbolKind::Function,
+            Location::new(
+                PathBuf::from("/project/src/a.ts"),
+                line * 100,
+                line * 100 + 50,
+                line,
+                10,
+            )
+            .with_end_line(line + 2),
+            FileId::new(0),
+        )
+    }
+
+    fn load(content: &str) -> CoverageData {
+        let mut data = CoverageData::default();
+        parse(content, Path::new("/project"), &mut data).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_trace_records_locate_symbols() {
+        let trace = load(concat!(
+            "{\"file\": \"src/a.ts\", \"line\": 1, \"hits\": 12}\n",
+            "\n",
+            "{\"file\": \"src/a.ts\", \"offset\": 420}\n",
+            "{\"file\": \"src/a.ts\", \"name\": \"named\", \"hits\": 2}\n",
+        ));
+
+        assert_eq!(
+            trace_execution(&trace, &make_symbol(0, "byLine", 1)),
+            Some(Execution::Executed { hits: 12 })
+        );
+        assert_eq!(
+            trace_execution(&trace, &make_symbol(1, "byOffset", 4)),
+            Some(Execution::Executed { hits: 1 })
+        );
+        assert_eq!(
+            trace_execution(&trace, &make_symbol(2, "named", 7)),
+            Some(Execution::Executed { hits: 2 })
+        );
+        assert_eq!(
+            trace_execution(&trace, &make_symbol(3, "idle", 10)),
+            Some(Execution::NeverExecuted)
+        );
+    }
+
+    #[test]
+    fn test_offsets_run_from_name_to_declaration_end() {
+        let symbol = make_symbol(0, "handler", 4);
+        for (offset, hit) in [(391, false), (400, true), (449, true), (450, false)] {
+            let trace = load(&format!(
+                "{{\"file\": \"src/a.ts\", \"offset\": {}}}",
+                offset
+            ));
+            let expected = if hit {
+                Execution::Executed { hits: 1 }
+            } else {
+                Execution::NeverExecuted
+            };
+            assert_eq!(
+                trace_execution(&trace, &symbol),
+                Some(expected),
+                "offset {}",
+                offset
+            );
+        }
+    }
+
+    #[test]
+    fn test_offsets_include_declaration_keywords() {
+        // `export async function handler` with the name at offset 400
+        let mut symbol = make_symbol(0, "handler", 4);
+        symbol.location = symbol.location.with_declaration_start(378);
+        for (offset, hit) in [
+            (377, false),
+            (378, true),
+            (385, true),
+            (391, true),
+            (450, false),
+        ] {
+            let trace = load(&format!(
+                "{{\"file\": \"src/a.ts\", \"offset\": {}}}",
+                offset
+            ));
+            let expected = if hit {
+                Execution::Executed { hits: 1 }
+            } else {
+                Execution::NeverExecuted
+            };
+            assert_eq!(
+                trace_execution(&trace, &symbol),
+                Some(expected),
+                "offset {}",
+                offset
+            );
+        }
+    }
+
+    #[test]
+    fn test_invalid_records_are_rejected() {
+        let mut data = CoverageData::default();
+        let root = Path::new("/project");
+
+        assert!(parse("{\"file\": \"src/a.ts\"}", root, &mut data).is_err());
+        assert!(parse("not json", root, &mut data).is_err());
+    }
+
+    #[test]
+    fn test_never_executed_excludes_dead_and_hit_symbols() {
+        let trace = load("{\"file\": \"src/a.ts\", \"line\": 1}\n");
+        let mut graph = CallGraph::new();
+        for symbol in [
+            make_symbol(0, "hit", 1),
+            make_symbol(1, "idle", 4),
+            make_symbol(2, "dead", 7),
+        ] {
+            graph.add_symbol(symbol);
+        }
+
+        let dead: HashSet<_> = [SymbolId::new(2)].into_iter().collect();
+        let idle = never_executed_symbols(&graph, &trace, &dead);
+
+        assert_eq!(idle.len(), 1);
+        assert_eq!(idle[0].name, "idle");
+    }
+}
@@ -0,0 +1,112 @@
+//! V8 JSON coverage parsing, as written by `NODE_V8_COVERAGE`.
+
+use super::{CoverageData, CoverageRange, FunctionCoverage};
+use serde_json::Value;
+use std::path::Path;
+
+/// Check whether a JSON document is V8 coverage output.
+pub fn is_v8(json: &Value) -> bool {
+    json.get("result").is_some_and(Value::is_array)
+}
+
+/// Parse per-function byte ranges from V8 coverage.
+///
+/// Scripts that are not `file://` URLs, such as Node internals, are skipped.
+pub fn parse(json: &Value, root: &Path, data: &mut CoverageData) -> Result<(), String> {
+    let scripts = json
+        .get("result")
+        .and_then(Value::as_array)
+        .ok_or("expected a result array")?;
+
+    for script in scripts {
+        let Some(url) = script.get("url").and_then(Value::as_str) else {
+            continue;
+        };
+        if !url.starts_with("file://") {
+            continue;
+        }
+
+        let functions = script
+            .get("functions")
+            .and_then(Value::as_array)
+            .ok_or_else(|| format!("missing functions for {}", url))?;
+
+        for function in functions {
+            let name = function
+                .get("functionName")
+                .and_then(Value::as_str)
+                .unwrap_or_default();
+
+            // The first range spans the whole function
+            let Some(range) = function
+                .get("ranges")
+                .and_then(Value::as_array)
+                .and_then(|ranges| ranges.first())
+            else {
+                continue;
+            };
+
+            let offset = |field: &str| range.get(field).and_then(Value::as_u64).map(|v| v as u32);
+            let (Some(start), Some(end)) = (offset("startOffset"), offset("endOffset")) else {
+                continue;
+            };
+
+            // The anonymous function at offset 0 is the module itself
+            if name.is_empty() && start == 0 {
+                continue;
+            }
+
+            data.add(
+                root,
+                url,
+                FunctionCoverage {
+                    name: name.to_string(),
+                    range: CoverageRange::Offsets { start, end },
+                    hits: range.get("count").and_then(Value::as_u64).unwrap_or(0),
+                },
+            );
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_parse_v8() {
+        let json: Value = serde_json::from_str(
+            r#"{
+                "result": [
+                    { "scriptId": "1", "url": "node:internal/main", "functions": [] },
+                    {
+                        "scriptId": "2",
+                        "url": "file:///project/src/a.js",
+                        "functions": [
+                            { "functionName": "", "ranges": [{ "startOffset": 0, "endOffset": 90, "count": 1 }], "isBlockCoverage": true },
+                            { "functionName": "unused", "ranges": [{ "startOffset": 10, "endOffset": 40, "count": 0 }], "isBlockCoverage": true }
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert!(is_v8(&json));
+
+        let mut data = CoverageData::default();
+        parse(&json, Path::new("/ignored"), &mut data).unwrap();
+
+        assert_eq!(
+            data.files[&PathBuf::from("/project/src/a.js")],
+            vec![FunctionCoverage {
+                name: "unused".to_string(),
+                range: CoverageRange::Offsets { start: 10, end: 40 },
+                hits: 0,
+            }]
+        );
+    }
+}
@@ -0,0 +1,232 @@
+//! Circular import detection over the file-level import graph.
+//!
+//! Cycles are the strongly connected components of the graph of eager
+//! imports; `import()` and type-only imports don't affect load order, so
+//! they are left out.
+
+use crate::core::{relative_to, AnalysisWarning, CallGraph, FileId, ImportEdge, WarningKind};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// A set of files that import each other, directly or indirectly.
+#[derive(Debug, Clone)]
+pub struct ImportCycle {
+    /// Files in the cycle, sorted by path.
+    pub files: Vec<FileId>,
+    /// The imports between files in the cycle, sorted by location.
+    pub edges: Vec<ImportEdge>,
+}
+
+/// Find all import cycles, sorted by the path of their first file.
+pub fn find_import_cycles(call_graph: &CallGraph) -> Vec<ImportCycle> {
+    let path = |id: &FileId| call_graph.files.get(id).map(|f| f.path.clone());
+
+    let mut adjacent: HashMap<FileId, Vec<FileId>> = HashMap::new();
+    for edge in call_graph.import_edges.iter().filter(|e| e.eager) {
+        adjacent.entry(edge.from).or_default().push(edge.to);
+    }
+    // Visit files in path order so component order is deterministic
+    let mut files: Vec<FileId> = adjacent.keys().copied().collect();
+    files.sort_by_key(&path);
+
+    let mut cycles: Vec<ImportCycle> = strongly_connected_components(&files, &adjacent)
+        .into_iter()
+        .filter(|component| {
+            component.len() > 1
+                || adjacent
+                    .get(&component[0])
+                    .is_some_and(|targets| targets.contains(&component[0]))
+        })
+        .map(|mut files| {
+            files.sort_by_key(&path);
+            let members: HashSet<FileId> = files.iter().copied().collect();
+            let mut edges: Vec<ImportEdge> = call_graph
+                .import_edges
+                .iter()
+                .filter(|e| e.eager && members.contains(&e.from) && members.contains(&e.to))
+                .cloned()
+                .collect();
+            edges.sort_by(|a, b| {
+                (&a.location.file_path, a.location.start_offset)
+                    .cmp(&(&b.location.file_path, b.location.start_offset))
+            });
+            ImportCycle { files, edges }
+        })
+        .collect();
+
+    cycles.sort_by_key(|cycle| path(&cycle.files[0]));
+    cycles
+}
+
+/// Report each import cycle as a warning at its first import, naming files
+/// relative to `root`.
+pub fn cycle_warnings(
+    call_graph: &CallGraph,
+    cycles: &[ImportCycle],
+    root: &Path,
+) -> Vec<AnalysisWarning> {
+    cycles
+        .iter()
+        .map(|cycle| {
+            let files: Vec<String> = cycle
+                .files
+                .iter()
+                .filter_map(|id| call_graph.files.get(id))
+                .map(|f| relative_to(Some(root), &f.path))
+                .collect();
+            let message = match files.as_slice() {
+                [file] => format!("Import cycle: {} imports itself", file),
+                _ => format!(
+                    "Import cycle between {} files: {}",
+                    files.len(),
+                    files.join(", ")
+                ),
+            };
+            AnalysisWarning {
+                kind: WarningKind::CircularDependency,
+                message,
+                location: cycle.edges.first().map(|e| e.location.clone()),
+            }
+        })
+        .collect()
+}
+
+/// Tarjan's algorithm, iterative so deep import chains can't overflow the stack.
+fn strongly_connected_components(
+    files: &[FileId],
+    adjacent: &HashMap<FileId, Vec<FileId>>,
+) -> Vec<Vec<FileId>> {
+    let mut index: HashMap<FileId, usize> = HashMap::new();
+    let mut low_link: HashMap<FileId, usize> = HashMap::new();
+    let mut stack: Vec<FileId> = Vec::new();
+    let mut on_stack: HashSet<FileId> = HashSet::new();
+    let mut components = Vec::new();
+
+    for &start in files {
+        if index.contains_key(&start) {
+            continue;
+        }
+
+        // Each frame is a file and the position of the next import to follow
+        let mut frames: Vec<(FileId, usize)> = vec![(start, 0)];
+        while let Some(&(file, next)) = frames.last() {
+            if next == 0 && !index.contains_key(&file) {
+                let n = index.len();
+                index.insert(file, n);
+                low_link.insert(file, n);
+                stack.push(file);
+                on_stack.insert(file);
+            }
+
+            let targets = adjacent.get(&file).map_or(&[][..], |v| v.as_slice());
+            if let Some(&target) = targets.get(next) {
+                if let Some(frame) = frames.last_mut() {
+                    frame.1 += 1;
+                }
+                if !index.contains_key(&target) {
+                    frames.push((target, 0));
+                } else if on_stack.contains(&target) {
+                    let low = low_link[&file].min(index[&target]);
+                    low_link.insert(file, low);
+                }
+                continue;
+            }
+
+            // All imports followed: close the component if this file is its root
+            frames.pop();
+            if let Some(&(parent, _)) = frames.last() {
+                let low = low_link[&parent].min(low_link[&file]);
+                low_link.insert(parent, low);
+            }
+            if low_link[&file] == index[&file] {
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack.remove(&member);
+                    component.push(member);
+                    if member == file {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+        }
+    }
+
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileInfo, Location};
+    use std::path::PathBuf;
+
+    fn make_graph(edges: &[(u32, u32, bool)]) -> CallGraph {
+        let mut graph = CallGraph::new();
+        for id in 0..5 {
+            graph.add_file(FileInfo {
+                id: FileId::new(id),
+                path: PathBuf::from(format!("/repo/./src/{}.ts", id)),
+                has_side_effects: false,
+                has_dynamic_eval: false,
+                symbols: Vec::new(),
+                suppressions: Vec::new(),
+            });
+        }
+        for &(from, to, eager) in edges {
+            graph.add_import_edge(ImportEdge {
+                from: FileId::new(from),
+                to: FileId::new(to),
+                location: Location::new(
+                    PathBuf::from(format!("/repo/src/{}.ts", from)),
+                    to,
+                    to + 10,
+                    1,
+                    1,
+                ),
+                eager,
+            });
+        }
+        graph
+    }
+
+    fn files(cycle: &ImportCycle) -> Vec<u32> {
+        cycle.files.iter().map(|id| id.0).collect()
+    }
+
+    #[test]
+    fn test_find_import_cycles() {
+        // 0 -> 1 -> 2 -> 0 is a cycle, 2 -> 3 is not, 4 imports itself
+        let graph = make_graph(&[
+            (0, 1, true),
+            (1, 2, true),
+            (2, 0, true),
+            (2, 3, true),
+            (4, 4, true),
+        ]);
+        let cycles = find_import_cycles(&graph);
+
+        assert_eq!(cycles.len(), 2);
+        assert_eq!(files(&cycles[0]), vec![0, 1, 2]);
+        assert_eq!(cycles[0].edges.len(), 3);
+        assert_eq!(files(&cycles[1]), vec![4]);
+
+        let warnings = cycle_warnings(&graph, &cycles, Path::new("/repo/."));
+        assert_eq!(warnings[0].kind, WarningKind::CircularDependency);
+        assert_eq!(
+            warnings[0].message,
+            "Import cycle between 3 files: src/0.ts, src/1.ts, src/2.ts"
+        );
+        assert_eq!(warnings[1].message, "Import cycle: src/4.ts imports itself");
+        assert_eq!(
+            warnings[0].location.as_ref().unwrap().file_path,
+            PathBuf::from("/repo/src/0.ts")
+        );
+    }
+
+    #[test]
+    fn test_lazy_imports_break_cycles() {
+        let graph = make_graph(&[(0, 1, true), (1, 0, false)]);
+        assert!(find_import_cycles(&graph).is_empty());
+    }
+}
@@ -0,0 +1,345 @@
+//! Removal impact estimation.
+//!
+//! Deleting a root dead symbol also frees every dead symbol it dominates in
+//! the dead subgraph: symbols whose only paths from the dead roots pass
+//! through it.
+
+use crate::core::{CallGraph, DeadSymbol, DeadnessReason, Location, RemovalImpact, SymbolId};
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+/// Index of the virtual node that precedes every root dead symbol.
+const VIRTUAL_ROOT: usize = 0;
+
+/// Compute the removal impact of each root (non-transitive) dead symbol.
+pub fn compute_removal_impact(dead_symbols: &mut [DeadSymbol], call_graph: &CallGraph) {
+    // Node 0 is the virtual root; dead symbols are numbered from 1
+    let mut index: HashMap<SymbolId, usize> = HashMap::new();
+    for (i, dead) in dead_symbols.iter().enumerate() {
+        index.insert(dead.symbol.id, i + 1);
+    }
+
+    let node_count = dead_symbols.len() + 1;
+    let mut successors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+    let mut predecessors: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+
+    for (i, dead) in dead_symbols.iter().enumerate() {
+        let from = i + 1;
+        if is_root(dead) {
+            successors[VIRTUAL_ROOT].push(from);
+            predecessors[from].push(VIRTUAL_ROOT);
+        }
+
+        let mut seen = HashSet::new();
+        for ref_id in call_graph.get_outgoing_refs(dead.symbol.id) {
+            if let Some(&to) = index.get(ref_id) {
+                if to != from && seen.insert(to) {
+                    successors[from].push(to);
+                    predecessors[to].push(from);
+                }
+            }
+        }
+    }
+
+    let idom = immediate_dominators(&successors, &predecessors);
+
+    // Build the dominator tree
+    let mut children: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+    for (node, dominator) in idom.iter().enumerate() {
+        if let Some(dominator) = *dominator {
+            if node != VIRTUAL_ROOT {
+                children[dominator].push(node);
+            }
+        }
+    }
+
+    let impacts: Vec<(usize, RemovalImpact)> = successors[VIRTUAL_ROOT]
+        .iter()
+        .map(|&root| {
+            let mut subtree = vec![root];
+            let mut stack = children[root].clone();
+            while let Some(node) = stack.pop() {
+                subtree.push(node);
+                stack.extend(children[node].iter().copied());
+            }
+
+            let locations: Vec<&Location> = subtree
+                .iter()
+                .map(|&n| &dead_symbols[n - 1].symbol.location)
+                .collect();
+            let (lines, bytes) = freed_size(&locations);
+
+            let mut dominated: Vec<SymbolId> = subtree[1..]
+                .iter()
+                .map(|&n| dead_symbols[n - 1].symbol.id)
+                .collect();
+            dominated.sort_by_key(|id| id.0);
+
+            (
+                root,
+                RemovalImpact {
+                    dominated,
+                    lines,
+                    bytes,
+                },
+            )
+        })
+        .collect();
+
+    for (root, impact) in impacts {
+        dead_symbols[root - 1].impact = Some(impact);
+    }
+}
+
+/// Sort dead symbols so the biggest removal wins come first.
+///
+/// Symbols without an impact estimate follow in file and line order.
+pub fn sort_by_impact<T: Borrow<DeadSymbol>>(dead_symbols: &mut [T]) {
+    dead_symbols.sort_by(|a, b| {
+        let (a, b) = (a.borrow(), b.borrow());
+        let size = |d: &DeadSymbol| d.impact.as_ref().map_or((0, 0), |i| (i.lines, i.bytes));
+        size(b)
+            .cmp(&size(a))
+            .then_with(|| {
+                a.symbol
+                    .location
+                    .file_path
+                    .cmp(&b.symbol.location.file_path)
+            })
+            .then_with(|| a.symbol.location.line.cmp(&b.symbol.location.line))
+    });
+}
+
+fn is_root(dead: &DeadSymbol) -> bool {
+    !matches!(dead.reason, DeadnessReason::Transitive { .. })
+}
+
+/// Compute immediate dominators from the virtual root.
+///
+/// Uses the iterative algorithm from Cooper, Harvey and Kennedy, "A Simple,
+/// Fast Dominance Algorithm". Nodes unreachable from the root (dead cycles)
+/// get no dominator.
+fn immediate_dominators(
+    successors: &[Vec<usize>],
+    predecessors: &[Vec<usize>],
+) -> Vec<Option<usize>> {
+    let node_count = successors.len();
+
+    // Iterative DFS to number nodes in postorder
+    let mut postorder = Vec::with_capacity(node_count);
+    let mut visited = vec![false; node_count];
+    let mut stack = vec![(VIRTUAL_ROOT, 0usize)];
+    visited[VIRTUAL_ROOT] = true;
+
+    while let Some((node, next_child)) = stack.pop() {
+        if let Some(&child) = successors[node].get(next_child) {
+            stack.push((node, next_child + 1));
+            if !visited[child] {
+                visited[child] = true;
+                stack.push((child, 0));
+            }
+        } else {
+            postorder.push(node);
+        }
+    }
+
+    let mut postorder_number = vec![usize::MAX; node_count];
+    for (n, &node) in postorder.iter().enumerate() {
+        postorder_number[node] = n;
+    }
+
+    let mut idom: Vec<Option<usize>> = vec![None; node_count];
+    idom[VIRTUAL_ROOT] = Some(VIRTUAL_ROOT);
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        // Reverse postorder, skipping the root
+        for &node in postorder.iter().rev().skip(1) {
+            let mut new_idom: Option<usize> = None;
+
+            for &pred in &predecessors[node] {
+                if idom[pred].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => pred,
+                    Some(current) => intersect(pred, current, &idom, &postorder_number),
+                });
+            }
+
+            if new_idom.is_some() && idom[node] != new_idom {
+                idom[node] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    idom
+}
+
+fn intersect(
+    mut a: usize,
+    mut b: usize,
+    idom: &[Option<usize>],
+    postorder_number: &[usize],
+) -> usize {
+    while a != b {
+        while postorder_number[a] < postorder_number[b] {
+            a = idom[a].unwrap_or(VIRTUAL_ROOT);
+        }
+        while postorder_number[b] < postorder_number[a] {
+            b = idom[b].unwrap_or(VIRTUAL_ROOT);
+        }
+    }
+    a
+}
+
+/// Total lines and bytes covered by the locations, counting overlaps once.
+fn freed_size(locations: &[&Location]) -> (u32, u32) {
+    let mut line_ranges: HashMap<&Path, Vec<(u32, u32)>> = HashMap::new();
+    let mut byte_ranges: HashMap<&Path, Vec<(u32, u32)>> = HashMap::new();
+
+    for location in locations {
+        let file = location.file_path.as_path();
+        line_ranges
+            .entry(file)
+            .or_default()
+            .push((location.line, location.end_line + 1));
+        byte_ranges
+            .entry(file)
+            .or_default()
+            .push((location.start_offset, location.end_offset));
+    }
+
+    let lines = line_ranges.into_values().map(union_length).sum();
+    let bytes = byte_ranges.into_values().map(union_length).sum();
+    (lines, bytes)
+}
+
+/// Length of the union of half-open ranges.
+fn union_length(mut ranges: Vec<(u32, u32)>) -> u32 {
+    ranges.sort_unstable();
+
+    let mut total = 0;
+    let mut current: Option<(u32, u32)> = None;
+
+    for (start, end) in ranges {
+        match current {
+            Some((cur_start, cur_end)) if start <= cur_end => {
+                current = Some((cur_start, cur_end.max(end)));
+            }
+            Some((cur_start, cur_end)) => {
+                total += cur_end - cur_start;
+                current = Some((start, end));
+            }
+            None => current = Some((start, end)),
+        }
+    }
+
+    if let Some((start, end)) = current {
+        total += end - start;
+    }
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileId, ReferenceKind, SymbolKind, SymbolReference, TrackedSymbol};
+    use std::path::PathBuf;
+
+    fn make_dead(id: u32, start_line: u32, end_line: u32, transitive: bool) -> DeadSymbol {
+        let location = Location::new(
+            PathBuf::from("test.ts"),
+            start_line * 10,
+            end_line * 10 + 9,
+            start_line,
+            1,
+        )
+        .with_end_line(end_line);
+        let symbol = TrackedSymbol::new(
+            SymbolId::new(id),
+            format!("sym{}", id),
+            SymbolKind::Function,
+            location,
+            FileId::new(0),
+        );
+        if transitive {
+            DeadSymbol::transitive(symbol, 95, Vec::new(), SymbolId::new(0))
+        } else {
+            DeadSymbol::new(
+                symbol,
+                100,
+                DeadnessReason::Unreachable {
+                    explanation: "never referenced".to_string(),
+                },
+            )
+        }
+    }
+
+    fn add_ref(graph: &mut CallGraph, from: u32, to: u32) {
+        graph.add_reference(SymbolReference::new(
+            SymbolId::new(from),
+            SymbolId::new(to),
+            ReferenceKind::Call,
+            Location::new(PathBuf::from("test.ts"), 0, 1, 1, 1),
+        ));
+    }
+
+    #[test]
+    fn test_root_dominates_chain() {
+        let mut graph = CallGraph::new();
+        // 0 -> 1 -> 2
+        add_ref(&mut graph, 0, 1);
+        add_ref(&mut graph, 1, 2);
+
+        let mut dead = vec![
+            make_dead(0, 1, 3, false),
+            make_dead(1, 5, 9, true),
+            make_dead(2, 11, 11, true),
+        ];
+        compute_removal_impact(&mut dead, &graph);
+
+        let impact = dead[0].impact.as_ref().unwrap();
+        assert_eq!(impact.dominated, vec![SymbolId::new(1), SymbolId::new(2)]);
+        assert_eq!(impact.lines, 3 + 5 + 1);
+        assert!(dead[1].impact.is_none());
+    }
+
+    #[test]
+    fn test_shared_child_is_not_dominated() {
+        let mut graph = CallGraph::new();
+        // 0 -> 2 and 1 -> 2
+        add_ref(&mut graph, 0, 2);
+        add_ref(&mut graph, 1, 2);
+
+        let mut dead = vec![
+            make_dead(0, 1, 1, false),
+            make_dead(1, 3, 3, false),
+            make_dead(2, 5, 5, true),
+        ];
+        compute_removal_impact(&mut dead, &graph);
+
+        assert!(dead[0].impact.as_ref().unwrap().dominated.is_empty());
+        assert!(dead[1].impact.as_ref().unwrap().dominated.is_empty());
+    }
+
+    #[test]
+    fn test_sort_by_impact() {
+        let graph = CallGraph::new();
+        let mut dead = vec![make_dead(0, 1, 1, false), make_dead(1, 3, 20, false)];
+        compute_removal_impact(&mut dead, &graph);
+        sort_by_impact(&mut dead);
+
+        assert_eq!(dead[0].symbol.id, SymbolId::new(1));
+    }
+
+    #[test]
+    fn test_union_length_merges_overlaps() {
+        assert_eq!(union_length(vec![(0, 10), (5, 15), (20, 25)]), 20);
+        assert_eq!(union_length(Vec::new()), 0);
+    }
+}
@@ -1,6 +1,8 @@
 //! Deadness analysis - finding unreachable code.
 
+mod impact;
 mod propagator;
 mod transitive;
 
-pub use propagator::find_dead_symbols;
+pub use impact::{compute_removal_impact, sort_by_impact};
+pub use propagator::{find_dead_symbols, mark_reachable_symbols, reachable_from};
@@ -1,28 +1,36 @@
 //! Deadness propagation using BFS from entry points.
 
 use super::transitive::find_transitive_dead;
-use crate::config::Config;
+use crate::config::{Config, FileConfigs};
 use crate::core::{CallGraph, DeadSymbol, DeadnessReason, SymbolId, TrackedSymbol};
 use std::collections::{HashSet, VecDeque};
 
-/// Find all dead symbols in the call graph.
-pub fn find_dead_symbols(call_graph: &CallGraph, config: &Config) -> Vec<DeadSymbol> {
-    // Phase 1: Mark all reachable symbols using BFS from entry points
-    let reachable = mark_reachable_symbols(call_graph);
-
-    // Phase 2: Collect unreachable symbols
+/// Find all dead symbols in the call graph, given the symbols reachable from
+/// its entry points.
+pub fn find_dead_symbols(
+    call_graph: &CallGraph,
+    reachable: &HashSet<SymbolId>,
+    config: &Config,
+) -> Vec<DeadSymbol> {
+    // Phase 1: Collect unreachable symbols
+    let mut configs = FileConfigs::new(config);
     let unreachable: Vec<_> = call_graph
         .symbols
         .values()
         .filter(|s| !reachable.contains(&s.id))
-        .filter(|s| !config.should_ignore_symbol(&s.name))
+        .filter(|s| {
+            !s.suppressed
+                && !configs
+                    .get(&s.location.file_path)
+                    .should_ignore_symbol(&s.name)
+        })
         .cloned()
         .collect();
 
-    // Phase 3: Analyze transitive deadness
+    // Phase 2: Analyze transitive deadness
     let (directly_dead, transitively_dead) = find_transitive_dead(&unreachable, call_graph);
 
-    // Phase 4: Create DeadSymbol instances
+    // Phase 3: Create DeadSymbol instances
     let mut dead_symbols = Vec::new();
 
     // Add directly dead symbols
@@ -37,7 +45,11 @@ pub fn find_dead_symbols(call_graph: &CallGraph, config: &Config) -> Vec<DeadSym
 
     // Sort by file and line for consistent output
     dead_symbols.sort_by(|a, b| {
-        let file_cmp = a.symbol.location.file_path.cmp(&b.symbol.location.file_path);
+        let file_cmp = a
+            .symbol
+            .location
+            .file_path
+            .cmp(&b.symbol.location.file_path);
         if file_cmp != std::cmp::Ordering::Equal {
             return file_cmp;
         }
@@ -48,15 +60,21 @@ pub fn find_dead_symbols(call_graph: &CallGraph, config: &Config) -> Vec<DeadSym
 }
 
 /// Mark all symbols reachable from entry points using BFS.
-fn mark_reachable_symbols(call_graph: &CallGraph) -> HashSet<SymbolId> {
+pub fn mark_reachable_symbols(call_graph: &CallGraph) -> HashSet<SymbolId> {
+    reachable_from(call_graph, call_graph.entry_points.iter().copied())
+}
+
+/// Mark all symbols reachable from the given roots using BFS.
+pub fn reachable_from(
+    call_graph: &CallGraph,
+    roots: impl IntoIterator<Item = SymbolId>,
+) -> HashSet<SymbolId> {
     let mut reachable = HashSet::new();
     let mut queue: VecDeque<SymbolId> = VecDeque::new();
 
-    // Start with entry points
-    for &entry_id in &call_graph.entry_points {
-        if !reachable.contains(&entry_id) {
-            queue.push_back(entry_id);
-            reachable.insert(entry_id);
+    for root_id in roots {
+        if reachable.insert(root_id) {
+            queue.push_back(root_id);
         }
     }
 
@@ -107,8 +125,7 @@ fn create_transitive_dead_symbol(
 fn is_type_symbol(symbol: &TrackedSymbol) -> bool {
     matches!(
         symbol.kind,
-        crate::core::SymbolKind::Type
-            | crate::core::SymbolKind::Interface
+        crate::core::SymbolKind::Type | crate::core::SymbolKind::Interface
     )
 }
 
@@ -131,7 +148,7 @@ fn generate_unreachable_explanation(symbol: &TrackedSymbol, call_graph: &CallGra
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::core::{FileId, Location, SymbolKind};
+    use crate::core::{EntryPointSource, FileId, Location, SymbolKind};
     use std::path::PathBuf;
 
     fn make_symbol(id: u32, name: &str, kind: SymbolKind) -> TrackedSymbol {
@@ -151,10 +168,15 @@ mod tests {
         let mut entry = make_symbol(0, "main", SymbolKind::Function);
         entry.is_entry_point = true;
         graph.add_symbol(entry);
-        graph.mark_entry_point(SymbolId::new(0));
+        graph.mark_entry_point(
+            SymbolId::new(0),
+            EntryPointSource::ConfigExport {
+                name: "main".to_string(),
+            },
+        );
 
         let config = Config::default();
-        let dead = find_dead_symbols(&graph, &config);
+        let dead = find_dead_symbols(&graph, &mark_reachable_symbols(&graph), &config);
 
         assert!(dead.is_empty());
     }
@@ -166,13 +188,18 @@ mod tests {
         let mut entry = make_symbol(0, "main", SymbolKind::Function);
         entry.is_entry_point = true;
         graph.add_symbol(entry);
-        graph.mark_entry_point(SymbolId::new(0));
+        graph.mark_entry_point(
+            SymbolId::new(0),
+            EntryPointSource::ConfigExport {
+                name: "main".to_string(),
+            },
+        );
 
         let orphan = make_symbol(1, "orphan", SymbolKind::Function);
         graph.add_symbol(orphan);
 
         let config = Config::default();
-        let dead = find_dead_symbols(&graph, &config);
+        let dead = find_dead_symbols(&graph, &mark_reachable_symbols(&graph), &config);
 
         assert_eq!(dead.len(), 1);
         assert_eq!(dead[0].symbol.name, "orphan");
@@ -185,7 +212,12 @@ mod tests {
         let mut entry = make_symbol(0, "main", SymbolKind::Function);
         entry.is_entry_point = true;
         graph.add_symbol(entry);
-        graph.mark_entry_point(SymbolId::new(0));
+        graph.mark_entry_point(
+            SymbolId::new(0),
+            EntryPointSource::ConfigExport {
+                name: "main".to_string(),
+            },
+        );
 
         let helper = make_symbol(1, "helper", SymbolKind::Function);
         graph.add_symbol(helper);
@@ -199,7 +231,7 @@ mod tests {
         ));
 
         let config = Config::default();
-        let dead = find_dead_symbols(&graph, &config);
+        let dead = find_dead_symbols(&graph, &mark_reachable_symbols(&graph), &config);
 
         assert!(dead.is_empty());
     }
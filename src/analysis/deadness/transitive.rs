@@ -5,6 +5,9 @@
 use crate::core::{CallGraph, SymbolId, TrackedSymbol};
 use std::collections::{HashMap, HashSet};
 
+/// A transitively dead symbol with its kill chain and the symbol that killed it.
+pub type TransitiveDead = (TrackedSymbol, Vec<SymbolId>, SymbolId);
+
 /// Categorize unreachable symbols into directly dead and transitively dead.
 ///
 /// Returns:
@@ -13,10 +16,7 @@ use std::collections::{HashMap, HashSet};
 pub fn find_transitive_dead(
     unreachable: &[TrackedSymbol],
     call_graph: &CallGraph,
-) -> (
-    Vec<TrackedSymbol>,
-    Vec<(TrackedSymbol, Vec<SymbolId>, SymbolId)>,
-) {
+) -> (Vec<TrackedSymbol>, Vec<TransitiveDead>) {
     let unreachable_ids: HashSet<SymbolId> = unreachable.iter().map(|s| s.id).collect();
 
     // Build reverse dependency map: symbol -> symbols that reference it
@@ -0,0 +1,108 @@
+//! Deprecation tracking: `@deprecated` symbols and what still references them.
+
+use crate::core::{CallGraph, Location, TrackedSymbol};
+
+/// A symbol tagged `@deprecated` in its JSDoc comment.
+#[derive(Debug, Clone)]
+pub struct DeprecatedSymbol {
+    pub symbol: TrackedSymbol,
+    /// Text of the `@deprecated` tag, e.g. the suggested replacement.
+    pub note: String,
+    /// Places that still reference the symbol.
+    pub referrers: Vec<Referrer>,
+}
+
+/// A reference to a deprecated symbol.
+#[derive(Debug, Clone)]
+pub struct Referrer {
+    /// Where the reference is made.
+    pub location: Location,
+    /// The referencing symbol, when it is tracked.
+    pub symbol: Option<TrackedSymbol>,
+}
+
+/// Find deprecated symbols and their referrers, sorted by file and line.
+pub fn find_deprecated(call_graph: &CallGraph) -> Vec<DeprecatedSymbol> {
+    let mut deprecated: Vec<_> = call_graph
+        .symbols
+        .values()
+        .filter_map(|symbol| {
+            let note = symbol.doc_tags.deprecated.clone()?;
+
+            let mut referrers: Vec<_> = call_graph
+                .references
+                .iter()
+                .filter(|r| r.to_id == symbol.id)
+                .map(|r| Referrer {
+                    location: r.location.clone(),
+                    symbol: call_graph.symbols.get(&r.from_id).cloned(),
+                })
+                .collect();
+            referrers.sort_by(|a, b| {
+                (&a.location.file_path, a.location.line)
+                    .cmp(&(&b.location.file_path, b.location.line))
+            });
+
+            Some(DeprecatedSymbol {
+                symbol: symbol.clone(),
+                note,
+                referrers,
+            })
+        })
+        .collect();
+
+    deprecated.sort_by(|a, b| {
+        (&a.symbol.location.file_path, a.symbol.location.line)
+            .cmp(&(&b.symbol.location.file_path, b.symbol.location.line))
+    });
+    deprecated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileId, ReferenceKind, SymbolId, SymbolReference};
+    use std::path::PathBuf;
+
+    fn make_symbol(id: u32, name: &str, deprecated: Option<&str>) -> TrackedSymbol {
+        let mut symbol = TrackedSymbol::new(
+            SymbolId::new(id),
+            name.to_string(),
+            crate::core::SymbolKind::Function,
+            Location::new(PathBuf::from("lib.ts"), id * 10, id * 10 + 5, id + 1, 1),
+            FileId::new(0),
+        );
+        symbol.doc_tags.deprecated = deprecated.map(str::to_string);
+        symbol
+    }
+
+    #[test]
+    fn test_find_deprecated_with_referrers() {
+        let mut graph = CallGraph::new();
+        graph.add_symbol(make_symbol(0, "oldApi", Some("use newApi")));
+        graph.add_symbol(make_symbol(1, "unusedOld", Some("")));
+        graph.add_symbol(make_symbol(2, "caller", None));
+        graph.add_reference(SymbolReference {
+            from_id: SymbolId::new(2),
+            to_id: SymbolId::new(0),
+            kind: ReferenceKind::Call,
+            is_dynamic: false,
+            location: Location::new(PathBuf::from("app.ts"), 40, 46, 7, 3),
+        });
+
+        let deprecated = find_deprecated(&graph);
+
+        assert_eq!(deprecated.len(), 2);
+        assert_eq!(deprecated[0].symbol.name, "oldApi");
+        assert_eq!(deprecated[0].note, "use newApi");
+        assert_eq!(deprecated[0].referrers.len(), 1);
+        assert_eq!(
+            deprecated[0].referrers[0]
+                .symbol
+                .as_ref()
+                .map(|s| s.name.as_str()),
+            Some("caller")
+        );
+        assert!(deprecated[1].referrers.is_empty());
+    }
+}
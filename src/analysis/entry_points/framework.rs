@@ -1,30 +1,65 @@
 //! Framework-specific entry point detection.
 
-use crate::config::Config;
-use crate::core::{CallGraph, Result};
+use crate::config::{Config, FileConfigs, PluginsConfig};
+use crate::core::{CallGraph, EntryPointSource, FileId, Result};
 use crate::plugins::{detect_frameworks, FrameworkDetector};
+use std::collections::{BTreeMap, HashSet};
 use std::path::Path;
 
 /// Discover entry points based on detected frameworks.
+///
+/// Overrides can change plugin settings for part of the tree, so files are
+/// grouped by their effective plugin settings and each group gets its own
+/// detectors.
 pub fn discover_framework_entry_points(
     call_graph: &mut CallGraph,
     root_path: &Path,
     config: &Config,
 ) -> Result<()> {
-    let detectors = detect_frameworks(root_path, config)?;
+    let mut configs = FileConfigs::new(config);
+    let mut groups: BTreeMap<PluginSettingsKey, (Config, HashSet<FileId>)> = BTreeMap::new();
+    for file in call_graph.files.values() {
+        let effective = configs.get(&file.path);
+        let plugins = &effective.plugins;
+        if !plugins.auto_detect && plugins.enabled.is_empty() {
+            continue;
+        }
+
+        groups
+            .entry(plugin_settings_key(plugins))
+            .or_insert_with(|| (Config::clone(effective), HashSet::new()))
+            .1
+            .insert(file.id);
+    }
 
-    for detector in detectors {
-        mark_framework_entry_points(call_graph, root_path, &*detector)?;
+    for (effective, files) in groups.into_values() {
+        for detector in detect_frameworks(root_path, &effective)? {
+            mark_framework_entry_points(call_graph, root_path, &*detector, &files)?;
+        }
     }
 
     Ok(())
 }
 
-/// Mark entry points for a specific framework.
+/// The plugin settings that decide which detectors run: enabled, disabled
+/// and auto-detect.
+type PluginSettingsKey = (Vec<String>, Vec<String>, bool);
+
+/// Identify the plugin settings that decide which detectors run.
+fn plugin_settings_key(plugins: &PluginsConfig) -> PluginSettingsKey {
+    (
+        plugins.enabled.clone(),
+        plugins.disabled.clone(),
+        plugins.auto_detect,
+    )
+}
+
+/// Mark entry points for a specific framework among the given files.
 fn mark_framework_entry_points(
     call_graph: &mut CallGraph,
     root_path: &Path,
     detector: &dyn FrameworkDetector,
+    files: &HashSet<FileId>,
 ) -> Result<()> {
     let entry_patterns = detector.get_entry_patterns();
 
@@ -36,7 +71,7 @@ fn mark_framework_entry_points(
             let matching_file_ids: Vec<_> = call_graph
                 .files
                 .values()
-                .filter(|f| glob_pattern.matches_path(&f.path))
+                .filter(|f| files.contains(&f.id) && glob_pattern.matches_path(&f.path))
                 .map(|f| f.id)
                 .collect();
 
@@ -50,13 +85,19 @@ fn mark_framework_entry_points(
                     .values()
                     .filter(|s| {
                         s.file_id == file_id
-                            && (s.exported || special_exports.contains(&s.name.as_str()))
+                            && (s.is_public_export() || special_exports.contains(&s.name.as_str()))
                     })
                     .map(|s| s.id)
                     .collect();
 
                 for id in symbols_to_mark {
-                    call_graph.mark_entry_point(id);
+                    call_graph.mark_entry_point(
+                        id,
+                        EntryPointSource::Framework {
+                            plugin: detector.name().to_string(),
+                            pattern: pattern.clone(),
+                        },
+                    );
                 }
             }
         }
@@ -64,3 +105,52 @@ fn mark_framework_entry_points(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::Analyzer;
+    use crate::config::Config;
+    use crate::core::EntryPointSource;
+    use std::fs;
+
+    #[test]
+    fn test_overrides_group_files_by_plugin_settings() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("pages/legacy")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "dependencies": { "next": "14" } }"#,
+        )
+        .unwrap();
+        fs::write(root.join("pages/home.ts"), "export function Home() {}\n").unwrap();
+        fs::write(
+            root.join("pages/legacy/old.ts"),
+            "export function Old() {}\n",
+        )
+        .unwrap();
+
+        let mut config: Config = toml::from_str(
+            r#"
+            [[overrides]]
+            files = ["pages/legacy/**"]
+            plugins.disabled = ["nextjs"]
+            "#,
+        )
+        .unwrap();
+        config.base_dir = Some(root.to_path_buf());
+
+        let call_graph = Analyzer::new(config, root.to_path_buf())
+            .unwrap()
+            .build_call_graph(None)
+            .unwrap();
+        let framework_entries: Vec<_> = call_graph
+            .collect_entry_points()
+            .into_iter()
+            .filter(|e| matches!(e.sources[0], EntryPointSource::Framework { .. }))
+            .map(|e| e.symbol.name)
+            .collect();
+
+        assert_eq!(framework_entries, vec!["Home"]);
+    }
+}
@@ -3,8 +3,8 @@
 mod framework;
 mod package_json;
 
-use crate::config::Config;
-use crate::core::{CallGraph, Result};
+use crate::config::{Config, FileConfigs};
+use crate::core::{CallGraph, EntryPointSource, Result};
 use std::path::Path;
 
 /// Discover and mark entry points in the call graph.
@@ -23,26 +23,32 @@ pub fn discover_entry_points(
         mark_pattern_as_entry_points(call_graph, root_path, pattern);
     }
 
-    // 3. Auto-detect from package.json
-    if config.entry.auto_detect {
+    // 3. Auto-detect from package.json, unless an override turns it off for a file
+    let auto_detect = config.entry.auto_detect
+        || config
+            .overrides
+            .iter()
+            .any(|o| o.entry.auto_detect == Some(true));
+    if auto_detect {
         let package_json_path = root_path.join("package.json");
         if package_json_path.exists() {
-            package_json::mark_package_json_entry_points(call_graph, &package_json_path)?;
+            package_json::mark_package_json_entry_points(call_graph, &package_json_path, config)?;
         }
     }
 
     // 4. Framework-specific entry points
-    if config.plugins.auto_detect || !config.plugins.enabled.is_empty() {
-        framework::discover_framework_entry_points(call_graph, root_path, config)?;
-    }
+    framework::discover_framework_entry_points(call_graph, root_path, config)?;
 
     // 5. Mark explicitly exported symbols from config
-    for export_name in &config.entry.exports {
-        mark_export_as_entry_point(call_graph, export_name);
-    }
+    mark_config_exports(call_graph, config);
 
-    // 6. Files with side effects are implicit entry points
-    mark_side_effect_files_as_entry_points(call_graph);
+    // 6. Exports tagged `@public` or `@api` in JSDoc
+    mark_doc_tagged_entry_points(call_graph);
+
+    // 7. Top-level side-effecting statements keep their references alive,
+    //    unless package.json declares their file pure
+    package_json::apply_side_effects_field(call_graph, root_path);
+    mark_side_effect_roots(call_graph);
 
     Ok(())
 }
@@ -61,12 +67,17 @@ fn mark_file_exports_as_entry_points(call_graph: &mut CallGraph, file_path: &Pat
         let symbols_to_mark: Vec<_> = call_graph
             .symbols
             .values()
-            .filter(|s| s.file_id == file_id && s.exported)
+            .filter(|s| s.file_id == file_id && s.is_public_export())
             .map(|s| s.id)
             .collect();
 
         for id in symbols_to_mark {
-            call_graph.mark_entry_point(id);
+            call_graph.mark_entry_point(
+                id,
+                EntryPointSource::ConfigFile {
+                    path: file_path.to_path_buf(),
+                },
+            );
         }
     }
 }
@@ -87,51 +98,84 @@ fn mark_pattern_as_entry_points(call_graph: &mut CallGraph, root_path: &Path, pa
             let symbols_to_mark: Vec<_> = call_graph
                 .symbols
                 .values()
-                .filter(|s| s.file_id == file_id && s.exported)
+                .filter(|s| s.file_id == file_id && s.is_public_export())
                 .map(|s| s.id)
                 .collect();
 
             for id in symbols_to_mark {
-                call_graph.mark_entry_point(id);
+                call_graph.mark_entry_point(
+                    id,
+                    EntryPointSource::ConfigPattern {
+                        pattern: pattern.to_string(),
+                    },
+                );
             }
         }
     }
 }
 
-/// Mark a named export as an entry point across all files.
-fn mark_export_as_entry_point(call_graph: &mut CallGraph, export_name: &str) {
+/// Mark exports named in `entry.exports` as entry points.
+///
+/// Overrides can replace the list for their files.
+fn mark_config_exports(call_graph: &mut CallGraph, config: &Config) {
+    let mut configs = FileConfigs::new(config);
     let symbols_to_mark: Vec<_> = call_graph
         .symbols
         .values()
-        .filter(|s| s.exported && s.name == export_name)
-        .map(|s| s.id)
+        .filter(|s| {
+            s.exported
+                && configs
+                    .get(&s.location.file_path)
+                    .entry
+                    .exports
+                    .contains(&s.name)
+        })
+        .map(|s| (s.id, s.name.clone()))
         .collect();
 
-    for id in symbols_to_mark {
-        call_graph.mark_entry_point(id);
+    for (id, name) in symbols_to_mark {
+        call_graph.mark_entry_point(id, EntryPointSource::ConfigExport { name });
     }
 }
 
-/// Mark files with side effects as having entry points.
-fn mark_side_effect_files_as_entry_points(call_graph: &mut CallGraph) {
-    let side_effect_file_ids: Vec<_> = call_graph
-        .files
+/// Mark exports documented as public API as entry points.
+fn mark_doc_tagged_entry_points(call_graph: &mut CallGraph) {
+    let marks: Vec<_> = call_graph
+        .symbols
         .values()
-        .filter(|f| f.has_side_effects)
-        .map(|f| f.id)
+        .filter(|s| s.exported)
+        .filter_map(|s| {
+            let tag = s.doc_tags.public.clone()?;
+            Some((s.id, EntryPointSource::DocTag { tag }))
+        })
         .collect();
 
-    for file_id in side_effect_file_ids {
-        // Mark all top-level symbols in files with side effects
-        let symbols_to_mark: Vec<_> = call_graph
-            .symbols
-            .values()
-            .filter(|s| s.file_id == file_id)
-            .map(|s| s.id)
-            .collect();
+    for (id, source) in marks {
+        call_graph.mark_entry_point(id, source);
+    }
+}
 
-        for id in symbols_to_mark {
-            call_graph.mark_entry_point(id);
-        }
+/// Mark the symbols referenced by top-level side-effecting statements.
+///
+/// Only what a statement actually uses is kept alive; the rest of its file is
+/// analyzed normally.
+fn mark_side_effect_roots(call_graph: &mut CallGraph) {
+    let marks: Vec<_> = call_graph
+        .side_effect_roots
+        .iter()
+        .flat_map(|root| {
+            root.references.iter().map(|&id| {
+                (
+                    id,
+                    EntryPointSource::SideEffect {
+                        location: root.location.clone(),
+                    },
+                )
+            })
+        })
+        .collect();
+
+    for (id, source) in marks {
+        call_graph.mark_entry_point(id, source);
     }
 }
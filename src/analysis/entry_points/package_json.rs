@@ -1,19 +1,32 @@
 //! Entry point extraction from package.json.
 
-use crate::config::extract_entry_points_from_package_json;
-use crate::core::{CallGraph, Result};
-use std::path::Path;
+use crate::analysis::deadness::reachable_from;
+use crate::config::{
+    extract_package_json_entries, extract_side_effects_field, Config, FileConfigs,
+    PackageJsonEntry, SideEffectsField,
+};
+use crate::core::{AnalysisWarning, CallGraph, EntryPointSource, FileId, Result, WarningKind};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Mark entry points from package.json in the call graph.
-pub fn mark_package_json_entry_points(call_graph: &mut CallGraph, package_json_path: &Path) -> Result<()> {
-    let entry_config = extract_entry_points_from_package_json(package_json_path)?;
+///
+/// Files whose effective config turns off `entry.autoDetect` are skipped.
+pub fn mark_package_json_entry_points(
+    call_graph: &mut CallGraph,
+    package_json_path: &Path,
+    config: &Config,
+) -> Result<()> {
+    let entries = extract_package_json_entries(package_json_path)?;
+    let mut configs = FileConfigs::new(config);
 
-    for entry_file in &entry_config.files {
+    for entry in &entries {
         // Find matching file in the call graph
         let file_id = call_graph
             .files
             .values()
-            .find(|f| paths_match(&f.path, entry_file))
+            .find(|f| paths_match(&f.path, &entry.path))
+            .filter(|f| configs.get(&f.path).entry.auto_detect)
             .map(|f| f.id);
 
         if let Some(file_id) = file_id {
@@ -21,12 +34,18 @@ pub fn mark_package_json_entry_points(call_graph: &mut CallGraph, package_json_p
             let symbols_to_mark: Vec<_> = call_graph
                 .symbols
                 .values()
-                .filter(|s| s.file_id == file_id && s.exported)
+                .filter(|s| s.file_id == file_id && s.is_public_export())
                 .map(|s| s.id)
                 .collect();
 
             for id in symbols_to_mark {
-                call_graph.mark_entry_point(id);
+                call_graph.mark_entry_point(
+                    id,
+                    EntryPointSource::PackageJson {
+                        field: entry.field.clone(),
+                        path: entry.path.clone(),
+                    },
+                );
             }
         }
     }
@@ -34,6 +53,144 @@ pub fn mark_package_json_entry_points(call_graph: &mut CallGraph, package_json_p
     Ok(())
 }
 
+/// Apply each package's `sideEffects` field to the side-effect roots.
+///
+/// Files a package declares pure lose their roots, unless the file is one of
+/// the package's entry files or reachable code uses it. Files it lists, or all
+/// files under `sideEffects: true`, have every top-level statement as a root.
+/// Packages without the field are left to syntactic detection. The lookup
+/// stops at `root_path`, and a package.json that cannot be read is reported as
+/// a warning.
+pub fn apply_side_effects_field(call_graph: &mut CallGraph, root_path: &Path) {
+    let mut packages: HashMap<PathBuf, Option<PathBuf>> = HashMap::new();
+    let mut fields: HashMap<PathBuf, Option<SideEffectsField>> = HashMap::new();
+    let mut entries: HashMap<PathBuf, Vec<PackageJsonEntry>> = HashMap::new();
+    let mut entry_files: HashSet<FileId> = HashSet::new();
+    let mut pure_files: HashSet<FileId> = HashSet::new();
+    let mut impure_files: HashSet<FileId> = HashSet::new();
+
+    for file in call_graph.files.values() {
+        let Some(dir) = file.path.parent() else {
+            continue;
+        };
+        let Some(pkg_path) = nearest_package_json(dir, root_path, &mut packages) else {
+            continue;
+        };
+
+        let field = fields.entry(pkg_path.clone()).or_insert_with(|| {
+            extract_side_effects_field(&pkg_path).unwrap_or_else(|e| {
+                call_graph.warnings.push(AnalysisWarning {
+                    kind: WarningKind::ConfigWarning,
+                    message: format!("ignoring sideEffects in {}: {}", pkg_path.display(), e),
+                    location: None,
+                });
+                None
+            })
+        });
+
+        if let Some(field) = field {
+            let pkg_dir = pkg_path.parent().unwrap_or(Path::new("."));
+            if field.includes(pkg_dir, &file.path) {
+                impure_files.insert(file.id);
+            } else {
+                pure_files.insert(file.id);
+                let entries = entries
+                    .entry(pkg_path.clone())
+                    .or_insert_with(|| extract_package_json_entries(&pkg_path).unwrap_or_default());
+                if entries.iter().any(|e| paths_match(&file.path, &e.path)) {
+                    entry_files.insert(file.id);
+                }
+            }
+        }
+    }
+
+    let used_files = used_pure_files(call_graph, &pure_files, &impure_files, entry_files);
+    call_graph.side_effect_roots.retain(|root| {
+        let dropped = pure_files.contains(&root.file_id) && !used_files.contains(&root.file_id);
+        !dropped && (root.detected || impure_files.contains(&root.file_id))
+    });
+
+    for file in call_graph.files.values_mut() {
+        if pure_files.contains(&file.id) {
+            file.has_side_effects = false;
+        } else if impure_files.contains(&file.id) {
+            file.has_side_effects = true;
+        }
+    }
+    for symbol in call_graph.symbols.values_mut() {
+        if pure_files.contains(&symbol.file_id) {
+            symbol.has_side_effects = false;
+        }
+    }
+}
+
+/// Find the pure files that must keep their roots: entry files, and files
+/// reachable code uses from the entry points or from the roots kept so far.
+fn used_pure_files(
+    call_graph: &CallGraph,
+    pure_files: &HashSet<FileId>,
+    impure_files: &HashSet<FileId>,
+    mut used: HashSet<FileId>,
+) -> HashSet<FileId> {
+    loop {
+        let kept_roots = call_graph
+            .side_effect_roots
+            .iter()
+            .filter(|root| !pure_files.contains(&root.file_id) || used.contains(&root.file_id))
+            .filter(|root| root.detected || impure_files.contains(&root.file_id))
+            .flat_map(|root| root.references.iter().copied());
+        let roots: Vec<_> = call_graph
+            .entry_points
+            .iter()
+            .copied()
+            .chain(kept_roots)
+            .collect();
+
+        let newly_used: Vec<_> = reachable_from(call_graph, roots)
+            .into_iter()
+            .filter_map(|id| call_graph.symbols.get(&id))
+            .map(|s| s.file_id)
+            .filter(|file_id| pure_files.contains(file_id) && !used.contains(file_id))
+            .collect();
+        if newly_used.is_empty() {
+            return used;
+        }
+        used.extend(newly_used);
+    }
+}
+
+/// Find the package.json governing `dir` without leaving `root_path`,
+/// caching the answer for every directory visited on the way up.
+fn nearest_package_json(
+    dir: &Path,
+    root_path: &Path,
+    cache: &mut HashMap<PathBuf, Option<PathBuf>>,
+) -> Option<PathBuf> {
+    let mut visited = Vec::new();
+    let mut current = Some(dir);
+
+    let found = loop {
+        let Some(dir) = current.filter(|d| d.starts_with(root_path)) else {
+            break None;
+        };
+        if let Some(cached) = cache.get(dir) {
+            break cached.clone();
+        }
+        visited.push(dir.to_path_buf());
+
+        let candidate = dir.join("package.json");
+        if candidate.is_file() {
+            break Some(candidate);
+        }
+        current = dir.parent();
+    };
+
+    for dir in visited {
+        cache.insert(dir, found.clone());
+    }
+    found
+}
+
 /// Check if two paths refer to the same file, handling extension normalization.
 fn paths_match(path1: &Path, path2: &Path) -> bool {
     // Direct match
@@ -60,3 +217,126 @@ fn paths_match(path1: &Path, path2: &Path) -> bool {
 
     false
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::analysis::Analyzer;
+    use crate::config::Config;
+    use crate::core::{EntryPointSource, WarningKind};
+    use std::fs;
+
+    #[test]
+    fn test_side_effects_field() {
+        let dir = tempfile::tempdir().unwrap();
+        // Outside the project, so never read
+        fs::write(dir.path().join("package.json"), "{ not json").unwrap();
+        let root = dir.path().join("proj");
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::create_dir_all(root.join("vendor")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "sideEffects": ["./src/polyfill.ts"] }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/polyfill.ts"),
+            "function install() {}\nconst installed = install;\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/pure.ts"), "function setup() {}\nsetup();\n").unwrap();
+        fs::write(root.join("vendor/package.json"), "{ not json").unwrap();
+        fs::write(root.join("vendor/lib.ts"), "export const x = 1;\n").unwrap();
+
+        let analyzer = Analyzer::new(Config::default(), root.clone()).unwrap();
+        let call_graph = analyzer.build_call_graph(None).unwrap();
+
+        let side_effect_roots: Vec<_> = call_graph
+            .collect_entry_points()
+            .into_iter()
+            .filter(|e| matches!(e.sources[0], EntryPointSource::SideEffect { .. }))
+            .map(|e| e.symbol.name)
+            .collect();
+        assert_eq!(side_effect_roots, vec!["install"]);
+
+        assert_eq!(call_graph.warnings.len(), 1);
+        assert_eq!(call_graph.warnings[0].kind, WarningKind::ConfigWarning);
+        assert!(call_graph.warnings[0].message.contains("vendor"));
+    }
+
+    #[test]
+    fn test_side_effects_false_keeps_entry_file_roots() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "main": "src/index.ts", "sideEffects": false }"#,
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/index.ts"),
+            "import { setup } from \"./setup\";\nsetup();\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/setup.ts"),
+            "import { register } from \"./registry\";\nexport function setup() {}\nregister();\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("src/registry.ts"),
+            "export function register() {}\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/unused.ts"), "function init() {}\ninit();\n").unwrap();
+
+        let result = Analyzer::new(Config::default(), root.to_path_buf())
+            .unwrap()
+            .analyze(None)
+            .unwrap();
+        let dead: Vec<_> = result
+            .dead_symbols
+            .iter()
+            .map(|d| d.symbol.name.as_str())
+            .collect();
+
+        assert_eq!(dead, vec!["init"]);
+    }
+
+    #[test]
+    fn test_entry_auto_detect_per_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::create_dir_all(root.join("legacy")).unwrap();
+        fs::write(
+            root.join("package.json"),
+            r#"{ "main": "index.ts", "bin": { "tool": "legacy/cli.ts" } }"#,
+        )
+        .unwrap();
+        fs::write(root.join("index.ts"), "export function main() {}\n").unwrap();
+        fs::write(root.join("legacy/cli.ts"), "export function run() {}\n").unwrap();
+
+        let mut config: Config = toml::from_str(
+            r#"
+            [[overrides]]
+            files = ["legacy/**"]
+            entry.autoDetect = false
+            "#,
+        )
+        .unwrap();
+        config.base_dir = Some(root.to_path_buf());
+
+        let call_graph = Analyzer::new(config, root.to_path_buf())
+            .unwrap()
+            .build_call_graph(None)
+            .unwrap();
+        let package_entries: Vec<_> = call_graph
+            .collect_entry_points()
+            .into_iter()
+            .filter(|e| matches!(e.sources[0], EntryPointSource::PackageJson { .. }))
+            .map(|e| e.symbol.name)
+            .collect();
+
+        assert_eq!(package_entries, vec!["main"]);
+    }
+}
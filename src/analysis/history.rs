@@ -0,0 +1,214 @@
+//! Git history for dead symbols.
+//!
+//! Runs `git blame` in the local repository to find when each dead symbol's
+//! lines last changed and who wrote them, so that long-untouched code can be
+//! told apart from code that was just added and is not wired up yet.
+
+use crate::config::HistoryConfig;
+use crate::core::{DddError, DeadSymbol, GitHistory, Result};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Blame information for a single line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BlameLine {
+    author: String,
+    time: u64,
+    committed: bool,
+}
+
+/// Check that `root` is inside a git work tree.
+pub fn ensure_repository(root: &Path) -> Result<()> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .map_err(|e| {
+            DddError::git_history(root.to_path_buf(), format!("failed to run git: {}", e))
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(DddError::git_history(root.to_path_buf(), stderr.trim()));
+    }
+    Ok(())
+}
+
+/// Annotate dead symbols with the git history of their lines.
+///
+/// Files git cannot blame, such as untracked ones, count as not yet committed.
+pub fn annotate_dead_symbols(dead_symbols: &mut [DeadSymbol], root: &Path, config: &HistoryConfig) {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+
+    let files: HashSet<&Path> = dead_symbols
+        .iter()
+        .map(|d| d.symbol.location.file_path.as_path())
+        .collect();
+    let blames: HashMap<PathBuf, Option<Vec<BlameLine>>> = files
+        .into_par_iter()
+        .map(|file| (file.to_path_buf(), blame(root, file)))
+        .collect();
+
+    for dead in dead_symbols {
+        let location = &dead.symbol.location;
+        let (last_modified, authors) = match blames.get(&location.file_path) {
+            Some(Some(lines)) => summarize(lines, location.line, location.end_line, now),
+            _ => (now, 0),
+        };
+
+        let age_days = now.saturating_sub(last_modified) / SECONDS_PER_DAY;
+        dead.history = Some(GitHistory {
+            last_modified,
+            age_days,
+            authors,
+            freshness: config.freshness(age_days),
+        });
+    }
+}
+
+/// Run `git blame` on a file, returning one entry per line.
+fn blame(root: &Path, file: &Path) -> Option<Vec<BlameLine>> {
+    let output = Command::new("git")
+        .arg("-C")
+        .arg(root)
+        .args(["blame", "--line-porcelain", "--"])
+        .arg(file)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+    Some(parse_blame(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Parse `git blame --line-porcelain` output.
+fn parse_blame(output: &str) -> Vec<BlameLine> {
+    let mut lines = Vec::new();
+    let mut author = String::new();
+    let mut time = 0;
+    let mut committed = true;
+
+    for line in output.lines() {
+        // Each entry ends with the line's content, prefixed by a tab
+        if line.starts_with('\t') {
+            lines.push(BlameLine {
+                author: std::mem::take(&mut author),
+                time,
+                committed,
+            });
+            continue;
+        }
+
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        match key {
+            "author-mail" => author = value.to_string(),
+            "author-time" => time = value.parse().unwrap_or(0),
+            // Entry header: commit hash, original line, final line
+            _ if key.len() == 40 && key.bytes().all(|b| b.is_ascii_hexdigit()) => {
+                // Uncommitted changes are attributed to the all-zero hash
+                committed = key.bytes().any(|b| b != b'0');
+            }
+            _ => {}
+        }
+    }
+
+    lines
+}
+
+/// Get the latest change time and distinct committed authors for a line range.
+fn summarize(lines: &[BlameLine], start_line: u32, end_line: u32, now: u64) -> (u64, usize) {
+    let start = start_line.saturating_sub(1) as usize;
+    let end = (end_line.max(start_line) as usize).min(lines.len());
+    let range = lines.get(start..end).unwrap_or_default();
+
+    let last_modified = range
+        .iter()
+        .map(|l| if l.committed { l.time } else { now })
+        .max()
+        .unwrap_or(now);
+    let authors: HashSet<_> = range
+        .iter()
+        .filter(|l| l.committed)
+        .map(|l| l.author.as_str())
+        .collect();
+
+    (last_modified, authors.len())
+}
+
+/// Format a Unix timestamp as a UTC `YYYY-MM-DD` date.
+pub fn format_date(timestamp: u64) -> String {
+    // Civil-from-days conversion for the proleptic Gregorian calendar
+    let days = (timestamp / SECONDS_PER_DAY) as i64 + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!("{:04}-{:02}-{:02}", year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HASH_A: &str = "1111111111111111111111111111111111111111";
+    const HASH_B: &str = "2222222222222222222222222222222222222222";
+    const UNCOMMITTED: &str = "0000000000000000000000000000000000000000";
+
+    fn entry(hash: &str, line: u32, mail: &str, time: u64, content: &str) -> String {
+        format!(
+            "{hash} {line} {line}\nauthor Someone\nauthor-mail {mail}\nauthor-time {time}\nauthor-tz +0000\nsummary change\nfilename src/a.ts\n\t{content}\n"
+        )
+    }
+
+    #[test]
+    fn test_parse_and_summarize_blame() {
+        let output = [
+            entry(HASH_A, 1, "<a@example.com>", 1_000, "function old() {"),
+            entry(HASH_B, 2, "<b@example.com>", 5_000, "  return 1;"),
+            entry(HASH_A, 3, "<a@example.com>", 1_000, "}"),
+            entry(
+                UNCOMMITTED,
+                4,
+                "<not.committed.yet>",
+                9_000,
+                "const added = 1;",
+            ),
+        ]
+        .concat();
+
+        let lines = parse_blame(&output);
+        assert_eq!(lines.len(), 4);
+        assert!(!lines[3].committed);
+
+        assert_eq!(summarize(&lines, 1, 3, 10_000), (5_000, 2));
+        assert_eq!(summarize(&lines, 3, 3, 10_000), (1_000, 1));
+        // Uncommitted lines are as new as it gets
+        assert_eq!(summarize(&lines, 4, 4, 10_000), (10_000, 0));
+    }
+
+    #[test]
+    fn test_format_date() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_735_689_599), "2024-12-31");
+    }
+}
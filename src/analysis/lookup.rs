@@ -0,0 +1,91 @@
+//! Symbol lookup by `file:name` specifiers.
+
+use crate::core::{CallGraph, DddError, Result, SymbolId};
+use std::path::Path;
+
+/// Find all symbols matching a `file:name` specifier.
+///
+/// The file part may be relative to `root_path` or any trailing portion of the
+/// symbol's path. A bare name matches symbols with that name in any file.
+pub fn find_symbols(call_graph: &CallGraph, root_path: &Path, spec: &str) -> Result<Vec<SymbolId>> {
+    let (file, name) = match spec.rsplit_once(':') {
+        Some((file, name)) if !file.is_empty() => (Some(file), name),
+        _ => (None, spec),
+    };
+
+    if name.is_empty() {
+        return Err(DddError::symbol_not_found(spec));
+    }
+
+    let full_path = file.map(|f| root_path.join(f));
+
+    let mut matches: Vec<SymbolId> = call_graph
+        .symbols
+        .values()
+        .filter(|s| s.name == name)
+        .filter(|s| match (file, &full_path) {
+            (Some(file), Some(full_path)) => {
+                s.location.file_path == *full_path || s.location.file_path.ends_with(file)
+            }
+            _ => true,
+        })
+        .map(|s| s.id)
+        .collect();
+
+    if matches.is_empty() {
+        return Err(DddError::symbol_not_found(spec));
+    }
+
+    matches.sort_by_key(|id| id.0);
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileId, Location, SymbolKind, TrackedSymbol};
+    use std::path::PathBuf;
+
+    fn make_graph() -> CallGraph {
+        let mut graph = CallGraph::new();
+        for (id, file, name) in [(0, "/repo/src/a.ts", "foo"), (1, "/repo/src/b.ts", "foo")] {
+            graph.add_symbol(TrackedSymbol::new(
+                SymbolId::new(id),
+                name.to_string(),
+                SymbolKind::Function,
+                Location::new(PathBuf::from(file), 0, 10, 1, 1),
+                FileId::new(id),
+            ));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_find_by_file_and_name() {
+        let graph = make_graph();
+        let root = Path::new("/repo");
+
+        assert_eq!(
+            find_symbols(&graph, root, "src/a.ts:foo").unwrap(),
+            vec![SymbolId::new(0)]
+        );
+        assert_eq!(
+            find_symbols(&graph, root, "b.ts:foo").unwrap(),
+            vec![SymbolId::new(1)]
+        );
+    }
+
+    #[test]
+    fn test_find_by_bare_name() {
+        let graph = make_graph();
+        let found = find_symbols(&graph, Path::new("/repo"), "foo").unwrap();
+        assert_eq!(found.len(), 2);
+    }
+
+    #[test]
+    fn test_unknown_symbol_is_error() {
+        let graph = make_graph();
+        assert!(find_symbols(&graph, Path::new("/repo"), "src/a.ts:bar").is_err());
+        assert!(find_symbols(&graph, Path::new("/repo"), "src/a.ts:").is_err());
+    }
+}
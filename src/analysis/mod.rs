@@ -2,32 +2,222 @@
 
 pub mod call_graph;
 pub mod confidence;
+pub mod coverage;
+pub mod cycles;
 pub mod deadness;
+pub mod deprecated;
 pub mod entry_points;
+pub mod history;
+pub mod lookup;
 pub mod project;
+pub mod subgraph;
+pub mod why;
 
 use crate::config::Config;
-use crate::core::{AnalysisResult, Result};
+use crate::core::{
+    AnalysisResult, AnalysisWarning, CallGraph, DeadSymbol, Execution, Result, SymbolId,
+    WarningKind,
+};
+use coverage::CoverageData;
 use indicatif::ProgressBar;
+use std::collections::HashSet;
 use std::path::PathBuf;
 use std::time::Instant;
 
+/// Receives findings while the analysis runs, as soon as they are final.
+///
+/// Warnings arrive one by one as each phase produces them. Dead symbols are
+/// only final once all of them are scored, so they arrive together, in impact
+/// order. A sink that can fail keeps its own error.
+pub trait AnalysisSink {
+    fn dead_symbols(&mut self, _dead_symbols: &[DeadSymbol]) {}
+
+    fn warning(&mut self, _warning: &AnalysisWarning) {}
+}
+
+/// Ignores every finding, for callers that only want the result.
+impl AnalysisSink for () {}
+
 /// Main analyzer that coordinates all analysis phases.
 pub struct Analyzer {
     config: Config,
     root_path: PathBuf,
+    coverage: Option<CoverageData>,
+    trace: Option<CoverageData>,
 }
 
 impl Analyzer {
     /// Create a new analyzer.
     pub fn new(config: Config, root_path: PathBuf) -> Result<Self> {
-        Ok(Self { config, root_path })
+        Ok(Self {
+            config,
+            root_path,
+            coverage: None,
+            trace: None,
+        })
+    }
+
+    /// Use runtime coverage as a confidence signal.
+    pub fn with_coverage(mut self, coverage: CoverageData) -> Self {
+        self.coverage = Some(coverage);
+        self
+    }
+
+    /// Use a production runtime trace as evidence of liveness.
+    pub fn with_trace(mut self, trace: CoverageData) -> Self {
+        self.trace = Some(trace);
+        self
     }
 
     /// Run the full analysis pipeline.
     pub fn analyze(&mut self, progress: Option<&ProgressBar>) -> Result<AnalysisResult> {
+        self.analyze_into(progress, &mut ())
+    }
+
+    /// Run the full analysis pipeline, passing findings to `sink` as they
+    /// are final.
+    pub fn analyze_into(
+        &mut self,
+        progress: Option<&ProgressBar>,
+        sink: &mut dyn AnalysisSink,
+    ) -> Result<AnalysisResult> {
         let start = Instant::now();
 
+        // History was asked for, so fail early rather than report without it
+        let repository = self.check_history();
+        if let Some(Err(e)) = repository {
+            return Err(e);
+        }
+
+        let call_graph = self.build_call_graph(progress)?;
+        let mut result = self.analyze_call_graph_into(&call_graph, progress, sink, repository);
+
+        result.duration_ms = start.elapsed().as_millis() as u64;
+        Ok(result)
+    }
+
+    /// Find and score dead symbols in an already built call graph (phases 4-5).
+    pub fn analyze_call_graph(
+        &self,
+        call_graph: &CallGraph,
+        progress: Option<&ProgressBar>,
+    ) -> AnalysisResult {
+        self.analyze_call_graph_into(call_graph, progress, &mut (), self.check_history())
+    }
+
+    /// Check for a git repository when history is asked for, or `None`
+    /// when it isn't.
+    fn check_history(&self) -> Option<Result<()>> {
+        self.config
+            .history
+            .is_active()
+            .then(|| history::ensure_repository(&self.root_path))
+    }
+
+    /// Find and score dead symbols, given the result of
+    /// [`Analyzer::check_history`].
+    fn analyze_call_graph_into(
+        &self,
+        call_graph: &CallGraph,
+        progress: Option<&ProgressBar>,
+        sink: &mut dyn AnalysisSink,
+        repository: Option<Result<()>>,
+    ) -> AnalysisResult {
+        let start = Instant::now();
+        let mut warnings = Vec::new();
+        report(&mut warnings, sink, call_graph.warnings.iter().cloned());
+
+        // Phase 4: Propagate deadness
+        if let Some(pb) = progress {
+            pb.set_message("Analyzing reachability...");
+        }
+        let reachable = deadness::mark_reachable_symbols(call_graph);
+        let mut dead_symbols = deadness::find_dead_symbols(call_graph, &reachable, &self.config);
+        deadness::compute_removal_impact(&mut dead_symbols, call_graph);
+        if let Some(coverage) = &self.coverage {
+            coverage::annotate_dead_symbols(&mut dead_symbols, coverage);
+        }
+        if let Some(trace) = &self.trace {
+            for dead in &mut dead_symbols {
+                dead.trace = coverage::trace_execution(trace, &dead.symbol);
+            }
+        }
+        match repository {
+            Some(Ok(())) => history::annotate_dead_symbols(
+                &mut dead_symbols,
+                &self.root_path,
+                &self.config.history,
+            ),
+            Some(Err(e)) => {
+                let warning = AnalysisWarning {
+                    kind: WarningKind::ConfigWarning,
+                    message: format!("git history is not available: {}", e),
+                    location: None,
+                };
+                report(&mut warnings, sink, [warning]);
+            }
+            None => {}
+        }
+
+        // Phase 5: Score confidence
+        if let Some(pb) = progress {
+            pb.set_message("Scoring confidence...");
+        }
+        let mut scored_dead =
+            confidence::score_dead_symbols(dead_symbols, call_graph, &reachable, &self.config);
+        deadness::sort_by_impact(&mut scored_dead);
+        sink.dead_symbols(&scored_dead);
+
+        // Statically dead code that ran contradicts the analysis
+        let executed = scored_dead.iter().filter_map(|dead| match dead.execution {
+            Some(Execution::Executed { hits }) => Some(AnalysisWarning {
+                kind: WarningKind::ExecutedDeadCode,
+                message: format!(
+                    "{} at {} is statically dead but was executed {} times",
+                    dead.symbol.name,
+                    dead.symbol.location.display(),
+                    hits
+                ),
+                location: Some(dead.symbol.location.clone()),
+            }),
+            _ => None,
+        });
+        report(&mut warnings, sink, executed);
+        report(
+            &mut warnings,
+            sink,
+            unused_suppressions(call_graph, &reachable),
+        );
+        let cycles = cycles::find_import_cycles(call_graph);
+        report(
+            &mut warnings,
+            sink,
+            cycles::cycle_warnings(call_graph, &cycles, &self.root_path),
+        );
+
+        let never_executed = match &self.trace {
+            Some(trace) => {
+                let dead: HashSet<_> = scored_dead.iter().map(|d| d.symbol.id).collect();
+                coverage::never_executed_symbols(call_graph, trace, &dead)
+            }
+            None => Vec::new(),
+        };
+
+        let duration = start.elapsed();
+
+        AnalysisResult {
+            dead_symbols: scored_dead,
+            entry_points: call_graph.collect_entry_points(),
+            never_executed,
+            total_symbols: call_graph.symbol_count(),
+            total_files: call_graph.files.len(),
+            warnings,
+            duration_ms: duration.as_millis() as u64,
+        }
+    }
+
+    /// Build the call graph with entry points marked, without deadness analysis.
+    pub fn build_call_graph(&self, progress: Option<&ProgressBar>) -> Result<CallGraph> {
         // Phase 1: Discover files
         if let Some(pb) = progress {
             pb.set_message("Discovering files...");
@@ -35,7 +225,9 @@ impl Analyzer {
         let files = project::discover_files(&self.root_path, &self.config)?;
 
         if files.is_empty() {
-            return Err(crate::core::DddError::no_files_found(self.root_path.clone()));
+            return Err(crate::core::DddError::no_files_found(
+                self.root_path.clone(),
+            ));
         }
 
         // Phase 2: Build call graph
@@ -49,27 +241,86 @@ impl Analyzer {
             pb.set_message("Discovering entry points...");
         }
         entry_points::discover_entry_points(&mut call_graph, &self.root_path, &self.config)?;
-
-        // Phase 4: Propagate deadness
-        if let Some(pb) = progress {
-            pb.set_message("Analyzing reachability...");
+        if let Some(trace) = &self.trace {
+            coverage::mark_traced_entry_points(&mut call_graph, trace);
         }
-        let dead_symbols = deadness::find_dead_symbols(&call_graph, &self.config);
 
-        // Phase 5: Score confidence
-        if let Some(pb) = progress {
-            pb.set_message("Scoring confidence...");
-        }
-        let scored_dead = confidence::score_dead_symbols(dead_symbols, &call_graph, &self.config);
+        Ok(call_graph)
+    }
+}
 
-        let duration = start.elapsed();
+/// Pass new warnings to `sink` and keep them for the result.
+fn report(
+    warnings: &mut Vec<AnalysisWarning>,
+    sink: &mut dyn AnalysisSink,
+    new: impl IntoIterator<Item = AnalysisWarning>,
+) {
+    for warning in new {
+        sink.warning(&warning);
+        warnings.push(warning);
+    }
+}
 
-        Ok(AnalysisResult {
-            dead_symbols: scored_dead,
-            total_symbols: call_graph.symbol_count(),
-            total_files: call_graph.files.len(),
-            warnings: Vec::new(), // TODO: Collect warnings during analysis
-            duration_ms: duration.as_millis() as u64,
+/// Warn about `ddd-ignore` comments that cover no dead symbols.
+fn unused_suppressions(
+    call_graph: &CallGraph,
+    reachable: &HashSet<SymbolId>,
+) -> Vec<AnalysisWarning> {
+    let mut unused: Vec<_> = call_graph
+        .files
+        .values()
+        .flat_map(|file| &file.suppressions)
+        .filter(|s| s.symbols.iter().all(|id| reachable.contains(id)))
+        .collect();
+    unused.sort_by(|a, b| {
+        (&a.location.file_path, a.location.line).cmp(&(&b.location.file_path, b.location.line))
+    });
+
+    unused
+        .into_iter()
+        .map(|suppression| {
+            let problem = if suppression.symbols.is_empty() {
+                "covers no declarations"
+            } else {
+                "only covers code that is alive"
+            };
+            AnalysisWarning {
+                kind: WarningKind::UnusedSuppression,
+                message: format!(
+                    "unused {} at {}: {}",
+                    suppression.kind.directive(),
+                    suppression.location.display(),
+                    problem
+                ),
+                location: Some(suppression.location.clone()),
+            }
         })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_outside_git_repository() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.ts"), "function unused() {}\n").unwrap();
+        let mut config = Config::default();
+        config.history.min_age = 30;
+
+        // Commands that only build the graph still work, with a warning
+        let mut analyzer = Analyzer::new(config, dir.path().to_path_buf()).unwrap();
+        let call_graph = analyzer.build_call_graph(None).unwrap();
+        let result = analyzer.analyze_call_graph(&call_graph, None);
+        assert_eq!(result.dead_symbols.len(), 1);
+        assert!(result.dead_symbols[0].history.is_none());
+        assert!(result
+            .warnings
+            .iter()
+            .any(|w| w.kind == WarningKind::ConfigWarning && w.message.contains("git history")));
+
+        // Collecting history for analyze needs the repository
+        assert!(analyzer.analyze(None).is_err());
     }
 }
@@ -1,6 +1,6 @@
 //! Project file discovery.
 
-use crate::config::Config;
+use crate::config::{Config, ConfigMatcher};
 use crate::core::Result;
 use ignore::WalkBuilder;
 use std::path::{Path, PathBuf};
@@ -8,6 +8,7 @@ use std::path::{Path, PathBuf};
 /// Discover TypeScript/JavaScript files in the project.
 pub fn discover_files(root: &Path, config: &Config) -> Result<Vec<PathBuf>> {
     let mut files = Vec::new();
+    let matcher = ConfigMatcher::new(config);
 
     let walker = WalkBuilder::new(root)
         .hidden(true)
@@ -31,7 +32,7 @@ pub fn discover_files(root: &Path, config: &Config) -> Result<Vec<PathBuf>> {
         }
 
         // Check include/exclude patterns
-        if !config.should_include(path) {
+        if !matcher.should_include(path) {
             continue;
         }
 
@@ -43,7 +44,10 @@ pub fn discover_files(root: &Path, config: &Config) -> Result<Vec<PathBuf>> {
 
 /// Check if an extension is a TypeScript/JavaScript file.
 fn is_typescript_extension(ext: &str) -> bool {
-    matches!(ext, "ts" | "tsx" | "js" | "jsx" | "mts" | "cts" | "mjs" | "cjs")
+    matches!(
+        ext,
+        "ts" | "tsx" | "js" | "jsx" | "mts" | "cts" | "mjs" | "cjs"
+    )
 }
 
 /// Get the source type for a file based on its extension.
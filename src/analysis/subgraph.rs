@@ -0,0 +1,202 @@
+//! Call graph subsets for export: the symbols in a file or directory, or the
+//! neighborhood of a symbol.
+
+use crate::core::{CallGraph, DddError, ReferenceKind, Result, SymbolId, SymbolReference};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::PathBuf;
+
+/// Which part of the call graph to export. Filters that are set all apply.
+#[derive(Debug, Clone, Default)]
+pub struct GraphFilter {
+    /// Only symbols defined in this file.
+    pub file: Option<PathBuf>,
+    /// Only symbols defined in files under this directory.
+    pub dir: Option<PathBuf>,
+    /// Only symbols within `depth` references of these, in either direction.
+    pub focus: Vec<SymbolId>,
+    pub depth: usize,
+}
+
+/// The symbols and references selected for export.
+#[derive(Debug, Clone, Default)]
+pub struct Subgraph {
+    /// Selected symbols, in ID order.
+    pub nodes: Vec<SymbolId>,
+    /// References between selected symbols, one per source, target and kind.
+    pub edges: Vec<SymbolReference>,
+}
+
+/// Select the part of the call graph matching `filter`.
+///
+/// File and directory paths are resolved against `root_path`. Returns an
+/// error if a path filter matches no symbols, which is most often a typo.
+pub fn select_subgraph(
+    call_graph: &CallGraph,
+    root_path: &std::path::Path,
+    filter: &GraphFilter,
+) -> Result<Subgraph> {
+    let file = filter.file.as_ref().map(|f| (f, root_path.join(f)));
+    let dir = filter.dir.as_ref().map(|d| root_path.join(d));
+
+    let mut nodes: HashSet<SymbolId> = call_graph
+        .symbols
+        .values()
+        .filter(|s| match &file {
+            Some((file, full_path)) => {
+                s.location.file_path == *full_path || s.location.file_path.ends_with(file)
+            }
+            None => true,
+        })
+        .filter(|s| {
+            dir.as_ref()
+                .is_none_or(|dir| s.location.file_path.starts_with(dir))
+        })
+        .map(|s| s.id)
+        .collect();
+
+    if nodes.is_empty() {
+        if let Some(path) = filter.file.as_ref().or(filter.dir.as_ref()) {
+            return Err(DddError::no_symbols_in_path(path.clone()));
+        }
+    }
+
+    if !filter.focus.is_empty() {
+        let neighborhood = neighborhood(call_graph, &filter.focus, filter.depth);
+        nodes.retain(|id| neighborhood.contains(id));
+    }
+
+    let mut seen: HashSet<(SymbolId, SymbolId, ReferenceKind)> = HashSet::new();
+    let edges = call_graph
+        .references
+        .iter()
+        .filter(|r| nodes.contains(&r.from_id) && nodes.contains(&r.to_id))
+        .filter(|r| seen.insert((r.from_id, r.to_id, r.kind)))
+        .cloned()
+        .collect();
+
+    let mut nodes: Vec<SymbolId> = nodes.into_iter().collect();
+    nodes.sort_by_key(|id| id.0);
+    Ok(Subgraph { nodes, edges })
+}
+
+/// Find the symbols within `depth` references of `focus`, following
+/// references in both directions.
+fn neighborhood(call_graph: &CallGraph, focus: &[SymbolId], depth: usize) -> HashSet<SymbolId> {
+    let mut adjacent: HashMap<SymbolId, Vec<SymbolId>> = HashMap::new();
+    for reference in &call_graph.references {
+        adjacent
+            .entry(reference.from_id)
+            .or_default()
+            .push(reference.to_id);
+        adjacent
+            .entry(reference.to_id)
+            .or_default()
+            .push(reference.from_id);
+    }
+
+    let mut distance: HashMap<SymbolId, usize> = focus.iter().map(|&id| (id, 0)).collect();
+    let mut queue: VecDeque<SymbolId> = focus.iter().copied().collect();
+
+    while let Some(current) = queue.pop_front() {
+        let next = distance[&current] + 1;
+        if next > depth {
+            continue;
+        }
+        for &neighbor in adjacent.get(&current).into_iter().flatten() {
+            if let Entry::Vacant(entry) = distance.entry(neighbor) {
+                entry.insert(next);
+                queue.push_back(neighbor);
+            }
+        }
+    }
+
+    distance.into_keys().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FileId, Location, SymbolKind, TrackedSymbol};
+    use std::path::Path;
+
+    /// a.ts: 0 -> 1, lib/b.ts: 1 -> 2 -> 3
+    fn make_graph() -> CallGraph {
+        let mut graph = CallGraph::new();
+        let files = [
+            "/repo/./src/a.ts",
+            "/repo/./src/a.ts",
+            "/repo/./src/lib/b.ts",
+            "/repo/./src/lib/b.ts",
+        ];
+        for (id, file) in files.iter().enumerate() {
+            graph.add_symbol(TrackedSymbol::new(
+                SymbolId::new(id as u32),
+                format!("s{}", id),
+                SymbolKind::Function,
+                Location::new(PathBuf::from(file), 0, 10, id as u32 + 1, 1),
+                FileId::new(0),
+            ));
+        }
+        for (from, to) in [(0, 1), (0, 1), (1, 2), (2, 3)] {
+            graph.add_reference(SymbolReference::new(
+                SymbolId::new(from),
+                SymbolId::new(to),
+                ReferenceKind::Call,
+                Location::new(PathBuf::from(files[from as usize]), 0, 1, 1, 1),
+            ));
+        }
+        graph
+    }
+
+    fn ids(nodes: &[SymbolId]) -> Vec<u32> {
+        nodes.iter().map(|id| id.0).collect()
+    }
+
+    #[test]
+    fn test_path_filters() {
+        let graph = make_graph();
+        let root = Path::new("/repo/.");
+
+        let all = select_subgraph(&graph, root, &GraphFilter::default()).unwrap();
+        assert_eq!(ids(&all.nodes), vec![0, 1, 2, 3]);
+        // Duplicate references collapse into one edge
+        assert_eq!(all.edges.len(), 3);
+
+        let filter = GraphFilter {
+            dir: Some(PathBuf::from("src/lib")),
+            ..GraphFilter::default()
+        };
+        let lib = select_subgraph(&graph, root, &filter).unwrap();
+        assert_eq!(ids(&lib.nodes), vec![2, 3]);
+        assert_eq!(lib.edges.len(), 1);
+
+        let filter = GraphFilter {
+            file: Some(PathBuf::from("a.ts")),
+            ..GraphFilter::default()
+        };
+        assert_eq!(
+            ids(&select_subgraph(&graph, root, &filter).unwrap().nodes),
+            vec![0, 1]
+        );
+
+        let filter = GraphFilter {
+            file: Some(PathBuf::from("src/missing.ts")),
+            ..GraphFilter::default()
+        };
+        assert!(select_subgraph(&graph, root, &filter).is_err());
+    }
+
+    #[test]
+    fn test_neighborhood() {
+        let graph = make_graph();
+        let filter = GraphFilter {
+            focus: vec![SymbolId::new(1)],
+            depth: 1,
+            ..GraphFilter::default()
+        };
+        let subgraph = select_subgraph(&graph, Path::new("/repo/."), &filter).unwrap();
+        assert_eq!(ids(&subgraph.nodes), vec![0, 1, 2]);
+        assert_eq!(subgraph.edges.len(), 2);
+    }
+}
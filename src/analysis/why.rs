@@ -0,0 +1,181 @@
+//! Liveness explanation: shortest paths from entry points to a symbol.
+
+use crate::core::{CallGraph, SymbolId, SymbolReference};
+use std::collections::{HashMap, VecDeque};
+
+/// A path through the call graph that keeps a symbol alive.
+#[derive(Debug, Clone)]
+pub struct LivenessPath {
+    /// The entry point the path starts from.
+    pub entry: SymbolId,
+    /// References followed from the entry point, in order.
+    pub edges: Vec<SymbolReference>,
+}
+
+/// Find up to `max_paths` shortest paths from any entry point to `target`.
+///
+/// Returns an empty list if the target is not reachable.
+pub fn find_liveness_paths(
+    call_graph: &CallGraph,
+    target: SymbolId,
+    max_paths: usize,
+) -> Vec<LivenessPath> {
+    // Index references by source so each edge keeps its kind and location
+    let mut outgoing: HashMap<SymbolId, Vec<&SymbolReference>> = HashMap::new();
+    for reference in &call_graph.references {
+        outgoing
+            .entry(reference.from_id)
+            .or_default()
+            .push(reference);
+    }
+
+    // BFS from all entry points at once, recording every shortest-path predecessor
+    let mut distance: HashMap<SymbolId, usize> = HashMap::new();
+    let mut predecessors: HashMap<SymbolId, Vec<&SymbolReference>> = HashMap::new();
+    let mut queue: VecDeque<SymbolId> = VecDeque::new();
+
+    let mut entries: Vec<SymbolId> = call_graph.entry_points.iter().copied().collect();
+    entries.sort_by_key(|id| id.0);
+    for id in entries {
+        distance.insert(id, 0);
+        queue.push_back(id);
+    }
+
+    while let Some(current_id) = queue.pop_front() {
+        let next_distance = distance[&current_id] + 1;
+
+        for &reference in outgoing.get(&current_id).into_iter().flatten() {
+            match distance.get(&reference.to_id) {
+                None => {
+                    distance.insert(reference.to_id, next_distance);
+                    predecessors
+                        .entry(reference.to_id)
+                        .or_default()
+                        .push(reference);
+                    queue.push_back(reference.to_id);
+                }
+                Some(&d) if d == next_distance => {
+                    predecessors
+                        .entry(reference.to_id)
+                        .or_default()
+                        .push(reference);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut paths = Vec::new();
+    if distance.contains_key(&target) {
+        let mut stack = Vec::new();
+        collect_paths(target, &predecessors, &mut stack, &mut paths, max_paths);
+    }
+    paths
+}
+
+/// Walk predecessor edges back from `current` to an entry point.
+fn collect_paths<'a>(
+    current: SymbolId,
+    predecessors: &HashMap<SymbolId, Vec<&'a SymbolReference>>,
+    stack: &mut Vec<&'a SymbolReference>,
+    paths: &mut Vec<LivenessPath>,
+    max_paths: usize,
+) {
+    if paths.len() >= max_paths {
+        return;
+    }
+
+    match predecessors.get(&current) {
+        // Entry points are the only reached symbols without predecessors
+        None => paths.push(LivenessPath {
+            entry: current,
+            edges: stack.iter().rev().map(|r| (*r).clone()).collect(),
+        }),
+        Some(edges) => {
+            for &edge in edges {
+                stack.push(edge);
+                collect_paths(edge.from_id, predecessors, stack, paths, max_paths);
+                stack.pop();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{EntryPointSource, Location, ReferenceKind};
+    use std::path::PathBuf;
+
+    fn add_ref(graph: &mut CallGraph, from: u32, to: u32) {
+        graph.add_reference(SymbolReference::new(
+            SymbolId::new(from),
+            SymbolId::new(to),
+            ReferenceKind::Call,
+            Location::new(PathBuf::from("test.ts"), 0, 10, 1, 1),
+        ));
+    }
+
+    fn mark_entry(graph: &mut CallGraph, id: u32) {
+        graph.mark_entry_point(
+            SymbolId::new(id),
+            EntryPointSource::ConfigExport {
+                name: "main".to_string(),
+            },
+        );
+    }
+
+    #[test]
+    fn test_entry_point_has_empty_path() {
+        let mut graph = CallGraph::new();
+        mark_entry(&mut graph, 0);
+
+        let paths = find_liveness_paths(&graph, SymbolId::new(0), 3);
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].entry, SymbolId::new(0));
+        assert!(paths[0].edges.is_empty());
+    }
+
+    #[test]
+    fn test_shortest_path_is_preferred() {
+        let mut graph = CallGraph::new();
+        mark_entry(&mut graph, 0);
+        // 0 -> 1 -> 2 -> 3 and 0 -> 3
+        add_ref(&mut graph, 0, 1);
+        add_ref(&mut graph, 1, 2);
+        add_ref(&mut graph, 2, 3);
+        add_ref(&mut graph, 0, 3);
+
+        let paths = find_liveness_paths(&graph, SymbolId::new(3), 3);
+
+        assert_eq!(paths.len(), 1);
+        assert_eq!(paths[0].edges.len(), 1);
+        assert_eq!(paths[0].edges[0].from_id, SymbolId::new(0));
+    }
+
+    #[test]
+    fn test_multiple_shortest_paths() {
+        let mut graph = CallGraph::new();
+        mark_entry(&mut graph, 0);
+        mark_entry(&mut graph, 1);
+        // 0 -> 2 and 1 -> 2
+        add_ref(&mut graph, 0, 2);
+        add_ref(&mut graph, 1, 2);
+
+        let paths = find_liveness_paths(&graph, SymbolId::new(2), 3);
+        assert_eq!(paths.len(), 2);
+
+        let limited = find_liveness_paths(&graph, SymbolId::new(2), 1);
+        assert_eq!(limited.len(), 1);
+    }
+
+    #[test]
+    fn test_unreachable_has_no_paths() {
+        let mut graph = CallGraph::new();
+        mark_entry(&mut graph, 0);
+        add_ref(&mut graph, 1, 2);
+
+        assert!(find_liveness_paths(&graph, SymbolId::new(2), 3).is_empty());
+    }
+}
@@ -1,10 +1,11 @@
 //! The `ddd analyze` command implementation.
 
+use crate::analysis::coverage::CoverageData;
 use crate::analysis::Analyzer;
 use crate::cli::output::OutputWriter;
-use crate::cli::AnalyzeArgs;
-use crate::config::Config;
-use crate::core::Result;
+use crate::cli::{AnalyzeArgs, TableLayout};
+use crate::config::{Config, ConfigMatcher, OutputConfig, OutputFormat};
+use crate::core::{DddError, DeadSymbol, Result};
 use indicatif::{ProgressBar, ProgressStyle};
 use std::path::Path;
 use std::time::Instant;
@@ -27,16 +28,58 @@ pub fn run_analyze(args: &AnalyzeArgs, path: &Path, config: &Config, verbose: bo
         None
     };
 
+    let mut config = config.clone();
+    if args.git_history {
+        config.history.enabled = true;
+    }
+    if let Some(min_age) = args.min_age {
+        config.history.min_age = min_age;
+    }
+    apply_output_args(&mut config.output, args)?;
+
     // Create analyzer
     let mut analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    if let Some(coverage_path) = &args.coverage {
+        analyzer = analyzer.with_coverage(CoverageData::load(coverage_path, path)?);
+    }
+    if let Some(trace_path) = &args.trace {
+        analyzer = analyzer.with_trace(CoverageData::load_trace(trace_path, path)?);
+    }
 
     // Update progress
     if let Some(ref pb) = progress {
         pb.set_message("Parsing files...");
     }
 
-    // Run analysis
-    let result = analyzer.analyze(progress.as_ref())?;
+    // Dead symbols are reported above the confidence level, which overrides
+    // can change per file, and above the minimum age
+    let min_age = config.history.min_age;
+    let matcher = ConfigMatcher::new(&config);
+    let report = |d: &DeadSymbol| {
+        let path = &d.symbol.location.file_path;
+        let min_confidence = matcher.min_confidence_for(path, args.confidence);
+        d.confidence >= min_confidence.to_confidence()
+            && (min_age == 0 || d.history.as_ref().is_some_and(|h| h.age_days >= min_age))
+    };
+
+    let mut writer = OutputWriter::new(config.output.format, verbose)
+        .with_root(path)
+        .with_output_config(&config.output)
+        .with_top(args.top)
+        .with_link_base(args.link_base.as_deref());
+    if let Some(output) = &args.output {
+        writer = writer.with_output(output)?;
+    }
+
+    // Run analysis, streaming ndjson records as they are final
+    let result = if config.output.format == OutputFormat::Ndjson {
+        let mut stream = writer.ndjson_stream(report);
+        let result = analyzer.analyze_into(progress.as_ref(), &mut stream);
+        stream.finish()?;
+        result?
+    } else {
+        analyzer.analyze(progress.as_ref())?
+    };
 
     // Finish progress
     if let Some(pb) = progress {
@@ -45,13 +88,14 @@ pub fn run_analyze(args: &AnalyzeArgs, path: &Path, config: &Config, verbose: bo
 
     let duration = start.elapsed();
 
-    // Filter by confidence level
-    let min_confidence = args.confidence.to_confidence();
-    let filtered_dead = result.filter_by_confidence(min_confidence);
-
     // Write output
-    let mut writer = OutputWriter::new(args.format.into(), verbose);
-    writer.write_result(&result, &filtered_dead, args.show_chains)?;
+    let filtered_dead: Vec<_> = result.dead_symbols.iter().filter(|d| report(d)).collect();
+    if config.output.format == OutputFormat::Ndjson {
+        writer.write_ndjson_summary(&result, &filtered_dead)?;
+    } else {
+        writer.write_result(&result, &filtered_dead, config.output.show_chains)?;
+    }
+    writer.finish()?;
 
     // Print summary
     if !args.check {
@@ -69,6 +113,21 @@ pub fn run_analyze(args: &AnalyzeArgs, path: &Path, config: &Config, verbose: bo
         );
     }
 
+    // List entry points with their provenance
+    if verbose {
+        eprintln!();
+        eprintln!("Entry points ({}):", result.entry_points.len());
+        for entry in &result.entry_points {
+            let sources: Vec<_> = entry.sources.iter().map(|s| s.description()).collect();
+            eprintln!(
+                "  {} {} - {}",
+                entry.symbol.location.display(),
+                entry.symbol.name,
+                sources.join("; ")
+            );
+        }
+    }
+
     // Print warnings
     for warning in &result.warnings {
         eprintln!("Warning: {}", warning.message);
@@ -81,3 +140,38 @@ pub fn run_analyze(args: &AnalyzeArgs, path: &Path, config: &Config, verbose: bo
         Ok(0)
     }
 }
+
+/// Override the config's output settings with the flags given on the command line.
+fn apply_output_args(output: &mut OutputConfig, args: &AnalyzeArgs) -> Result<()> {
+    if let Some(format) = args.format {
+        output.format = format.into();
+    }
+    if let Some(confidence) = args.confidence {
+        output.min_confidence = confidence;
+    }
+    if args.show_chains {
+        output.show_chains = true;
+    }
+    if args.no_chains {
+        output.show_chains = false;
+    }
+    if let Some(max_chain_length) = args.max_chain_length {
+        output.max_chain_length = max_chain_length;
+    }
+    if let Some(layout) = args.layout {
+        output.group_by_file = layout == TableLayout::Grouped;
+    }
+    if let Some(sort) = args.sort {
+        output.sort = sort;
+    }
+    if let Some(columns) = &args.columns {
+        output.columns = columns.clone();
+    }
+
+    if output.columns.is_empty() {
+        return Err(DddError::config_error(
+            "output.columns must list at least one column",
+        ));
+    }
+    Ok(())
+}
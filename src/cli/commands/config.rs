@@ -0,0 +1,49 @@
+//! The `ddd config` command implementation.
+
+use crate::cli::ConfigArgs;
+use crate::config::Config;
+use crate::core::{DddError, Result};
+use colored::Colorize;
+use std::path::Path;
+
+/// Run the config command.
+pub fn run_config(args: &ConfigArgs, path: &Path, config: &Config) -> Result<i32> {
+    let Some(file) = &args.explain else {
+        println!("{}", to_toml(config)?);
+        return Ok(0);
+    };
+
+    let file = path.join(file);
+    println!("{}", file.display().to_string().bold());
+
+    let overrides = config.matching_overrides(&file);
+    if overrides.is_empty() {
+        println!("  Overrides: {}", "none".dimmed());
+    } else {
+        println!("  Overrides:");
+        for (i, o) in &overrides {
+            println!("    #{} files = {:?}", i + 1, o.files);
+        }
+    }
+
+    let status = if config.should_include(&file) {
+        "analyzed".green()
+    } else {
+        "not analyzed (excluded or skipped by an override)".yellow()
+    };
+    println!("  Status:    {}", status);
+    println!();
+
+    // Show the settings that apply to the file, without the overrides themselves
+    let mut effective = config.for_path(&file).into_owned();
+    effective.overrides.clear();
+    println!("{}", to_toml(&effective)?);
+
+    Ok(0)
+}
+
+/// Serialize a config as TOML.
+fn to_toml(config: &Config) -> Result<String> {
+    toml::to_string_pretty(config)
+        .map_err(|e| DddError::config_error(format!("Failed to serialize config: {}", e)))
+}
@@ -0,0 +1,26 @@
+//! The `ddd cycles` command implementation.
+
+use crate::analysis::cycles::find_import_cycles;
+use crate::analysis::Analyzer;
+use crate::cli::output::OutputWriter;
+use crate::cli::CyclesArgs;
+use crate::config::Config;
+use crate::core::Result;
+use std::path::Path;
+
+/// Run the cycles command.
+pub fn run_cycles(args: &CyclesArgs, path: &Path, config: &Config) -> Result<i32> {
+    let analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    let call_graph = analyzer.build_call_graph(None)?;
+    let cycles = find_import_cycles(&call_graph);
+
+    let mut writer = OutputWriter::new(args.format.into(), false).with_root(path);
+    writer.write_cycles(&call_graph, &cycles)?;
+    writer.finish()?;
+
+    let files: usize = cycles.iter().map(|c| c.files.len()).sum();
+    eprintln!();
+    eprintln!("{} import cycles involving {} files", cycles.len(), files);
+
+    Ok(if cycles.is_empty() { 0 } else { 1 })
+}
@@ -0,0 +1,33 @@
+//! The `ddd deprecated` command implementation.
+
+use crate::analysis::deprecated::find_deprecated;
+use crate::analysis::Analyzer;
+use crate::cli::output::OutputWriter;
+use crate::cli::DeprecatedArgs;
+use crate::config::Config;
+use crate::core::Result;
+use std::path::Path;
+
+/// Run the deprecated command.
+pub fn run_deprecated(args: &DeprecatedArgs, path: &Path, config: &Config) -> Result<i32> {
+    let analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    let call_graph = analyzer.build_call_graph(None)?;
+    let deprecated = find_deprecated(&call_graph);
+
+    let mut writer = OutputWriter::new(args.format.into(), false);
+    writer.write_deprecated(&deprecated)?;
+    writer.finish()?;
+
+    let referenced = deprecated
+        .iter()
+        .filter(|d| !d.referrers.is_empty())
+        .count();
+    eprintln!();
+    eprintln!(
+        "{} deprecated symbols, {} still referenced",
+        deprecated.len(),
+        referenced
+    );
+
+    Ok(0)
+}
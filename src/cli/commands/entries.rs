@@ -0,0 +1,30 @@
+//! The `ddd entries` command implementation.
+
+use crate::analysis::Analyzer;
+use crate::cli::output::OutputWriter;
+use crate::cli::EntriesArgs;
+use crate::config::Config;
+use crate::core::Result;
+use std::collections::HashSet;
+use std::path::Path;
+
+/// Run the entries command.
+pub fn run_entries(args: &EntriesArgs, path: &Path, config: &Config) -> Result<i32> {
+    let analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    let call_graph = analyzer.build_call_graph(None)?;
+    let entry_points = call_graph.collect_entry_points();
+
+    let mut writer = OutputWriter::new(args.format.into(), false).with_root(path);
+    writer.write_entry_points(&entry_points)?;
+    writer.finish()?;
+
+    let files: HashSet<_> = entry_points.iter().map(|e| e.symbol.file_id).collect();
+    eprintln!();
+    eprintln!(
+        "{} entry points in {} files",
+        entry_points.len(),
+        files.len()
+    );
+
+    Ok(0)
+}
@@ -0,0 +1,142 @@
+//! The `ddd explain` command implementation.
+
+use crate::analysis::lookup::find_symbols;
+use crate::analysis::why::find_liveness_paths;
+use crate::analysis::Analyzer;
+use crate::cli::output::{describe_symbol, format_confidence, format_history};
+use crate::cli::ExplainArgs;
+use crate::config::Config;
+use crate::core::{DeadSymbol, Result};
+use colored::Colorize;
+use std::path::Path;
+
+/// Run the explain command.
+pub fn run_explain(args: &ExplainArgs, path: &Path, config: &Config) -> Result<i32> {
+    let analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    let call_graph = analyzer.build_call_graph(None)?;
+    let result = analyzer.analyze_call_graph(&call_graph, None);
+
+    let targets = find_symbols(&call_graph, path, &args.symbol)?;
+
+    for (i, &target) in targets.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let Some(dead) = result.dead_symbols.iter().find(|d| d.symbol.id == target) else {
+            let alive = !find_liveness_paths(&call_graph, target, 1).is_empty();
+            let suppressed = call_graph
+                .symbols
+                .get(&target)
+                .is_some_and(|s| s.suppressed);
+            let status = if alive {
+                "is alive; run `ddd why` to see what keeps it alive".green()
+            } else if suppressed {
+                "is not reported as dead (suppressed by a ddd-ignore comment)".yellow()
+            } else {
+                "is not reported as dead (excluded by configuration)".yellow()
+            };
+            println!("{} {}", describe_symbol(&call_graph, target), status);
+            continue;
+        };
+
+        println!(
+            "{} {}",
+            describe_symbol(&call_graph, target),
+            "is dead".red().bold()
+        );
+        println!("  Reason:     {}", dead.reason.description());
+        println!(
+            "  Confidence: {}",
+            format_confidence(dead.confidence, dead.confidence_score)
+        );
+        if let Some(history) = &dead.history {
+            println!("  History:    {}", format_history(history));
+        }
+        println!();
+        for line in score_breakdown(dead) {
+            println!("{}", line);
+        }
+    }
+
+    Ok(0)
+}
+
+/// Show how a dead symbol's confidence score was reached, one rule per line.
+fn score_breakdown(dead: &DeadSymbol) -> Vec<String> {
+    let mut lines = vec![format!("  {:>5}  base score", dead.base_confidence)];
+
+    for factor in &dead.confidence_factors {
+        lines.push(format!(
+            "  {:>+5}  {} ({})",
+            factor.delta,
+            factor.description,
+            factor.rule.dimmed()
+        ));
+    }
+
+    let total =
+        dead.base_confidence as i32 + dead.confidence_factors.iter().map(|f| f.delta).sum::<i32>();
+    if total != dead.confidence_score as i32 {
+        lines.push(format!("  {:>5}  clamped to 0-100", ""));
+    }
+    lines.push(format!(
+        "  {:>5}  {}",
+        "=",
+        dead.confidence_score.to_string().bold()
+    ));
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Strip terminal styling, which depends on where the tests run.
+    fn plain(lines: Vec<String>) -> Vec<String> {
+        let ansi = regex_lite::Regex::new("\x1b\\[[0-9;]*m").unwrap();
+        lines
+            .iter()
+            .map(|line| ansi.replace_all(line, "").into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn test_score_breakdown() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("lib.ts"),
+            "export function unused() { helper(); }\nfunction helper() {}\n",
+        )
+        .unwrap();
+
+        let analyzer = Analyzer::new(Config::default(), dir.path().to_path_buf()).unwrap();
+        let call_graph = analyzer.build_call_graph(None).unwrap();
+        let result = analyzer.analyze_call_graph(&call_graph, None);
+        let dead = |name: &str| {
+            result
+                .dead_symbols
+                .iter()
+                .find(|d| d.symbol.name == name)
+                .unwrap()
+        };
+
+        let helper = dead("helper");
+        assert_eq!(helper.base_confidence, 100);
+        assert_eq!(
+            plain(score_breakdown(helper)),
+            vec!["    100  base score", "      =  100"]
+        );
+
+        let unused = dead("unused");
+        assert_eq!(unused.base_confidence, 100);
+        assert_eq!(
+            plain(score_breakdown(unused)),
+            vec![
+                "    100  base score",
+                "    -10  exported (exported)",
+                "      =  90",
+            ]
+        );
+    }
+}
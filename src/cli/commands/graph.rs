@@ -0,0 +1,38 @@
+//! The `ddd graph` command implementation.
+
+use crate::analysis::lookup::find_symbols;
+use crate::analysis::subgraph::{select_subgraph, GraphFilter};
+use crate::analysis::Analyzer;
+use crate::cli::output::OutputWriter;
+use crate::cli::GraphArgs;
+use crate::config::{Config, OutputFormat};
+use crate::core::Result;
+use std::path::Path;
+
+/// Run the graph command.
+pub fn run_graph(args: &GraphArgs, path: &Path, config: &Config) -> Result<i32> {
+    let analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    let call_graph = analyzer.build_call_graph(None)?;
+    let result = analyzer.analyze_call_graph(&call_graph, None);
+
+    let focus = match &args.symbol {
+        Some(spec) => find_symbols(&call_graph, path, spec)?,
+        None => Vec::new(),
+    };
+    let filter = GraphFilter {
+        file: args.file.clone(),
+        dir: args.dir.clone(),
+        focus,
+        depth: args.depth,
+    };
+    let subgraph = select_subgraph(&call_graph, path, &filter)?;
+
+    let mut writer = OutputWriter::new(OutputFormat::Json, false).with_root(path);
+    if let Some(output) = &args.output {
+        writer = writer.with_output(output)?;
+    }
+    writer.write_graph(&call_graph, &result.dead_symbols, &subgraph, args.format)?;
+    writer.finish()?;
+
+    Ok(0)
+}
@@ -1,9 +1,25 @@
 //! CLI command implementations.
 
 pub mod analyze;
+pub mod config;
+pub mod cycles;
+pub mod deprecated;
+pub mod entries;
+pub mod explain;
+pub mod graph;
 pub mod init;
+pub mod schema;
 pub mod watch;
+pub mod why;
 
 pub use analyze::run_analyze;
+pub use config::run_config;
+pub use cycles::run_cycles;
+pub use deprecated::run_deprecated;
+pub use entries::run_entries;
+pub use explain::run_explain;
+pub use graph::run_graph;
 pub use init::run_init;
+pub use schema::run_schema;
 pub use watch::run_watch;
+pub use why::run_why;
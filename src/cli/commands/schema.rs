@@ -0,0 +1,12 @@
+//! The `ddd schema` command implementation.
+
+use crate::cli::output::json_schema;
+use crate::core::{DddError, Result};
+
+/// Run the schema command.
+pub fn run_schema() -> Result<()> {
+    let schema = serde_json::to_string_pretty(&json_schema())
+        .map_err(|e| DddError::analysis_error(format!("JSON serialization failed: {}", e)))?;
+    println!("{}", schema);
+    Ok(())
+}
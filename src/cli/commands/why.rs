@@ -0,0 +1,65 @@
+//! The `ddd why` command implementation.
+
+use crate::analysis::lookup::find_symbols;
+use crate::analysis::why::find_liveness_paths;
+use crate::analysis::Analyzer;
+use crate::cli::output::describe_symbol;
+use crate::cli::WhyArgs;
+use crate::config::Config;
+use crate::core::Result;
+use colored::Colorize;
+use std::path::Path;
+
+/// Run the why command.
+pub fn run_why(args: &WhyArgs, path: &Path, config: &Config) -> Result<i32> {
+    let analyzer = Analyzer::new(config.clone(), path.to_path_buf())?;
+    let call_graph = analyzer.build_call_graph(None)?;
+
+    let targets = find_symbols(&call_graph, path, &args.symbol)?;
+    let mut all_alive = true;
+
+    for (i, &target) in targets.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+
+        let paths = find_liveness_paths(&call_graph, target, args.max_paths);
+
+        if paths.is_empty() {
+            all_alive = false;
+            println!(
+                "{} {}",
+                describe_symbol(&call_graph, target),
+                "is not reachable from any entry point".red().bold()
+            );
+            continue;
+        }
+
+        println!(
+            "{} {}",
+            describe_symbol(&call_graph, target),
+            "is alive".green().bold()
+        );
+
+        for (n, liveness_path) in paths.iter().enumerate() {
+            println!("\n{}", format!("Path {}:", n + 1).bold());
+            println!("  {}", describe_symbol(&call_graph, liveness_path.entry));
+
+            for source in call_graph.get_entry_point_sources(liveness_path.entry) {
+                println!("    {} entry point: {}", "◆".cyan(), source.description());
+            }
+
+            for edge in &liveness_path.edges {
+                println!(
+                    "  {} {} at {}",
+                    "└─".dimmed(),
+                    edge.kind.label(),
+                    edge.location.display()
+                );
+                println!("  {}", describe_symbol(&call_graph, edge.to_id));
+            }
+        }
+    }
+
+    Ok(if all_alive { 0 } else { 1 })
+}
@@ -6,7 +6,7 @@ pub mod output;
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 
-use crate::config::ConfidenceLevel;
+use crate::config::{ConfidenceLevel, SortOrder, TableColumn};
 
 /// ddd - Conservative TypeScript dead code detection.
 #[derive(Parser, Debug)]
@@ -47,6 +47,30 @@ pub enum Commands {
 
     /// Watch for file changes and analyze continuously
     Watch(WatchArgs),
+
+    /// Explain why a symbol is alive
+    Why(WhyArgs),
+
+    /// List entry points and the rules that marked them
+    Entries(EntriesArgs),
+
+    /// Explain how a dead symbol's confidence score was reached
+    Explain(ExplainArgs),
+
+    /// List @deprecated symbols and what still references them
+    Deprecated(DeprecatedArgs),
+
+    /// Show the loaded configuration, or the effective settings for a file
+    Config(ConfigArgs),
+
+    /// Export the call graph as Graphviz DOT, Mermaid or JSON
+    Graph(GraphArgs),
+
+    /// List circular imports and the import statements that form them
+    Cycles(CyclesArgs),
+
+    /// Print the JSON Schema for `analyze --format json` output
+    Schema,
 }
 
 /// Arguments for the init command.
@@ -64,18 +88,50 @@ pub struct InitArgs {
 /// Arguments for the analyze command.
 #[derive(Parser, Debug, Clone)]
 pub struct AnalyzeArgs {
-    /// Output format: table, json, or compact
-    #[arg(short, long, default_value = "table")]
-    pub format: OutputFormat,
+    /// Output format: table, json, ndjson, compact, sarif, github, gitlab, junit, checkstyle, html, or markdown (overrides output.format)
+    #[arg(short, long)]
+    pub format: Option<OutputFormat>,
+
+    /// Write the report to a file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Number of dead symbols listed in the markdown summary's top table
+    #[arg(long, value_name = "N", default_value = "10")]
+    pub top: usize,
 
-    /// Minimum confidence level to report: high, medium, or low
-    #[arg(long, default_value = "high")]
-    pub confidence: ConfidenceLevel,
+    /// URL prefix for file links in markdown, e.g. https://github.com/org/repo/blob/<sha>
+    #[arg(long, value_name = "URL")]
+    pub link_base: Option<String>,
 
-    /// Show transitive dead code chains
+    /// Minimum confidence level to report: high, medium, or low (overrides output.minConfidence)
     #[arg(long)]
+    pub confidence: Option<ConfidenceLevel>,
+
+    /// Show transitive dead code chains in verbose table output
+    #[arg(long, overrides_with = "no_chains")]
     pub show_chains: bool,
 
+    /// Hide transitive dead code chains (overrides output.showChains)
+    #[arg(long, overrides_with = "show_chains")]
+    pub no_chains: bool,
+
+    /// Maximum number of chain members to list (overrides output.maxChainLength)
+    #[arg(long, value_name = "N")]
+    pub max_chain_length: Option<usize>,
+
+    /// Table layout: grouped under file headings, or one flat table (overrides output.groupByFile)
+    #[arg(long)]
+    pub layout: Option<TableLayout>,
+
+    /// Order of table and compact output: file, confidence, impact, or kind (overrides output.sort)
+    #[arg(long)]
+    pub sort: Option<SortOrder>,
+
+    /// Comma-separated table columns: file, line, name, kind, confidence, reason, impact (overrides output.columns)
+    #[arg(long, value_delimiter = ',', value_name = "COLUMNS")]
+    pub columns: Option<Vec<TableColumn>>,
+
     /// Only check, exit with error if dead code found
     #[arg(long)]
     pub check: bool,
@@ -91,18 +147,46 @@ pub struct AnalyzeArgs {
     /// Include test files in analysis
     #[arg(long)]
     pub include_tests: bool,
+
+    /// Coverage file (Istanbul JSON, lcov or V8 JSON) used as a confidence signal
+    #[arg(long, value_name = "FILE")]
+    pub coverage: Option<PathBuf>,
+
+    /// Runtime trace (JSON Lines of file, line/offset/name, hits) from production
+    #[arg(long, value_name = "FILE")]
+    pub trace: Option<PathBuf>,
+
+    /// Read git history to date dead code
+    #[arg(long)]
+    pub git_history: bool,
+
+    /// Only report dead code untouched for at least this many days (reads git history)
+    #[arg(long, value_name = "DAYS")]
+    pub min_age: Option<u64>,
 }
 
 impl Default for AnalyzeArgs {
     fn default() -> Self {
         Self {
-            format: OutputFormat::Table,
-            confidence: ConfidenceLevel::High,
+            format: None,
+            output: None,
+            top: 10,
+            link_base: None,
+            confidence: None,
             show_chains: false,
+            no_chains: false,
+            max_chain_length: None,
+            layout: None,
+            sort: None,
+            columns: None,
             check: false,
             progress: false,
             jobs: None,
             include_tests: false,
+            coverage: None,
+            trace: None,
+            git_history: false,
+            min_age: None,
         }
     }
 }
@@ -119,13 +203,117 @@ pub struct WatchArgs {
     pub clear: bool,
 }
 
+/// Arguments for the why command.
+#[derive(Parser, Debug, Clone)]
+pub struct WhyArgs {
+    /// Symbol to explain, as <file>:<name>
+    pub symbol: String,
+
+    /// Maximum number of paths to show
+    #[arg(long, default_value = "3")]
+    pub max_paths: usize,
+}
+
+/// Arguments for the entries command.
+#[derive(Parser, Debug, Clone)]
+pub struct EntriesArgs {
+    /// Output format: table, json, or compact
+    #[arg(short, long, default_value = "table")]
+    pub format: OutputFormat,
+}
+
+/// Arguments for the explain command.
+#[derive(Parser, Debug, Clone)]
+pub struct ExplainArgs {
+    /// Symbol to explain, as <file>:<name>
+    pub symbol: String,
+}
+
+/// Arguments for the deprecated command.
+#[derive(Parser, Debug, Clone)]
+pub struct DeprecatedArgs {
+    /// Output format: table, json, or compact
+    #[arg(short, long, default_value = "table")]
+    pub format: OutputFormat,
+}
+
+/// Arguments for the config command.
+#[derive(Parser, Debug, Clone)]
+pub struct ConfigArgs {
+    /// Show the settings that apply to this file, with overrides applied
+    #[arg(long, value_name = "FILE")]
+    pub explain: Option<PathBuf>,
+}
+
+/// Arguments for the graph command.
+#[derive(Parser, Debug, Clone)]
+pub struct GraphArgs {
+    /// Graph format: dot, mermaid, or json
+    #[arg(short, long, default_value = "dot")]
+    pub format: GraphFormat,
+
+    /// Write the graph to a file instead of stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Only include symbols defined in this file
+    #[arg(long, value_name = "FILE")]
+    pub file: Option<PathBuf>,
+
+    /// Only include symbols defined under this directory
+    #[arg(long, value_name = "DIR")]
+    pub dir: Option<PathBuf>,
+
+    /// Only include symbols near this one, as <file>:<name>
+    #[arg(long, value_name = "SYMBOL")]
+    pub symbol: Option<String>,
+
+    /// Maximum number of references between --symbol and included symbols
+    #[arg(long, value_name = "N", default_value = "2")]
+    pub depth: usize,
+}
+
+/// Arguments for the cycles command.
+#[derive(Parser, Debug, Clone)]
+pub struct CyclesArgs {
+    /// Output format: table, json, or compact
+    #[arg(short, long, default_value = "table")]
+    pub format: OutputFormat,
+}
+
+/// Layout of the dead code table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TableLayout {
+    /// One table per file, under a file heading
+    Grouped,
+    /// One table for all files
+    Flat,
+}
+
+/// Export format for the call graph.
+#[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
+pub enum GraphFormat {
+    #[default]
+    Dot,
+    Mermaid,
+    Json,
+}
+
 /// Output format for analysis results.
 #[derive(Debug, Clone, Copy, Default, clap::ValueEnum)]
 pub enum OutputFormat {
     #[default]
     Table,
     Json,
+    Ndjson,
     Compact,
+    Sarif,
+    Github,
+    Gitlab,
+    Junit,
+    Checkstyle,
+    Html,
+    Markdown,
 }
 
 impl Cli {
@@ -136,7 +324,9 @@ impl Cli {
 
     /// Get the effective command, defaulting to analyze.
     pub fn effective_command(&self) -> Commands {
-        self.command.clone().unwrap_or(Commands::Analyze(AnalyzeArgs::default()))
+        self.command
+            .clone()
+            .unwrap_or(Commands::Analyze(AnalyzeArgs::default()))
     }
 }
 
@@ -145,7 +335,15 @@ impl From<OutputFormat> for crate::config::OutputFormat {
         match f {
             OutputFormat::Table => crate::config::OutputFormat::Table,
             OutputFormat::Json => crate::config::OutputFormat::Json,
+            OutputFormat::Ndjson => crate::config::OutputFormat::Ndjson,
             OutputFormat::Compact => crate::config::OutputFormat::Compact,
+            OutputFormat::Sarif => crate::config::OutputFormat::Sarif,
+            OutputFormat::Github => crate::config::OutputFormat::Github,
+            OutputFormat::Gitlab => crate::config::OutputFormat::Gitlab,
+            OutputFormat::Junit => crate::config::OutputFormat::Junit,
+            OutputFormat::Checkstyle => crate::config::OutputFormat::Checkstyle,
+            OutputFormat::Html => crate::config::OutputFormat::Html,
+            OutputFormat::Markdown => crate::config::OutputFormat::Markdown,
         }
     }
 }
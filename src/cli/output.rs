@@ -1,9 +1,34 @@
 //! Output formatting for analysis results.
 
-use crate::config::OutputFormat;
-use crate::core::{AnalysisResult, Confidence, DeadSymbol, Result, SymbolKind};
-use colored::Colorize;
+mod annotations;
+mod cycles;
+#[cfg(test)]
+mod fixtures;
+mod graph;
+mod html;
+mod json;
+mod markdown;
+mod ndjson;
+mod sarif;
+mod table;
+mod xml;
+
+pub use json::json_schema;
+
+use crate::analysis::deprecated::DeprecatedSymbol;
+use crate::analysis::history::format_date;
+use crate::config::{OutputConfig, OutputFormat, SortOrder, TableColumn};
+use crate::core::{
+    normalize_path, relative_to, AnalysisResult, CallGraph, Confidence, DddError, DeadSymbol,
+    EntryPoint, GitHistory, Result, SymbolId, SymbolKind, TrackedSymbol,
+};
+use colored::{ColoredString, Colorize};
+use json::{JsonDeprecatedSymbol, JsonEntryPoint};
 use std::collections::HashMap;
+use std::fmt::Display;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
 use tabled::{
     settings::{object::Rows, Alignment, Modify, Style},
     Table, Tabled,
@@ -13,11 +38,76 @@ use tabled::{
 pub struct OutputWriter {
     format: OutputFormat,
     verbose: bool,
+    /// Project root, for formats that report paths relative to it.
+    root: Option<PathBuf>,
+    out: Box<dyn Write>,
+    /// Output file, if not writing to stdout.
+    output_path: Option<PathBuf>,
+    group_by_file: bool,
+    max_chain_length: usize,
+    sort: SortOrder,
+    /// Columns of the dead code table, in order.
+    columns: Vec<TableColumn>,
+    /// Dead symbols listed in summaries that show only the biggest.
+    top: usize,
+    /// URL prefix for file links, e.g. a repository's blob URL at a commit.
+    link_base: Option<String>,
+    /// Whether to style text with terminal colors.
+    color: bool,
 }
 
 impl OutputWriter {
     pub fn new(format: OutputFormat, verbose: bool) -> Self {
-        Self { format, verbose }
+        Self {
+            format,
+            verbose,
+            root: None,
+            out: Box::new(std::io::stdout()),
+            output_path: None,
+            group_by_file: true,
+            max_chain_length: 5,
+            sort: SortOrder::Impact,
+            columns: OutputConfig::default().columns,
+            top: 10,
+            link_base: None,
+            color: true,
+        }
+    }
+
+    /// Apply the `[output]` layout settings.
+    pub fn with_output_config(mut self, config: &OutputConfig) -> Self {
+        self.group_by_file = config.group_by_file;
+        self.max_chain_length = config.max_chain_length;
+        self.sort = config.sort;
+        self.columns = config.columns.clone();
+        self
+    }
+
+    /// Set how many dead symbols summaries list.
+    pub fn with_top(mut self, top: usize) -> Self {
+        self.top = top;
+        self
+    }
+
+    /// Prefix file links with a URL instead of leaving them relative.
+    pub fn with_link_base(mut self, base: Option<&str>) -> Self {
+        self.link_base = base.map(str::to_string);
+        self
+    }
+
+    /// Write to a file instead of stdout, without colors.
+    pub fn with_output(mut self, path: &Path) -> Result<Self> {
+        let file = File::create(path).map_err(|e| DddError::output_error(path.to_path_buf(), e))?;
+        self.out = Box::new(BufWriter::new(file));
+        self.color = false;
+        self.output_path = Some(path.to_path_buf());
+        Ok(self)
+    }
+
+    /// Report paths relative to the project root where the format calls for it.
+    pub fn with_root(mut self, root: &Path) -> Self {
+        self.root = Some(normalize_path(root));
+        self
     }
 
     /// Write analysis results to stdout.
@@ -28,109 +118,274 @@ impl OutputWriter {
         show_chains: bool,
     ) -> Result<()> {
         match self.format {
-            OutputFormat::Table => self.write_table(dead_symbols, show_chains),
+            OutputFormat::Table => {
+                self.write_table(result, dead_symbols, show_chains)?;
+                self.write_never_executed_table(&result.never_executed)
+            }
             OutputFormat::Json => self.write_json(result, dead_symbols),
-            OutputFormat::Compact => self.write_compact(dead_symbols),
+            OutputFormat::Ndjson => self.write_ndjson(result, dead_symbols),
+            OutputFormat::Sarif => self.write_sarif(result, dead_symbols),
+            OutputFormat::Github => self.write_github(dead_symbols),
+            OutputFormat::Gitlab => self.write_gitlab(result, dead_symbols),
+            OutputFormat::Junit => self.write_junit(result, dead_symbols),
+            OutputFormat::Checkstyle => self.write_checkstyle(dead_symbols),
+            OutputFormat::Html => self.write_html(result, dead_symbols),
+            OutputFormat::Markdown => self.write_markdown(result, dead_symbols),
+            OutputFormat::Compact => {
+                self.write_compact(dead_symbols)?;
+                for symbol in &result.never_executed {
+                    self.emit(format_args!(
+                        "{}: {} ({}) - never executed in production",
+                        symbol.location.display(),
+                        symbol.name,
+                        format_kind(symbol.kind)
+                    ))?;
+                }
+                Ok(())
+            }
         }
     }
 
-    fn write_table(&self, dead_symbols: &[&DeadSymbol], show_chains: bool) -> Result<()> {
-        if dead_symbols.is_empty() {
-            println!("{}", "No dead code found!".green().bold());
+    /// Write statically alive symbols that a runtime trace never saw execute.
+    fn write_never_executed_table(&mut self, symbols: &[TrackedSymbol]) -> Result<()> {
+        if symbols.is_empty() {
             return Ok(());
         }
 
-        // Group by file
-        let mut by_file: HashMap<String, Vec<&DeadSymbol>> = HashMap::new();
-        for dead in dead_symbols {
-            let file = dead.symbol.location.file_path.display().to_string();
-            by_file.entry(file).or_default().push(dead);
+        self.emit(format_args!(
+            "\n{}",
+            self.paint(
+                format!("Alive but never executed in production ({})", symbols.len())
+                    .yellow()
+                    .bold()
+            )
+        ))?;
+
+        // Group by file (symbols arrive sorted by file and line)
+        let mut current_file: Option<&std::path::Path> = None;
+        let mut rows: Vec<SymbolRow> = Vec::new();
+
+        for symbol in symbols {
+            let file = symbol.location.file_path.as_path();
+            if current_file != Some(file) {
+                self.write_file_rows(current_file, &mut rows)?;
+                current_file = Some(file);
+            }
+            rows.push(SymbolRow {
+                line: symbol.location.line.to_string(),
+                name: symbol.name.clone(),
+                kind: format_kind(symbol.kind),
+            });
         }
+        self.write_file_rows(current_file, &mut rows)?;
+
+        Ok(())
+    }
 
-        // Sort files
-        let mut files: Vec<_> = by_file.keys().cloned().collect();
-        files.sort();
+    /// Apply a style, unless writing without colors.
+    fn paint(&self, text: ColoredString) -> ColoredString {
+        if self.color {
+            text
+        } else {
+            text.clear()
+        }
+    }
 
-        for file in files {
-            println!("\n{}", file.cyan().bold());
+    /// Write a line of output.
+    fn emit(&mut self, line: impl Display) -> Result<()> {
+        writeln!(self.out, "{}", line).map_err(|e| self.write_error(e))
+    }
 
-            let symbols = by_file.get(&file).unwrap();
-            let mut rows: Vec<TableRow> = symbols
-                .iter()
-                .map(|d| TableRow {
-                    line: d.symbol.location.line.to_string(),
-                    name: d.symbol.name.clone(),
-                    kind: format_kind(d.symbol.kind),
-                    confidence: format_confidence(d.confidence, d.confidence_score),
-                    reason: d.reason.description(),
-                })
-                .collect();
+    /// Flush buffered output, reporting any write error.
+    pub fn finish(&mut self) -> Result<()> {
+        self.out.flush().map_err(|e| self.write_error(e))
+    }
 
-            rows.sort_by_key(|r| r.line.parse::<u32>().unwrap_or(0));
+    fn write_error(&self, source: std::io::Error) -> DddError {
+        let path = self
+            .output_path
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("<stdout>"));
+        DddError::output_error(path, source)
+    }
 
-            let table = Table::new(&rows)
-                .with(Style::rounded())
-                .with(Modify::new(Rows::first()).with(Alignment::center()))
-                .to_string();
+    /// Get a file's path relative to the project root, with `/` separators.
+    fn relative_path(&self, path: &Path) -> String {
+        relative_to(self.root.as_deref(), path)
+    }
 
-            println!("{}", table);
-
-            // Show chains if requested
-            if show_chains && self.verbose {
-                for dead in symbols {
-                    if let crate::core::DeadnessReason::Transitive { chain } = &dead.reason {
-                        if !chain.is_empty() {
-                            println!(
-                                "  {} Chain: {} symbols",
-                                "└─".dimmed(),
-                                chain.len()
-                            );
-                        }
+    /// Write entry points and the rules that marked them to stdout.
+    pub fn write_entry_points(&mut self, entry_points: &[EntryPoint]) -> Result<()> {
+        match self.format {
+            OutputFormat::Table => self.write_entry_points_table(entry_points),
+            OutputFormat::Json => {
+                let output: Vec<JsonEntryPoint> = entry_points
+                    .iter()
+                    .map(|entry| self.json_entry_point(entry))
+                    .collect();
+                let json = serde_json::to_string_pretty(&output).map_err(|e| {
+                    crate::core::DddError::analysis_error(format!(
+                        "JSON serialization failed: {}",
+                        e
+                    ))
+                })?;
+                self.emit(json)?;
+                Ok(())
+            }
+            OutputFormat::Compact => {
+                for entry in entry_points {
+                    for source in &entry.sources {
+                        self.emit(format_args!(
+                            "{}: {} ({}) - {}",
+                            entry.symbol.location.display(),
+                            entry.symbol.name,
+                            format_kind(entry.symbol.kind),
+                            source.description()
+                        ))?;
                     }
                 }
+                Ok(())
             }
+            format => Err(DddError::unsupported_format(format.name(), "entries")),
+        }
+    }
+
+    /// Write deprecated symbols and their referrers to stdout.
+    pub fn write_deprecated(&mut self, deprecated: &[DeprecatedSymbol]) -> Result<()> {
+        match self.format {
+            OutputFormat::Table => self.write_deprecated_table(deprecated),
+            OutputFormat::Json => {
+                let output: Vec<JsonDeprecatedSymbol> = deprecated
+                    .iter()
+                    .map(|dep| self.json_deprecated_symbol(dep))
+                    .collect();
+                let json = serde_json::to_string_pretty(&output).map_err(|e| {
+                    crate::core::DddError::analysis_error(format!(
+                        "JSON serialization failed: {}",
+                        e
+                    ))
+                })?;
+                self.emit(json)?;
+                Ok(())
+            }
+            OutputFormat::Compact => {
+                for dep in deprecated {
+                    let status = match dep.referrers.len() {
+                        0 => "unreferenced".to_string(),
+                        n => format!("referenced {} times", n),
+                    };
+                    self.emit(format_args!(
+                        "{}: {} ({}) - deprecated, {}",
+                        dep.symbol.location.display(),
+                        dep.symbol.name,
+                        format_kind(dep.symbol.kind),
+                        status
+                    ))?;
+                }
+                Ok(())
+            }
+            format => Err(DddError::unsupported_format(format.name(), "deprecated")),
+        }
+    }
+
+    fn write_deprecated_table(&mut self, deprecated: &[DeprecatedSymbol]) -> Result<()> {
+        if deprecated.is_empty() {
+            self.emit(self.paint("No deprecated symbols found!".green().bold()))?;
+            return Ok(());
         }
 
+        // Group by file (deprecated symbols arrive sorted by file and line)
+        let mut current_file: Option<&std::path::Path> = None;
+        let mut rows: Vec<DeprecatedRow> = Vec::new();
+
+        for dep in deprecated {
+            let file = dep.symbol.location.file_path.as_path();
+            if current_file != Some(file) {
+                self.write_file_rows(current_file, &mut rows)?;
+                current_file = Some(file);
+            }
+            let referrers = if dep.referrers.is_empty() {
+                self.paint("none - ready to remove".green()).to_string()
+            } else {
+                dep.referrers
+                    .iter()
+                    .map(|r| match &r.symbol {
+                        Some(symbol) => format!("{} ({})", symbol.name, r.location.display()),
+                        None => r.location.display(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join("\n")
+            };
+            rows.push(DeprecatedRow {
+                line: dep.symbol.location.line.to_string(),
+                name: dep.symbol.name.clone(),
+                kind: format_kind(dep.symbol.kind),
+                note: dep.note.clone(),
+                referrers,
+            });
+        }
+        self.write_file_rows(current_file, &mut rows)?;
+
         Ok(())
     }
 
-    fn write_json(&self, result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> Result<()> {
-        let output = JsonOutput {
-            total_symbols: result.total_symbols,
-            total_files: result.total_files,
-            dead_count: dead_symbols.len(),
-            duration_ms: result.duration_ms,
-            dead_symbols: dead_symbols
-                .iter()
-                .map(|d| JsonDeadSymbol {
-                    name: d.symbol.name.clone(),
-                    kind: format!("{:?}", d.symbol.kind),
-                    file: d.symbol.location.file_path.display().to_string(),
-                    line: d.symbol.location.line,
-                    column: d.symbol.location.column,
-                    confidence: d.confidence.label().to_string(),
-                    confidence_score: d.confidence_score,
-                    reason: d.reason.description(),
-                    exported: d.symbol.exported,
-                })
-                .collect(),
-            warnings: result
-                .warnings
-                .iter()
-                .map(|w| w.message.clone())
-                .collect(),
-        };
-
-        let json = serde_json::to_string_pretty(&output)
-            .map_err(|e| crate::core::DddError::analysis_error(format!("JSON serialization failed: {}", e)))?;
-        println!("{}", json);
+    fn write_entry_points_table(&mut self, entry_points: &[EntryPoint]) -> Result<()> {
+        if entry_points.is_empty() {
+            self.emit(self.paint("No entry points found!".yellow().bold()))?;
+            return Ok(());
+        }
+
+        // Group by file (entry points arrive sorted by file and line)
+        let mut current_file: Option<&std::path::Path> = None;
+        let mut rows: Vec<EntryRow> = Vec::new();
+
+        for entry in entry_points {
+            let file = entry.symbol.location.file_path.as_path();
+            if current_file != Some(file) {
+                self.write_file_rows(current_file, &mut rows)?;
+                current_file = Some(file);
+            }
+            rows.push(EntryRow {
+                line: entry.symbol.location.line.to_string(),
+                name: entry.symbol.name.clone(),
+                kind: format_kind(entry.symbol.kind),
+                source: entry
+                    .sources
+                    .iter()
+                    .map(|s| s.description())
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            });
+        }
+        self.write_file_rows(current_file, &mut rows)?;
 
         Ok(())
     }
 
-    fn write_compact(&self, dead_symbols: &[&DeadSymbol]) -> Result<()> {
+    /// Write the accumulated rows for a file, then clear them.
+    fn write_file_rows<T: Tabled>(
+        &mut self,
+        file: Option<&std::path::Path>,
+        rows: &mut Vec<T>,
+    ) -> Result<()> {
+        if let Some(file) = file {
+            let heading = self.paint(file.display().to_string().cyan().bold());
+            self.emit(format_args!("\n{}", heading))?;
+            let table = Table::new(rows.iter())
+                .with(Style::rounded())
+                .with(Modify::new(Rows::first()).with(Alignment::center()))
+                .to_string();
+            self.emit(table)?;
+        }
+        rows.clear();
+        Ok(())
+    }
+
+    fn write_compact(&mut self, dead_symbols: &[&DeadSymbol]) -> Result<()> {
+        let mut dead_symbols = dead_symbols.to_vec();
+        table::sort_dead_symbols(&mut dead_symbols, self.sort);
         for dead in dead_symbols {
-            println!(
+            self.emit(format_args!(
                 "{}:{}:{}: {} ({}) - {}",
                 dead.symbol.location.file_path.display(),
                 dead.symbol.location.line,
@@ -138,50 +393,49 @@ impl OutputWriter {
                 dead.symbol.name,
                 format_kind(dead.symbol.kind),
                 dead.confidence.label()
-            );
+            ))?;
         }
         Ok(())
     }
 }
 
 #[derive(Tabled)]
-struct TableRow {
+struct EntryRow {
     #[tabled(rename = "Line")]
     line: String,
     #[tabled(rename = "Name")]
     name: String,
     #[tabled(rename = "Kind")]
     kind: String,
-    #[tabled(rename = "Confidence")]
-    confidence: String,
-    #[tabled(rename = "Reason")]
-    reason: String,
+    #[tabled(rename = "Source")]
+    source: String,
 }
 
-#[derive(serde::Serialize)]
-struct JsonOutput {
-    total_symbols: usize,
-    total_files: usize,
-    dead_count: usize,
-    duration_ms: u64,
-    dead_symbols: Vec<JsonDeadSymbol>,
-    warnings: Vec<String>,
+#[derive(Tabled)]
+struct DeprecatedRow {
+    #[tabled(rename = "Line")]
+    line: String,
+    #[tabled(rename = "Name")]
+    name: String,
+    #[tabled(rename = "Kind")]
+    kind: String,
+    #[tabled(rename = "Note")]
+    note: String,
+    #[tabled(rename = "Referenced By")]
+    referrers: String,
 }
 
-#[derive(serde::Serialize)]
-struct JsonDeadSymbol {
+#[derive(Tabled)]
+struct SymbolRow {
+    #[tabled(rename = "Line")]
+    line: String,
+    #[tabled(rename = "Name")]
     name: String,
+    #[tabled(rename = "Kind")]
     kind: String,
-    file: String,
-    line: u32,
-    column: u32,
-    confidence: String,
-    confidence_score: u8,
-    reason: String,
-    exported: bool,
 }
 
-fn format_kind(kind: SymbolKind) -> String {
+pub(crate) fn format_kind(kind: SymbolKind) -> String {
     match kind {
         SymbolKind::Function => "fn",
         SymbolKind::ArrowFunction => "=>",
@@ -199,11 +453,83 @@ fn format_kind(kind: SymbolKind) -> String {
     .to_string()
 }
 
-fn format_confidence(confidence: Confidence, score: u8) -> String {
+/// Format a symbol as "name (kind) path:line:column".
+pub(crate) fn describe_symbol(call_graph: &CallGraph, id: SymbolId) -> String {
+    match call_graph.symbols.get(&id) {
+        Some(symbol) => format!(
+            "{} ({}) {}",
+            symbol.name.bold(),
+            format_kind(symbol.kind),
+            symbol.location.display().dimmed()
+        ),
+        None => format!("<symbol #{}>", id.0),
+    }
+}
+
+fn format_impact(dead: &DeadSymbol) -> String {
+    match &dead.impact {
+        Some(impact) if impact.dominated.is_empty() => format!("{} lines", impact.lines),
+        Some(impact) => format!(
+            "{} lines (+{} symbols)",
+            impact.lines,
+            impact.dominated.len()
+        ),
+        None => "-".to_string(),
+    }
+}
+
+/// Name the dead symbols in a kill chain, cut to `max` with a "… (+n more)" tail.
+fn chain_names(
+    chain: &[SymbolId],
+    dead_by_id: &HashMap<SymbolId, &DeadSymbol>,
+    max: usize,
+    name: impl Fn(&str) -> String,
+) -> Vec<String> {
+    if max == 0 {
+        return Vec::new();
+    }
+    let callers: Vec<_> = chain.iter().filter_map(|id| dead_by_id.get(id)).collect();
+    let mut names: Vec<String> = callers
+        .iter()
+        .take(max)
+        .map(|caller| name(&caller.symbol.name))
+        .collect();
+    if callers.len() > max {
+        names.push(format!("… (+{} more)", callers.len() - max));
+    }
+    names
+}
+
+/// Format git history, e.g. "last changed 2024-03-01 (212 days ago) by 2 authors, stale".
+pub(crate) fn format_history(history: &GitHistory) -> String {
+    let authors = match history.authors {
+        0 => return format!("not committed yet, {}", history.freshness.label()),
+        1 => "1 author".to_string(),
+        n => format!("{} authors", n),
+    };
+    format!(
+        "last changed {} ({} days ago) by {}, {}",
+        format_date(history.last_modified),
+        history.age_days,
+        authors,
+        history.freshness.label()
+    )
+}
+
+/// Format the applied confidence rules, e.g. "exported -10, file uses eval -30".
+fn format_factors(dead: &DeadSymbol) -> String {
+    dead.confidence_factors
+        .iter()
+        .map(|f| f.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn format_confidence(confidence: Confidence, score: u8) -> ColoredString {
     let label = format!("{} ({})", confidence.label(), score);
     match confidence {
-        Confidence::High => label.green().to_string(),
-        Confidence::Medium => label.yellow().to_string(),
-        Confidence::Low => label.red().to_string(),
+        Confidence::High => label.green(),
+        Confidence::Medium => label.yellow(),
+        Confidence::Low => label.red(),
     }
 }
@@ -0,0 +1,248 @@
+//! CI annotation output: GitHub Actions workflow commands and GitLab Code
+//! Quality reports, so dead code shows inline in pull and merge requests.
+
+use super::{format_kind, normalize_path, relative_to, OutputWriter};
+use crate::core::{AnalysisResult, Confidence, DddError, DeadSymbol, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+#[derive(Serialize)]
+struct CodeQualityIssue {
+    description: String,
+    check_name: &'static str,
+    fingerprint: String,
+    severity: &'static str,
+    location: CodeQualityLocation,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLocation {
+    path: String,
+    lines: CodeQualityLines,
+}
+
+#[derive(Serialize)]
+struct CodeQualityLines {
+    begin: u32,
+    end: u32,
+}
+
+impl OutputWriter {
+    /// Write dead symbols as GitHub Actions `::warning` / `::notice` commands.
+    pub(super) fn write_github(&mut self, dead_symbols: &[&DeadSymbol]) -> Result<()> {
+        for dead in dead_symbols {
+            self.emit(self.github_annotation(dead))?;
+        }
+        Ok(())
+    }
+
+    fn github_annotation(&self, dead: &DeadSymbol) -> String {
+        let location = &dead.symbol.location;
+        let command = match dead.confidence {
+            Confidence::High => "warning",
+            Confidence::Medium | Confidence::Low => "notice",
+        };
+        let title = format!(
+            "Dead {} `{}`",
+            format_kind(dead.symbol.kind),
+            dead.symbol.name
+        );
+
+        format!(
+            "::{} file={},line={},endLine={},col={},title={}::{} ({} confidence, {})",
+            command,
+            escape_property(&self.workspace_path(&location.file_path)),
+            location.line,
+            location.end_line.max(location.line),
+            location.column,
+            escape_property(&title),
+            escape_data(&dead.reason.description()),
+            dead.confidence.label(),
+            dead.confidence_score
+        )
+    }
+
+    /// Write dead symbols as a GitLab Code Quality report.
+    pub(super) fn write_gitlab(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        let issues = self.code_quality_issues(&result.dead_symbols, dead_symbols);
+        let json = serde_json::to_string_pretty(&issues)
+            .map_err(|e| DddError::analysis_error(format!("JSON serialization failed: {}", e)))?;
+        self.emit(json)?;
+        Ok(())
+    }
+
+    fn code_quality_issues(
+        &self,
+        all: &[DeadSymbol],
+        dead_symbols: &[&DeadSymbol],
+    ) -> Vec<CodeQualityIssue> {
+        // Symbols sharing a path, name and kind are told apart by their order
+        // in the file among all dead symbols, so sorting or filtering the
+        // report doesn't change a fingerprint
+        let mut offsets: HashMap<String, Vec<u32>> = HashMap::new();
+        for dead in all {
+            offsets
+                .entry(self.fingerprint_key(dead))
+                .or_default()
+                .push(dead.symbol.location.start_offset);
+        }
+        for starts in offsets.values_mut() {
+            starts.sort_unstable();
+        }
+
+        dead_symbols
+            .iter()
+            .map(|dead| {
+                let location = &dead.symbol.location;
+                let mut key = self.fingerprint_key(dead);
+                let occurrence = offsets
+                    .get(&key)
+                    .and_then(|starts| starts.binary_search(&location.start_offset).ok())
+                    .unwrap_or_default();
+                if occurrence > 0 {
+                    key.push_str(&format!("\0{}", occurrence));
+                }
+
+                CodeQualityIssue {
+                    description: format!(
+                        "`{}` ({}) is dead: {}",
+                        dead.symbol.name,
+                        format_kind(dead.symbol.kind),
+                        dead.reason.description()
+                    ),
+                    check_name: dead.reason.kind(),
+                    fingerprint: format!("{:016x}", fnv1a(key.as_bytes())),
+                    severity: match dead.confidence {
+                        Confidence::High => "minor",
+                        Confidence::Medium | Confidence::Low => "info",
+                    },
+                    location: CodeQualityLocation {
+                        path: self.workspace_path(&location.file_path),
+                        lines: CodeQualityLines {
+                            begin: location.line,
+                            end: location.end_line.max(location.line),
+                        },
+                    },
+                }
+            })
+            .collect()
+    }
+
+    /// Key identifying a dead symbol by its path, name and kind.
+    fn fingerprint_key(&self, dead: &DeadSymbol) -> String {
+        format!(
+            "{}\0{}\0{}",
+            self.relative_path(&dead.symbol.location.file_path),
+            dead.symbol.name,
+            dead.symbol.kind.as_str()
+        )
+    }
+
+    /// Get a file's path as CI tools expect it: relative to the working
+    /// directory, which is the checkout in CI, or else to the project root.
+    pub(super) fn workspace_path(&self, path: &Path) -> String {
+        let cwd = std::env::current_dir().map(|dir| normalize_path(&dir));
+        match cwd {
+            Ok(cwd) if normalize_path(path).starts_with(&cwd) => relative_to(Some(&cwd), path),
+            _ => self.relative_path(path),
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash, stable across platforms and releases.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+    })
+}
+
+/// Escape a workflow command message.
+fn escape_data(value: &str) -> String {
+    value
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a workflow command property value.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{dead_symbol, reported, result, writer};
+    use crate::config::OutputFormat;
+
+    #[test]
+    fn test_fnv1a() {
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+
+    #[test]
+    fn test_github_annotation() {
+        let writer = writer(OutputFormat::Github).with_root(Path::new("/repo/."));
+        let dead = dead_symbol(0, "format,Date", 3)
+            .file("/repo/./src/a.ts")
+            .column(8)
+            .build();
+        assert_eq!(
+            writer.github_annotation(&dead),
+            "::warning file=src/a.ts,line=3,endLine=3,col=8,title=Dead fn `format%2CDate`::exported but never imported (high confidence, 90)"
+        );
+        assert!(writer
+            .github_annotation(&dead_symbol(0, "helper", 3).score(60).build())
+            .starts_with("::notice "));
+    }
+
+    #[test]
+    fn test_fingerprints_are_stable() {
+        let helpers = |first: u32, second: u32| {
+            result(vec![
+                dead_symbol(0, "helper", first)
+                    .offsets(first * 10, first * 10 + 5)
+                    .build(),
+                dead_symbol(1, "helper", second)
+                    .offsets(second * 10, second * 10 + 5)
+                    .score(60)
+                    .build(),
+            ])
+        };
+        let fingerprints = |all: &[DeadSymbol], dead: &[&DeadSymbol]| -> Vec<String> {
+            writer(OutputFormat::Gitlab)
+                .code_quality_issues(all, dead)
+                .into_iter()
+                .map(|issue| issue.fingerprint)
+                .collect()
+        };
+
+        let before = helpers(3, 9);
+        let before = fingerprints(&before.dead_symbols, &reported(&before));
+        assert_ne!(before[0], before[1]);
+        // The key names the kind explicitly, not through its Debug output
+        let key = "src/a.ts\0helper\0function";
+        assert_eq!(before[0], format!("{:016x}", fnv1a(key.as_bytes())));
+
+        // Lines shift
+        let after = helpers(13, 19);
+        assert_eq!(before, fingerprints(&after.dead_symbols, &reported(&after)));
+
+        // The report is reordered, or filtered by confidence
+        let reversed: Vec<_> = reported(&after).into_iter().rev().collect();
+        let fingerprint = fingerprints(&after.dead_symbols, &reversed);
+        assert_eq!(fingerprint, vec![before[1].clone(), before[0].clone()]);
+        let filtered = fingerprints(&after.dead_symbols, &reversed[..1]);
+        assert_eq!(filtered, vec![before[1].clone()]);
+    }
+}
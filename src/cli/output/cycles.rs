@@ -0,0 +1,131 @@
+//! Import cycle report for `ddd cycles`.
+
+use super::OutputWriter;
+use crate::analysis::cycles::ImportCycle;
+use crate::config::OutputFormat;
+use crate::core::{CallGraph, DddError, FileId, ImportEdge, Result};
+use colored::Colorize;
+use serde::Serialize;
+use tabled::{
+    settings::{object::Rows, Alignment, Modify, Style},
+    Table, Tabled,
+};
+
+#[derive(Tabled)]
+struct ImportRow {
+    #[tabled(rename = "File")]
+    file: String,
+    #[tabled(rename = "Line")]
+    line: String,
+    #[tabled(rename = "Imports")]
+    imports: String,
+}
+
+#[derive(Serialize)]
+struct JsonCycle {
+    files: Vec<String>,
+    imports: Vec<JsonImport>,
+}
+
+#[derive(Serialize)]
+struct JsonImport {
+    from: String,
+    to: String,
+    line: u32,
+    column: u32,
+}
+
+impl OutputWriter {
+    /// Write import cycles with the imports that form them.
+    pub fn write_cycles(&mut self, call_graph: &CallGraph, cycles: &[ImportCycle]) -> Result<()> {
+        match self.format {
+            OutputFormat::Table => self.write_cycles_table(call_graph, cycles),
+            OutputFormat::Json => {
+                let output: Vec<JsonCycle> = cycles
+                    .iter()
+                    .map(|cycle| JsonCycle {
+                        files: cycle
+                            .files
+                            .iter()
+                            .map(|&id| self.file_path(call_graph, id))
+                            .collect(),
+                        imports: cycle
+                            .edges
+                            .iter()
+                            .map(|edge| JsonImport {
+                                from: self.file_path(call_graph, edge.from),
+                                to: self.file_path(call_graph, edge.to),
+                                line: edge.location.line,
+                                column: edge.location.column,
+                            })
+                            .collect(),
+                    })
+                    .collect();
+                let json = serde_json::to_string_pretty(&output).map_err(|e| {
+                    DddError::analysis_error(format!("JSON serialization failed: {}", e))
+                })?;
+                self.emit(json)
+            }
+            OutputFormat::Compact => {
+                for (n, cycle) in cycles.iter().enumerate() {
+                    for edge in &cycle.edges {
+                        self.emit(format_args!(
+                            "{} - imports {} (cycle {})",
+                            self.import_site(call_graph, edge),
+                            self.file_path(call_graph, edge.to),
+                            n + 1
+                        ))?;
+                    }
+                }
+                Ok(())
+            }
+            format => Err(DddError::unsupported_format(format.name(), "cycles")),
+        }
+    }
+
+    fn write_cycles_table(&mut self, call_graph: &CallGraph, cycles: &[ImportCycle]) -> Result<()> {
+        if cycles.is_empty() {
+            self.emit(self.paint("No import cycles found!".green().bold()))?;
+            return Ok(());
+        }
+
+        for (n, cycle) in cycles.iter().enumerate() {
+            let heading = format!("Import cycle {} ({} files)", n + 1, cycle.files.len());
+            self.emit(format_args!("\n{}", self.paint(heading.yellow().bold())))?;
+
+            let rows: Vec<ImportRow> = cycle
+                .edges
+                .iter()
+                .map(|edge| ImportRow {
+                    file: self.file_path(call_graph, edge.from),
+                    line: edge.location.line.to_string(),
+                    imports: self.file_path(call_graph, edge.to),
+                })
+                .collect();
+            let table = Table::new(&rows)
+                .with(Style::rounded())
+                .with(Modify::new(Rows::first()).with(Alignment::center()))
+                .to_string();
+            self.emit(table)?;
+        }
+
+        Ok(())
+    }
+
+    fn file_path(&self, call_graph: &CallGraph, id: FileId) -> String {
+        match call_graph.files.get(&id) {
+            Some(file) => self.relative_path(&file.path),
+            None => format!("<file #{}>", id.0),
+        }
+    }
+
+    /// Format where an import is made, as "path:line:column".
+    fn import_site(&self, call_graph: &CallGraph, edge: &ImportEdge) -> String {
+        format!(
+            "{}:{}:{}",
+            self.file_path(call_graph, edge.from),
+            edge.location.line,
+            edge.location.column
+        )
+    }
+}
@@ -0,0 +1,128 @@
+//! Shared fixtures for output format tests.
+
+use super::OutputWriter;
+use crate::config::OutputFormat;
+use crate::core::{
+    AnalysisResult, DeadSymbol, DeadnessReason, FileId, Location, SymbolId, SymbolKind,
+    TrackedSymbol,
+};
+use std::path::{Path, PathBuf};
+
+/// Project root the fixture files live under.
+pub const ROOT: &str = "/repo";
+
+/// Default file for fixture symbols.
+pub const FILE: &str = "/repo/src/a.ts";
+
+/// Builder for a dead symbol, by default an unused exported function in
+/// [`FILE`] with a score of 90.
+pub struct DeadSymbolBuilder {
+    id: u32,
+    name: String,
+    kind: SymbolKind,
+    location: Location,
+    score: u8,
+    reason: DeadnessReason,
+}
+
+/// Start building dead symbol `id` named `name`, declared at `line`.
+pub fn dead_symbol(id: u32, name: &str, line: u32) -> DeadSymbolBuilder {
+    DeadSymbolBuilder {
+        id,
+        name: name.to_string(),
+        kind: SymbolKind::Function,
+        location: Location::new(PathBuf::from(FILE), 0, 10, line, 1),
+        score: 90,
+        reason: DeadnessReason::UnusedExport,
+    }
+}
+
+impl DeadSymbolBuilder {
+    pub fn file(mut self, file: impl AsRef<Path>) -> Self {
+        self.location.file_path = file.as_ref().to_path_buf();
+        self
+    }
+
+    pub fn column(mut self, column: u32) -> Self {
+        self.location.column = column;
+        self
+    }
+
+    pub fn end_line(mut self, end_line: u32) -> Self {
+        self.location.end_line = end_line;
+        self
+    }
+
+    pub fn offsets(mut self, start: u32, end: u32) -> Self {
+        self.location.start_offset = start;
+        self.location.end_offset = end;
+        self
+    }
+
+    pub fn kind(mut self, kind: SymbolKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn score(mut self, score: u8) -> Self {
+        self.score = score;
+        self
+    }
+
+    pub fn reason(mut self, reason: DeadnessReason) -> Self {
+        self.reason = reason;
+        self
+    }
+
+    pub fn build(self) -> DeadSymbol {
+        DeadSymbol::new(self.symbol(), self.score, self.reason)
+    }
+
+    /// Build only the tracked symbol, e.g. for an entry point.
+    pub fn symbol(&self) -> TrackedSymbol {
+        TrackedSymbol::new(
+            SymbolId::new(self.id),
+            self.name.clone(),
+            self.kind,
+            self.location.clone(),
+            FileId::new(0),
+        )
+    }
+}
+
+/// Reason for a symbol only used by the dead symbols in `chain`.
+pub fn transitive(chain: &[u32]) -> DeadnessReason {
+    DeadnessReason::Transitive {
+        chain: chain.iter().copied().map(SymbolId::new).collect(),
+    }
+}
+
+/// Reason for a symbol nothing references.
+pub fn unreachable() -> DeadnessReason {
+    DeadnessReason::Unreachable {
+        explanation: "never called".to_string(),
+    }
+}
+
+/// Analysis result of one file holding `dead_symbols` and nothing else.
+pub fn result(dead_symbols: Vec<DeadSymbol>) -> AnalysisResult {
+    AnalysisResult {
+        total_symbols: dead_symbols.len(),
+        dead_symbols,
+        entry_points: Vec::new(),
+        never_executed: Vec::new(),
+        total_files: 1,
+        warnings: Vec::new(),
+        duration_ms: 5,
+    }
+}
+
+/// Writer for `format` with paths relative to [`ROOT`].
+pub fn writer(format: OutputFormat) -> OutputWriter {
+    OutputWriter::new(format, false).with_root(Path::new(ROOT))
+}
+
+/// Report every dead symbol of `result`, in order.
+pub fn reported(result: &AnalysisResult) -> Vec<&DeadSymbol> {
+    result.dead_symbols.iter().collect()
+}
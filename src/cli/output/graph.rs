@@ -0,0 +1,454 @@
+//! Call graph export as Graphviz DOT, Mermaid and JSON.
+//!
+//! Nodes are grouped by file. Entry points are highlighted and dead symbols
+//! are colored by confidence; dynamic references are drawn dashed.
+
+use super::{format_kind, OutputWriter};
+use crate::analysis::subgraph::Subgraph;
+use crate::cli::GraphFormat;
+use crate::core::{
+    CallGraph, Confidence, DddError, DeadSymbol, ReferenceKind, Result, SymbolId, SymbolKind,
+    TrackedSymbol,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// Version of the JSON graph format, bumped on incompatible changes.
+const GRAPH_JSON_VERSION: u32 = 1;
+
+/// Whether a node is an entry point, reported dead, or neither.
+#[derive(Clone, Copy)]
+enum NodeStatus<'a> {
+    Entry,
+    Dead(&'a DeadSymbol),
+    Alive,
+}
+
+impl NodeStatus<'_> {
+    fn name(&self) -> &'static str {
+        match self {
+            NodeStatus::Entry => "entry",
+            NodeStatus::Dead(_) => "dead",
+            NodeStatus::Alive => "alive",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonGraph {
+    version: u32,
+    nodes: Vec<JsonNode>,
+    edges: Vec<JsonEdge>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsonNode {
+    id: u32,
+    name: String,
+    kind: SymbolKind,
+    file: String,
+    line: u32,
+    column: u32,
+    exported: bool,
+    status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    confidence_score: Option<u8>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    entry_sources: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct JsonEdge {
+    from: u32,
+    to: u32,
+    kind: ReferenceKind,
+    dynamic: bool,
+    file: String,
+    line: u32,
+    column: u32,
+}
+
+impl OutputWriter {
+    /// Write the selected part of the call graph.
+    pub fn write_graph(
+        &mut self,
+        call_graph: &CallGraph,
+        dead_symbols: &[DeadSymbol],
+        subgraph: &Subgraph,
+        format: GraphFormat,
+    ) -> Result<()> {
+        let dead: HashMap<SymbolId, &DeadSymbol> =
+            dead_symbols.iter().map(|d| (d.symbol.id, d)).collect();
+        let status = |id| node_status(call_graph, &dead, id);
+
+        let output = match format {
+            GraphFormat::Dot => self.graph_dot(call_graph, subgraph, status),
+            GraphFormat::Mermaid => self.graph_mermaid(call_graph, subgraph, status),
+            GraphFormat::Json => {
+                let graph = self.graph_json(call_graph, subgraph, status);
+                serde_json::to_string_pretty(&graph).map_err(|e| {
+                    DddError::analysis_error(format!("JSON serialization failed: {}", e))
+                })?
+            }
+        };
+        self.emit(output)
+    }
+
+    fn graph_dot<'a>(
+        &self,
+        call_graph: &CallGraph,
+        subgraph: &Subgraph,
+        status: impl Fn(SymbolId) -> NodeStatus<'a>,
+    ) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph ddd {{");
+        let _ = writeln!(dot, "  rankdir=LR;");
+        let _ = writeln!(
+            dot,
+            r#"  node [shape=box, style="rounded,filled", fillcolor="white", fontname="Helvetica"];"#
+        );
+        let _ = writeln!(dot, r#"  edge [fontname="Helvetica", fontsize=10];"#);
+
+        for (i, (file, symbols)) in self.nodes_by_file(call_graph, subgraph).iter().enumerate() {
+            let _ = writeln!(dot, r#"  subgraph "cluster_{}" {{"#, i);
+            let _ = writeln!(dot, r#"    label="{}";"#, escape_dot(file));
+            for symbol in symbols {
+                let mut attributes = vec![format!(
+                    r#"label="{} ({})\nline {}""#,
+                    escape_dot(&symbol.name),
+                    escape_dot(&format_kind(symbol.kind)),
+                    symbol.location.line
+                )];
+                match status(symbol.id) {
+                    NodeStatus::Entry => {
+                        attributes.push(r##"fillcolor="#d1f0d6", penwidth=2"##.to_string());
+                        let sources: Vec<_> = call_graph
+                            .get_entry_point_sources(symbol.id)
+                            .iter()
+                            .map(|s| s.description())
+                            .collect();
+                        if !sources.is_empty() {
+                            attributes.push(format!(
+                                r#"tooltip="entry point: {}""#,
+                                escape_dot(&sources.join("; "))
+                            ));
+                        }
+                    }
+                    NodeStatus::Dead(dead) => attributes.push(format!(
+                        r#"fillcolor="{}", tooltip="dead: {} ({} confidence, {})""#,
+                        dead_color(dead.confidence).0,
+                        escape_dot(&dead.reason.description()),
+                        dead.confidence.label(),
+                        dead.confidence_score
+                    )),
+                    NodeStatus::Alive => {}
+                }
+                let _ = writeln!(dot, "    n{} [{}];", symbol.id.0, attributes.join(", "));
+            }
+            let _ = writeln!(dot, "  }}");
+        }
+
+        for edge in &subgraph.edges {
+            let style = if edge.is_dynamic {
+                ", style=dashed"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                r#"  n{} -> n{} [label="{}"{}];"#,
+                edge.from_id.0,
+                edge.to_id.0,
+                edge.kind.label(),
+                style
+            );
+        }
+
+        dot.push('}');
+        dot
+    }
+
+    fn graph_mermaid<'a>(
+        &self,
+        call_graph: &CallGraph,
+        subgraph: &Subgraph,
+        status: impl Fn(SymbolId) -> NodeStatus<'a>,
+    ) -> String {
+        let mut mermaid = String::new();
+        let _ = writeln!(mermaid, "flowchart LR");
+
+        let mut classes: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+        for (i, (file, symbols)) in self.nodes_by_file(call_graph, subgraph).iter().enumerate() {
+            let _ = writeln!(mermaid, r#"  subgraph f{}["{}"]"#, i, escape_mermaid(file));
+            for symbol in symbols {
+                let _ = writeln!(
+                    mermaid,
+                    r#"    n{}["{} ({}) :{}"]"#,
+                    symbol.id.0,
+                    escape_mermaid(&symbol.name),
+                    escape_mermaid(&format_kind(symbol.kind)),
+                    symbol.location.line
+                );
+                let class = match status(symbol.id) {
+                    NodeStatus::Entry => "entry",
+                    NodeStatus::Dead(dead) => dead_color(dead.confidence).1,
+                    NodeStatus::Alive => continue,
+                };
+                classes
+                    .entry(class)
+                    .or_default()
+                    .push(format!("n{}", symbol.id.0));
+            }
+            let _ = writeln!(mermaid, "  end");
+        }
+
+        for edge in &subgraph.edges {
+            let arrow = if edge.is_dynamic { "-.->" } else { "-->" };
+            let _ = writeln!(
+                mermaid,
+                "  n{} {}|{}| n{}",
+                edge.from_id.0,
+                arrow,
+                edge.kind.label(),
+                edge.to_id.0
+            );
+        }
+
+        let _ = writeln!(
+            mermaid,
+            "  classDef entry fill:#d1f0d6,stroke:#1a7f37,stroke-width:2px"
+        );
+        for confidence in [Confidence::High, Confidence::Medium, Confidence::Low] {
+            let (fill, class) = dead_color(confidence);
+            let _ = writeln!(mermaid, "  classDef {} fill:{}", class, fill);
+        }
+        for (class, nodes) in classes {
+            let _ = writeln!(mermaid, "  class {} {}", nodes.join(","), class);
+        }
+
+        mermaid.trim_end().to_string()
+    }
+
+    fn graph_json<'a>(
+        &self,
+        call_graph: &CallGraph,
+        subgraph: &Subgraph,
+        status: impl Fn(SymbolId) -> NodeStatus<'a>,
+    ) -> JsonGraph {
+        let nodes = subgraph
+            .nodes
+            .iter()
+            .filter_map(|id| call_graph.symbols.get(id))
+            .map(|symbol| {
+                let status = status(symbol.id);
+                let dead = match status {
+                    NodeStatus::Dead(dead) => Some(dead),
+                    _ => None,
+                };
+                JsonNode {
+                    id: symbol.id.0,
+                    name: symbol.name.clone(),
+                    kind: symbol.kind,
+                    file: self.relative_path(&symbol.location.file_path),
+                    line: symbol.location.line,
+                    column: symbol.location.column,
+                    exported: symbol.exported,
+                    status: status.name(),
+                    confidence: dead.map(|d| d.confidence.label()),
+                    confidence_score: dead.map(|d| d.confidence_score),
+                    entry_sources: call_graph
+                        .get_entry_point_sources(symbol.id)
+                        .iter()
+                        .map(|s| s.description())
+                        .collect(),
+                }
+            })
+            .collect();
+
+        let edges = subgraph
+            .edges
+            .iter()
+            .map(|edge| JsonEdge {
+                from: edge.from_id.0,
+                to: edge.to_id.0,
+                kind: edge.kind,
+                dynamic: edge.is_dynamic,
+                file: self.relative_path(&edge.location.file_path),
+                line: edge.location.line,
+                column: edge.location.column,
+            })
+            .collect();
+
+        JsonGraph {
+            version: GRAPH_JSON_VERSION,
+            nodes,
+            edges,
+        }
+    }
+
+    /// Group the selected symbols by root-relative path, then by line.
+    fn nodes_by_file<'a>(
+        &self,
+        call_graph: &'a CallGraph,
+        subgraph: &Subgraph,
+    ) -> BTreeMap<String, Vec<&'a TrackedSymbol>> {
+        let mut by_file: BTreeMap<String, Vec<&TrackedSymbol>> = BTreeMap::new();
+        for symbol in subgraph
+            .nodes
+            .iter()
+            .filter_map(|id| call_graph.symbols.get(id))
+        {
+            by_file
+                .entry(self.relative_path(&symbol.location.file_path))
+                .or_default()
+                .push(symbol);
+        }
+        for symbols in by_file.values_mut() {
+            symbols.sort_by_key(|s| (s.location.line, s.location.column, s.id.0));
+        }
+        by_file
+    }
+}
+
+fn node_status<'a>(
+    call_graph: &CallGraph,
+    dead: &HashMap<SymbolId, &'a DeadSymbol>,
+    id: SymbolId,
+) -> NodeStatus<'a> {
+    if call_graph.entry_points.contains(&id) {
+        NodeStatus::Entry
+    } else if let Some(dead) = dead.get(&id) {
+        NodeStatus::Dead(dead)
+    } else {
+        NodeStatus::Alive
+    }
+}
+
+/// Fill color and Mermaid class for a dead symbol.
+fn dead_color(confidence: Confidence) -> (&'static str, &'static str) {
+    match confidence {
+        Confidence::High => ("#ffb3b3", "deadHigh"),
+        Confidence::Medium => ("#ffe08a", "deadMedium"),
+        Confidence::Low => ("#e6e6e6", "deadLow"),
+    }
+}
+
+/// Escape text for a quoted DOT string.
+fn escape_dot(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Escape text for a quoted Mermaid label using its entity codes.
+fn escape_mermaid(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+        .replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::subgraph::{select_subgraph, GraphFilter};
+    use crate::cli::output::fixtures::{self, dead_symbol};
+    use crate::config::OutputFormat;
+    use crate::core::{EntryPointSource, Location, SymbolReference};
+    use std::path::{Path, PathBuf};
+
+    /// main -> helper -(dynamic)-> unused<T>, with unused reported dead.
+    fn fixture() -> (CallGraph, Vec<DeadSymbol>) {
+        let mut graph = CallGraph::new();
+        for (id, name, line) in [(0, "main", 1), (1, "helper", 5), (2, "unused<T>", 9)] {
+            graph.add_symbol(dead_symbol(id, name, line).symbol());
+        }
+        graph.mark_entry_point(
+            SymbolId::new(0),
+            EntryPointSource::ConfigFile {
+                path: PathBuf::from("src/a.ts"),
+            },
+        );
+        graph.add_reference(SymbolReference::new(
+            SymbolId::new(0),
+            SymbolId::new(1),
+            ReferenceKind::Call,
+            Location::new(PathBuf::from(fixtures::FILE), 0, 1, 2, 3),
+        ));
+        let mut dynamic = SymbolReference::new(
+            SymbolId::new(1),
+            SymbolId::new(2),
+            ReferenceKind::PropertyAccess,
+            Location::new(PathBuf::from(fixtures::FILE), 0, 1, 6, 3),
+        );
+        dynamic.is_dynamic = true;
+        graph.add_reference(dynamic);
+
+        let dead = dead_symbol(2, "unused<T>", 9).score(60).build();
+        (graph, vec![dead])
+    }
+
+    fn render(format: GraphFormat) -> String {
+        let (graph, dead) = fixture();
+        let subgraph =
+            select_subgraph(&graph, Path::new(fixtures::ROOT), &GraphFilter::default()).unwrap();
+        let dead: HashMap<SymbolId, &DeadSymbol> = dead.iter().map(|d| (d.symbol.id, d)).collect();
+        let status = |id| node_status(&graph, &dead, id);
+        let writer = fixtures::writer(OutputFormat::Json);
+        match format {
+            GraphFormat::Dot => writer.graph_dot(&graph, &subgraph, status),
+            GraphFormat::Mermaid => writer.graph_mermaid(&graph, &subgraph, status),
+            GraphFormat::Json => {
+                serde_json::to_string(&writer.graph_json(&graph, &subgraph, status)).unwrap()
+            }
+        }
+    }
+
+    #[test]
+    fn test_dot() {
+        let dot = render(GraphFormat::Dot);
+        assert!(dot.starts_with("digraph ddd {"));
+        assert!(dot.contains(r#"label="src/a.ts";"#));
+        assert!(dot.contains(
+            r##"n0 [label="main (fn)\nline 1", fillcolor="#d1f0d6", penwidth=2, tooltip="entry point: config entry file src/a.ts"];"##
+        ));
+        assert!(dot.contains(r##"n2 [label="unused<T> (fn)\nline 9", fillcolor="#ffe08a""##));
+        assert!(dot.contains(r#"n0 -> n1 [label="call"];"#));
+        assert!(dot.contains(r#"n1 -> n2 [label="property access", style=dashed];"#));
+        assert!(dot.ends_with('}'));
+    }
+
+    #[test]
+    fn test_mermaid() {
+        let mermaid = render(GraphFormat::Mermaid);
+        assert!(mermaid.starts_with("flowchart LR"));
+        assert!(mermaid.contains(r#"n2["unused#lt;T#gt; (fn) :9"]"#));
+        assert!(mermaid.contains("n0 -->|call| n1"));
+        assert!(mermaid.contains("n1 -.->|property access| n2"));
+        assert!(mermaid.contains("class n0 entry"));
+        assert!(mermaid.contains("class n2 deadMedium"));
+    }
+
+    #[test]
+    fn test_json() {
+        let json: serde_json::Value = serde_json::from_str(&render(GraphFormat::Json)).unwrap();
+        assert_eq!(json["version"], 1);
+        assert_eq!(json["nodes"][0]["status"], "entry");
+        assert_eq!(
+            json["nodes"][0]["entrySources"][0],
+            "config entry file src/a.ts"
+        );
+        assert_eq!(json["nodes"][1]["status"], "alive");
+        assert!(json["nodes"][1].get("confidence").is_none());
+        assert_eq!(json["nodes"][2]["confidence"], "medium");
+        assert_eq!(json["nodes"][2]["file"], "src/a.ts");
+        assert_eq!(json["edges"][1]["kind"], "propertyAccess");
+        assert_eq!(json["edges"][1]["dynamic"], true);
+    }
+}
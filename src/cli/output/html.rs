@@ -0,0 +1,428 @@
+//! Self-contained HTML report.
+//!
+//! Everything, including styles and the filter script, is inlined so the
+//! report can be archived as a single CI artifact and opened offline.
+
+use super::xml::escape;
+use super::{format_kind, OutputWriter};
+use crate::analysis::history::format_date;
+use crate::core::{AnalysisResult, DeadSymbol, DeadnessReason, Result, SymbolId};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Lines of context shown before a dead symbol.
+const CONTEXT_BEFORE: u32 = 2;
+
+/// Most lines of a dead symbol's body shown before it is cut off.
+const MAX_SNIPPET_LINES: u32 = 12;
+
+const STYLE: &str = r#"
+body { font: 14px/1.5 system-ui, sans-serif; margin: 0 auto; max-width: 1100px; padding: 1.5rem; color: #1f2328; }
+h1 { font-size: 1.6rem; margin-bottom: 0.2rem; }
+h2 { margin-top: 2rem; border-bottom: 1px solid #d0d7de; }
+.meta { color: #656d76; }
+.summary { display: flex; gap: 1rem; margin: 1rem 0; }
+.card { border: 1px solid #d0d7de; border-radius: 6px; padding: 0.6rem 1rem; min-width: 7rem; }
+.card strong { display: block; font-size: 1.5rem; }
+.filters { display: flex; gap: 1.5rem; align-items: center; padding: 0.6rem 0; position: sticky; top: 0; background: #fff; }
+details.dir > summary { font-weight: 600; cursor: pointer; }
+details.file { margin: 0.4rem 0 0.4rem 1.2rem; }
+details.file > summary { font-family: ui-monospace, monospace; cursor: pointer; }
+.finding { border-left: 4px solid #d0d7de; margin: 0.6rem 0 0.6rem 1.2rem; padding: 0.2rem 0.8rem; }
+.finding.high { border-color: #cf222e; }
+.finding.medium { border-color: #bf8700; }
+.finding.low { border-color: #8c959f; }
+.badge { border-radius: 1rem; padding: 0 0.5rem; font-size: 0.8rem; background: #eaeef2; }
+pre { background: #f6f8fa; padding: 0.5rem; overflow-x: auto; margin: 0.4rem 0; }
+pre .dead { background: #ffebe9; display: block; }
+pre .ln { color: #8c959f; user-select: none; }
+table { border-collapse: collapse; width: 100%; }
+th, td { text-align: left; padding: 0.3rem 0.6rem; border-bottom: 1px solid #d0d7de; vertical-align: top; }
+code { font-family: ui-monospace, monospace; }
+.hidden { display: none; }
+"#;
+
+const SCRIPT: &str = r#"
+function applyFilters() {
+  const levels = new Set([...document.querySelectorAll('input[name=confidence]:checked')].map(i => i.value));
+  const kind = document.getElementById('kind').value;
+  document.querySelectorAll('.finding').forEach(f => {
+    const show = levels.has(f.dataset.confidence) && (kind === '' || f.dataset.kind === kind);
+    f.classList.toggle('hidden', !show);
+  });
+  document.querySelectorAll('details.file, details.dir').forEach(d => {
+    d.classList.toggle('hidden', !d.querySelector('.finding:not(.hidden)'));
+  });
+}
+document.querySelectorAll('.filters input, .filters select').forEach(i => i.addEventListener('change', applyFilters));
+"#;
+
+impl OutputWriter {
+    /// Write analysis results as a self-contained HTML page.
+    pub(super) fn write_html(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        self.emit(self.html_report(result, dead_symbols))
+    }
+
+    fn html_report(&self, result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> String {
+        let dead_by_id: HashMap<SymbolId, &DeadSymbol> = result
+            .dead_symbols
+            .iter()
+            .map(|d| (d.symbol.id, d))
+            .collect();
+        let mut sources = SourceCache::default();
+
+        let mut html = String::new();
+        let _ = writeln!(html, "<!DOCTYPE html>");
+        let _ = writeln!(html, r#"<html lang="en">"#);
+        let _ = writeln!(html, "<head>");
+        let _ = writeln!(html, r#"<meta charset="utf-8">"#);
+        let _ = writeln!(html, "<title>ddd dead code report</title>");
+        let _ = writeln!(html, "<style>{}</style>", STYLE);
+        let _ = writeln!(html, "</head>");
+        let _ = writeln!(html, "<body>");
+
+        self.html_summary(&mut html, result, dead_symbols);
+        self.html_filters(&mut html, dead_symbols);
+
+        let _ = writeln!(html, "<h2>Dead code</h2>");
+        if dead_symbols.is_empty() {
+            let _ = writeln!(html, "<p>No dead code found!</p>");
+        }
+        for (dir, files) in self.file_tree(dead_symbols) {
+            let _ = writeln!(
+                html,
+                r#"<details class="dir" open><summary>{}</summary>"#,
+                escape(if dir.is_empty() { "." } else { &dir })
+            );
+            for (file, symbols) in files {
+                let _ = writeln!(
+                    html,
+                    r#"<details class="file" open><summary>{} <span class="badge">{}</span></summary>"#,
+                    escape(&file),
+                    symbols.len()
+                );
+                for dead in symbols {
+                    self.html_finding(&mut html, dead, &dead_by_id, &mut sources);
+                }
+                let _ = writeln!(html, "</details>");
+            }
+            let _ = writeln!(html, "</details>");
+        }
+
+        self.html_entry_points(&mut html, result);
+
+        let _ = writeln!(html, "<script>{}</script>", SCRIPT);
+        let _ = writeln!(html, "</body>");
+        html.push_str("</html>");
+        html
+    }
+
+    fn html_summary(
+        &self,
+        html: &mut String,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        let count = |label: &str| {
+            dead_symbols
+                .iter()
+                .filter(|d| d.confidence.label() == label)
+                .count()
+        };
+
+        let _ = writeln!(html, "<h1>Dead code report</h1>");
+        let _ = writeln!(
+            html,
+            r#"<p class="meta">{} symbols in {} files analyzed in {} ms &middot; generated {}</p>"#,
+            result.total_symbols,
+            result.total_files,
+            result.duration_ms,
+            format_date(now)
+        );
+        let _ = writeln!(html, r#"<div class="summary">"#);
+        for (label, value) in [
+            ("dead symbols", dead_symbols.len()),
+            ("high", count("high")),
+            ("medium", count("medium")),
+            ("low", count("low")),
+            ("entry points", result.entry_points.len()),
+        ] {
+            let _ = writeln!(
+                html,
+                r#"<div class="card"><strong>{}</strong>{}</div>"#,
+                value, label
+            );
+        }
+        let _ = writeln!(html, "</div>");
+
+        if !result.warnings.is_empty() {
+            let _ = writeln!(
+                html,
+                "<details><summary>Warnings ({})</summary><ul>",
+                result.warnings.len()
+            );
+            for warning in &result.warnings {
+                let _ = writeln!(html, "<li>{}</li>", escape(&warning.message));
+            }
+            let _ = writeln!(html, "</ul></details>");
+        }
+    }
+
+    fn html_filters(&self, html: &mut String, dead_symbols: &[&DeadSymbol]) {
+        let kinds: BTreeSet<String> = dead_symbols
+            .iter()
+            .map(|d| format_kind(d.symbol.kind))
+            .collect();
+
+        let _ = writeln!(html, r#"<div class="filters">"#);
+        let _ = writeln!(html, "<span>Confidence:</span>");
+        for level in ["high", "medium", "low"] {
+            let _ = writeln!(
+                html,
+                r#"<label><input type="checkbox" name="confidence" value="{0}" checked> {0}</label>"#,
+                level
+            );
+        }
+        let _ = writeln!(
+            html,
+            r#"<label>Kind: <select id="kind"><option value="">all</option>"#
+        );
+        for kind in kinds {
+            let kind = escape(&kind);
+            let _ = writeln!(html, r#"<option value="{0}">{0}</option>"#, kind);
+        }
+        let _ = writeln!(html, "</select></label>");
+        let _ = writeln!(html, "</div>");
+    }
+
+    fn html_finding(
+        &self,
+        html: &mut String,
+        dead: &DeadSymbol,
+        dead_by_id: &HashMap<SymbolId, &DeadSymbol>,
+        sources: &mut SourceCache,
+    ) {
+        let location = &dead.symbol.location;
+        let kind = format_kind(dead.symbol.kind);
+        let level = dead.confidence.label();
+
+        let _ = writeln!(
+            html,
+            r#"<div class="finding {0}" data-confidence="{0}" data-kind="{1}">"#,
+            level,
+            escape(&kind)
+        );
+        let _ = writeln!(
+            html,
+            r#"<p><code>{}</code> <span class="badge">{}</span> line {} &middot; {} confidence ({}) &middot; {}</p>"#,
+            escape(&dead.symbol.name),
+            escape(&kind),
+            location.line,
+            level,
+            dead.confidence_score,
+            escape(&dead.reason.description())
+        );
+
+        if let Some(lines) = sources.lines(&location.file_path) {
+            write_snippet(html, lines, location.line, location.end_line);
+        }
+
+        if !dead.confidence_factors.is_empty() {
+            let _ = writeln!(html, "<details><summary>Confidence breakdown</summary><ul>");
+            for factor in &dead.confidence_factors {
+                let _ = writeln!(
+                    html,
+                    "<li>{:+} {} <code>{}</code></li>",
+                    factor.delta,
+                    escape(&factor.description),
+                    escape(&factor.rule)
+                );
+            }
+            let _ = writeln!(html, "</ul></details>");
+        }
+
+        if let DeadnessReason::Transitive { chain } = &dead.reason {
+            let callers: Vec<_> = chain.iter().filter_map(|id| dead_by_id.get(id)).collect();
+            if !callers.is_empty() {
+                let _ = writeln!(
+                    html,
+                    "<details><summary>Kill chain ({})</summary><ol>",
+                    callers.len()
+                );
+                for caller in callers {
+                    let _ = writeln!(
+                        html,
+                        "<li>used by dead <code>{}</code> ({}) at {}:{}</li>",
+                        escape(&caller.symbol.name),
+                        escape(&format_kind(caller.symbol.kind)),
+                        escape(&self.relative_path(&caller.symbol.location.file_path)),
+                        caller.symbol.location.line
+                    );
+                }
+                let _ = writeln!(html, "</ol></details>");
+            }
+        }
+
+        let _ = writeln!(html, "</div>");
+    }
+
+    fn html_entry_points(&self, html: &mut String, result: &AnalysisResult) {
+        let _ = writeln!(
+            html,
+            "<h2>Entry points</h2><details><summary>{} entry points and the rules that marked them</summary>",
+            result.entry_points.len()
+        );
+        let _ = writeln!(
+            html,
+            "<table><tr><th>Symbol</th><th>Location</th><th>Source</th></tr>"
+        );
+        for entry in &result.entry_points {
+            let sources: Vec<_> = entry
+                .sources
+                .iter()
+                .map(|s| escape(&s.description()))
+                .collect();
+            let _ = writeln!(
+                html,
+                "<tr><td><code>{}</code> ({})</td><td>{}:{}</td><td>{}</td></tr>",
+                escape(&entry.symbol.name),
+                escape(&format_kind(entry.symbol.kind)),
+                escape(&self.relative_path(&entry.symbol.location.file_path)),
+                entry.symbol.location.line,
+                sources.join("<br>")
+            );
+        }
+        let _ = writeln!(html, "</table></details>");
+    }
+
+    /// Group dead symbols by directory and file, sorted by path and line.
+    fn file_tree<'a>(
+        &self,
+        dead_symbols: &[&'a DeadSymbol],
+    ) -> BTreeMap<String, BTreeMap<String, Vec<&'a DeadSymbol>>> {
+        let mut tree: BTreeMap<String, BTreeMap<String, Vec<&DeadSymbol>>> = BTreeMap::new();
+        for &dead in dead_symbols {
+            let file = self.relative_path(&dead.symbol.location.file_path);
+            let (dir, name) = file.rsplit_once('/').unwrap_or(("", &file));
+            tree.entry(dir.to_string())
+                .or_default()
+                .entry(name.to_string())
+                .or_default()
+                .push(dead);
+        }
+        for files in tree.values_mut() {
+            for symbols in files.values_mut() {
+                symbols.sort_by_key(|d| (d.symbol.location.line, d.symbol.location.column));
+            }
+        }
+        tree
+    }
+}
+
+/// Source files read for snippets, kept for the rest of the report.
+#[derive(Default)]
+struct SourceCache(HashMap<std::path::PathBuf, Option<Vec<String>>>);
+
+impl SourceCache {
+    fn lines(&mut self, path: &Path) -> Option<&[String]> {
+        self.0
+            .entry(path.to_path_buf())
+            .or_insert_with(|| {
+                std::fs::read_to_string(path)
+                    .ok()
+                    .map(|source| source.lines().map(str::to_string).collect())
+            })
+            .as_deref()
+    }
+}
+
+/// Write the lines around a symbol, highlighting the symbol's own lines.
+fn write_snippet(html: &mut String, lines: &[String], start_line: u32, end_line: u32) {
+    let end_line = end_line.max(start_line);
+    let first = start_line.saturating_sub(CONTEXT_BEFORE).max(1);
+    let last = end_line.min(start_line + MAX_SNIPPET_LINES - 1);
+
+    let _ = write!(html, "<pre>");
+    for number in first..=last {
+        let Some(line) = lines.get(number as usize - 1) else {
+            break;
+        };
+        // Highlighted lines are blocks, so only the others need a newline
+        let class = if number >= start_line {
+            " class=\"dead\""
+        } else {
+            ""
+        };
+        let _ = write!(
+            html,
+            r#"<span{}><span class="ln">{:>4}</span>  {}</span>"#,
+            class,
+            number,
+            escape(line)
+        );
+        if class.is_empty() {
+            html.push('\n');
+        }
+    }
+    if last < end_line {
+        let _ = write!(html, "<span class=\"ln\">     …</span>");
+    }
+    let _ = writeln!(html, "</pre>");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{dead_symbol, reported, result, transitive, unreachable};
+    use crate::config::OutputFormat;
+
+    #[test]
+    fn test_html_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let file = dir.path().join("src").join("a.ts");
+        std::fs::create_dir_all(file.parent().unwrap()).unwrap();
+        std::fs::write(
+            &file,
+            "// header\n\nfunction root() {\n  helper();\n}\nfunction helper<T>() {\n}\n",
+        )
+        .unwrap();
+
+        let result = result(vec![
+            dead_symbol(0, "root", 3)
+                .file(&file)
+                .end_line(4)
+                .reason(unreachable())
+                .build(),
+            dead_symbol(1, "helper", 6)
+                .file(&file)
+                .end_line(7)
+                .reason(transitive(&[0]))
+                .build(),
+        ]);
+        let html = OutputWriter::new(OutputFormat::Html, false)
+            .with_root(dir.path())
+            .html_report(&result, &reported(&result));
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.ends_with("</html>"));
+        // Self-contained: no external assets
+        assert!(!html.contains("src=\"http"));
+        assert!(!html.contains("href=\"http"));
+
+        assert!(html.contains(r#"<details class="dir" open><summary>src</summary>"#));
+        assert!(html.contains(r#"data-confidence="high" data-kind="fn""#));
+        assert!(html.contains(r#"<span class="ln">   6</span>  function helper&lt;T&gt;() {"#));
+        assert!(html.contains("<details><summary>Kill chain (1)</summary>"));
+        assert!(html.contains("used by dead <code>root</code> (fn) at src/a.ts:3"));
+        assert_eq!(html.matches(r#"<div class="finding "#).count(), 2);
+    }
+}
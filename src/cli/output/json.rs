@@ -0,0 +1,670 @@
+//! Versioned JSON output for `ddd analyze --format json`, and its JSON Schema.
+//!
+//! The layout is a contract with downstream tools. Adding fields keeps
+//! `SCHEMA_VERSION`; renaming, removing or retyping one bumps it. The schema
+//! is derived from these types by [`json_schema`], with their doc comments as
+//! descriptions, and published as `schema/analyze-output.v1.json`; the tests
+//! fail if the published copy is out of date.
+
+use super::OutputWriter;
+use crate::analysis::deprecated::DeprecatedSymbol;
+use crate::analysis::history::format_date;
+use crate::core::{
+    AnalysisResult, AnalysisWarning, Confidence, DddError, DeadSymbol, DeadnessReason, EntryPoint,
+    EntryPointSource, Execution, Freshness, Location, Result, SymbolId, SymbolKind, TrackedSymbol,
+    WarningKind,
+};
+use schemars::generate::SchemaSettings;
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// Version of the JSON output layout.
+pub(super) const SCHEMA_VERSION: u32 = 1;
+
+/// Output of `ddd analyze --format json`.
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(
+    title = "ddd analyze JSON output",
+    description = "Output of `ddd analyze --format json`. Paths are relative to `root` and use `/` separators. Fields may be added without a version change."
+)]
+struct JsonOutput {
+    /// Version of this layout
+    #[schemars(extend("const" = SCHEMA_VERSION))]
+    schema_version: u32,
+    /// Version of ddd that wrote the output
+    tool_version: &'static str,
+    /// Absolute path of the analyzed directory
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "String")]
+    root: Option<String>,
+    summary: JsonSummary,
+    /// Reported dead symbols, biggest removal first
+    dead_symbols: Vec<JsonDeadSymbol>,
+    /// Analysis warnings
+    warnings: Vec<JsonWarning>,
+    /// Symbols the analysis started from
+    entry_points: Vec<JsonEntryPoint>,
+    /// Alive symbols a runtime trace never saw execute
+    never_executed: Vec<JsonSymbol>,
+}
+
+/// Totals for the run
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename = "summary")]
+pub(super) struct JsonSummary {
+    /// Symbols analyzed
+    total_symbols: usize,
+    /// Files analyzed
+    total_files: usize,
+    /// Reported dead symbols
+    dead_count: usize,
+    by_confidence: JsonConfidenceCounts,
+    /// Analysis time in milliseconds
+    duration_ms: u64,
+}
+
+/// Reported dead symbols by confidence level
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "confidenceCounts")]
+struct JsonConfidenceCounts {
+    /// High confidence
+    high: usize,
+    /// Medium confidence
+    medium: usize,
+    /// Low confidence
+    low: usize,
+}
+
+/// A symbol
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename = "symbol")]
+pub(super) struct JsonSymbol {
+    /// Symbol ID, unique within one run
+    id: u32,
+    /// Symbol name
+    name: String,
+    kind: SymbolKind,
+    location: JsonLocation,
+    /// Whether the symbol is exported
+    exported: bool,
+}
+
+/// A source range
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename = "location")]
+struct JsonLocation {
+    /// File path relative to root
+    file: String,
+    /// 1-based start line
+    line: u32,
+    /// 1-based start column
+    column: u32,
+    /// 1-based end line
+    end_line: u32,
+    /// Byte offset of the start
+    start_offset: u32,
+    /// Byte offset of the end, exclusive
+    end_offset: u32,
+}
+
+/// A symbol reported as dead
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename = "deadSymbol")]
+pub(super) struct JsonDeadSymbol {
+    #[serde(flatten)]
+    symbol: JsonSymbol,
+    confidence: Confidence,
+    /// Confidence score, 0-100
+    confidence_score: u8,
+    /// Score before confidence rules applied
+    base_confidence: u8,
+    /// Confidence rules applied to the base score, in order
+    confidence_factors: Vec<JsonConfidenceFactor>,
+    reason: JsonReason,
+    /// For transitively dead symbols, the dead symbol that caused it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "JsonSymbol")]
+    killed_by: Option<JsonSymbol>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "JsonImpact")]
+    impact: Option<JsonImpact>,
+    /// Times the symbol ran according to coverage data
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "u64")]
+    execution_count: Option<u64>,
+    /// Whether the symbol ran in production according to a runtime trace
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Execution")]
+    trace: Option<Execution>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "JsonHistory")]
+    history: Option<JsonHistory>,
+}
+
+/// Why a symbol is dead
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "reason")]
+struct JsonReason {
+    kind: JsonReasonKind,
+    /// Human-readable reason
+    description: String,
+    /// For transitive reasons, the dead symbols that were its only users
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "Vec<JsonSymbol>")]
+    chain: Option<Vec<JsonSymbol>>,
+}
+
+/// Reason identifier, as `DeadnessReason::kind` names it
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "reasonKind")]
+enum JsonReasonKind {
+    Unreachable,
+    Transitive,
+    UnusedExport,
+    UnusedType,
+}
+
+impl From<&DeadnessReason> for JsonReasonKind {
+    fn from(reason: &DeadnessReason) -> Self {
+        match reason {
+            DeadnessReason::Unreachable { .. } => JsonReasonKind::Unreachable,
+            DeadnessReason::Transitive { .. } => JsonReasonKind::Transitive,
+            DeadnessReason::UnusedExport => JsonReasonKind::UnusedExport,
+            DeadnessReason::UnusedType => JsonReasonKind::UnusedType,
+        }
+    }
+}
+
+/// A confidence rule that adjusted the score
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "confidenceFactor")]
+struct JsonConfidenceFactor {
+    /// Rule identifier, e.g. exported or neverExecuted
+    rule: String,
+    /// What the rule matched
+    description: String,
+    /// Score adjustment
+    delta: i32,
+}
+
+/// Code freed by removing a root dead symbol
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "impact")]
+struct JsonImpact {
+    /// Lines freed, including the symbol itself
+    lines: u32,
+    /// Bytes freed, including the symbol itself
+    bytes: u32,
+    /// Dead symbols kept in place only by this one
+    dominated: Vec<JsonSymbol>,
+}
+
+/// When the symbol's lines last changed in git
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename = "history")]
+struct JsonHistory {
+    /// Date of the most recent change, YYYY-MM-DD
+    last_modified: String,
+    /// Whole days since the most recent change
+    age_days: u64,
+    /// Distinct authors of committed lines
+    authors: usize,
+    freshness: Freshness,
+}
+
+/// An analysis warning
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "warning")]
+pub(super) struct JsonWarning {
+    kind: WarningKind,
+    /// Human-readable message
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[schemars(with = "JsonLocation")]
+    location: Option<JsonLocation>,
+}
+
+/// A symbol the analysis treats as live
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "entryPoint")]
+pub(super) struct JsonEntryPoint {
+    #[serde(flatten)]
+    symbol: JsonSymbol,
+    /// Rules that marked the symbol as an entry point
+    sources: Vec<JsonEntryPointSource>,
+}
+
+/// A rule that marked an entry point
+#[derive(Serialize, JsonSchema)]
+#[schemars(rename = "entryPointSource")]
+struct JsonEntryPointSource {
+    kind: JsonEntryPointSourceKind,
+    /// Human-readable description
+    description: String,
+}
+
+/// Rule identifier, as `EntryPointSource::kind` names it
+#[derive(Serialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "entryPointSourceKind")]
+enum JsonEntryPointSourceKind {
+    ConfigFile,
+    ConfigPattern,
+    ConfigExport,
+    PackageJson,
+    Framework,
+    SideEffect,
+    RuntimeTrace,
+    #[serde(rename = "jsdoc")]
+    DocTag,
+}
+
+/// A `@deprecated` symbol, for `ddd deprecated --format json`
+#[derive(Serialize)]
+pub(super) struct JsonDeprecatedSymbol {
+    #[serde(flatten)]
+    symbol: JsonSymbol,
+    /// Text of the `@deprecated` tag
+    note: String,
+    /// Places that still reference the symbol
+    referrers: Vec<JsonReferrer>,
+}
+
+/// A reference to a deprecated symbol
+#[derive(Serialize)]
+struct JsonReferrer {
+    /// Name of the referencing symbol, when it is tracked
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    location: JsonLocation,
+}
+
+impl From<&EntryPointSource> for JsonEntryPointSourceKind {
+    fn from(source: &EntryPointSource) -> Self {
+        match source {
+            EntryPointSource::ConfigFile { .. } => JsonEntryPointSourceKind::ConfigFile,
+            EntryPointSource::ConfigPattern { .. } => JsonEntryPointSourceKind::ConfigPattern,
+            EntryPointSource::ConfigExport { .. } => JsonEntryPointSourceKind::ConfigExport,
+            EntryPointSource::PackageJson { .. } => JsonEntryPointSourceKind::PackageJson,
+            EntryPointSource::Framework { .. } => JsonEntryPointSourceKind::Framework,
+            EntryPointSource::SideEffect { .. } => JsonEntryPointSourceKind::SideEffect,
+            EntryPointSource::RuntimeTrace { .. } => JsonEntryPointSourceKind::RuntimeTrace,
+            EntryPointSource::DocTag { .. } => JsonEntryPointSourceKind::DocTag,
+        }
+    }
+}
+
+impl OutputWriter {
+    /// Write analysis results as one JSON document.
+    pub(super) fn write_json(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.json_output(result, dead_symbols))
+            .map_err(|e| DddError::analysis_error(format!("JSON serialization failed: {}", e)))?;
+        self.emit(json)
+    }
+
+    fn json_output(&self, result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> JsonOutput {
+        let symbols = dead_symbol_index(&result.dead_symbols);
+
+        JsonOutput {
+            schema_version: SCHEMA_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION"),
+            root: self.root.as_ref().map(|root| root.display().to_string()),
+            summary: json_summary(result, dead_symbols),
+            dead_symbols: dead_symbols
+                .iter()
+                .map(|dead| self.json_dead_symbol(dead, &symbols))
+                .collect(),
+            warnings: result
+                .warnings
+                .iter()
+                .map(|w| self.json_warning(w))
+                .collect(),
+            entry_points: result
+                .entry_points
+                .iter()
+                .map(|entry| self.json_entry_point(entry))
+                .collect(),
+            never_executed: result
+                .never_executed
+                .iter()
+                .map(|s| self.json_symbol(s))
+                .collect(),
+        }
+    }
+
+    /// Convert a dead symbol, resolving the symbols its reason and impact
+    /// refer to through `symbols`.
+    pub(super) fn json_dead_symbol(
+        &self,
+        dead: &DeadSymbol,
+        symbols: &HashMap<SymbolId, &TrackedSymbol>,
+    ) -> JsonDeadSymbol {
+        let resolve = |ids: &[SymbolId]| -> Vec<JsonSymbol> {
+            ids.iter()
+                .filter_map(|id| symbols.get(id))
+                .map(|s| self.json_symbol(s))
+                .collect()
+        };
+
+        JsonDeadSymbol {
+            symbol: self.json_symbol(&dead.symbol),
+            confidence: dead.confidence,
+            confidence_score: dead.confidence_score,
+            base_confidence: dead.base_confidence,
+            confidence_factors: dead
+                .confidence_factors
+                .iter()
+                .map(|f| JsonConfidenceFactor {
+                    rule: f.rule.clone(),
+                    description: f.description.clone(),
+                    delta: f.delta,
+                })
+                .collect(),
+            reason: JsonReason {
+                kind: JsonReasonKind::from(&dead.reason),
+                description: dead.reason.description(),
+                chain: match &dead.reason {
+                    DeadnessReason::Transitive { chain } => Some(resolve(chain)),
+                    _ => None,
+                },
+            },
+            killed_by: dead
+                .killed_by
+                .and_then(|id| symbols.get(&id))
+                .map(|s| self.json_symbol(s)),
+            impact: dead.impact.as_ref().map(|i| JsonImpact {
+                lines: i.lines,
+                bytes: i.bytes,
+                dominated: resolve(&i.dominated),
+            }),
+            execution_count: dead.execution.map(|e| e.hits()),
+            trace: dead.trace,
+            history: dead.history.as_ref().map(|h| JsonHistory {
+                last_modified: format_date(h.last_modified),
+                age_days: h.age_days,
+                authors: h.authors,
+                freshness: h.freshness,
+            }),
+        }
+    }
+
+    pub(super) fn json_symbol(&self, symbol: &TrackedSymbol) -> JsonSymbol {
+        JsonSymbol {
+            id: symbol.id.0,
+            name: symbol.name.clone(),
+            kind: symbol.kind,
+            location: self.json_location(&symbol.location),
+            exported: symbol.exported,
+        }
+    }
+
+    fn json_location(&self, location: &Location) -> JsonLocation {
+        JsonLocation {
+            file: self.relative_path(&location.file_path),
+            line: location.line,
+            column: location.column,
+            end_line: location.end_line.max(location.line),
+            start_offset: location.start_offset,
+            end_offset: location.end_offset,
+        }
+    }
+
+    pub(super) fn json_warning(&self, warning: &AnalysisWarning) -> JsonWarning {
+        JsonWarning {
+            kind: warning.kind,
+            message: warning.message.clone(),
+            location: warning.location.as_ref().map(|l| self.json_location(l)),
+        }
+    }
+
+    pub(super) fn json_entry_point(&self, entry: &EntryPoint) -> JsonEntryPoint {
+        JsonEntryPoint {
+            symbol: self.json_symbol(&entry.symbol),
+            sources: entry
+                .sources
+                .iter()
+                .map(|s| JsonEntryPointSource {
+                    kind: JsonEntryPointSourceKind::from(s),
+                    description: s.description(),
+                })
+                .collect(),
+        }
+    }
+
+    pub(super) fn json_deprecated_symbol(&self, dep: &DeprecatedSymbol) -> JsonDeprecatedSymbol {
+        JsonDeprecatedSymbol {
+            symbol: self.json_symbol(&dep.symbol),
+            note: dep.note.clone(),
+            referrers: dep
+                .referrers
+                .iter()
+                .map(|r| JsonReferrer {
+                    name: r.symbol.as_ref().map(|s| s.name.clone()),
+                    location: self.json_location(&r.location),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Index every dead symbol, including those below the reporting threshold,
+/// so chains and impact can name them.
+pub(super) fn dead_symbol_index(dead_symbols: &[DeadSymbol]) -> HashMap<SymbolId, &TrackedSymbol> {
+    dead_symbols
+        .iter()
+        .map(|d| (d.symbol.id, &d.symbol))
+        .collect()
+}
+
+pub(super) fn json_summary(result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> JsonSummary {
+    let count = |level: Confidence| {
+        dead_symbols
+            .iter()
+            .filter(|d| d.confidence == level)
+            .count()
+    };
+    JsonSummary {
+        total_symbols: result.total_symbols,
+        total_files: result.total_files,
+        dead_count: dead_symbols.len(),
+        by_confidence: JsonConfidenceCounts {
+            high: count(Confidence::High),
+            medium: count(Confidence::Medium),
+            low: count(Confidence::Low),
+        },
+        duration_ms: result.duration_ms,
+    }
+}
+
+/// Build the JSON Schema (draft 2020-12) for the JSON output.
+pub fn json_schema() -> Value {
+    let mut schema = SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+        .into_root_schema_for::<JsonOutput>();
+    schema.insert(
+        "$id".to_string(),
+        Value::from(
+            "https://raw.githubusercontent.com/dean0x/diedeadcode/main/schema/analyze-output.v1.json",
+        ),
+    );
+    schema.to_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{
+        self, dead_symbol, reported, transitive, unreachable, DeadSymbolBuilder,
+    };
+    use crate::config::OutputFormat;
+    use crate::core::{EntryPointSource, Freshness, GitHistory, RemovalImpact, WarningKind};
+    use std::path::{Path, PathBuf};
+
+    /// Forbid properties the schema doesn't declare, so undocumented fields
+    /// can't slip into the output.
+    fn strict(schema: &mut Value) {
+        match schema {
+            Value::Object(object) => {
+                if object.contains_key("properties") {
+                    object.insert("additionalProperties".to_string(), Value::Bool(false));
+                }
+                object.values_mut().for_each(strict);
+            }
+            Value::Array(items) => items.iter_mut().for_each(strict),
+            _ => {}
+        }
+    }
+
+    fn symbol(id: u32, name: &str, line: u32) -> DeadSymbolBuilder {
+        dead_symbol(id, name, line)
+            .file("/repo/./src/a.ts")
+            .offsets(line * 10, line * 10 + 5)
+            .end_line(line + 2)
+            .kind(SymbolKind::ArrowFunction)
+    }
+
+    fn result() -> AnalysisResult {
+        let mut root = symbol(0, "root", 1).reason(unreachable()).build();
+        root.impact = Some(RemovalImpact {
+            dominated: vec![SymbolId::new(1)],
+            lines: 6,
+            bytes: 40,
+        });
+        root.history = Some(GitHistory {
+            last_modified: 0,
+            age_days: 400,
+            authors: 1,
+            freshness: Freshness::Stale,
+        });
+        root.trace = Some(Execution::NeverExecuted);
+        let mut helper = symbol(1, "helper", 5)
+            .score(70)
+            .reason(transitive(&[0]))
+            .build();
+        helper.killed_by = Some(SymbolId::new(0));
+
+        let mut result = fixtures::result(vec![root, helper]);
+        result.entry_points = vec![EntryPoint {
+            symbol: symbol(2, "main", 9).symbol(),
+            sources: vec![EntryPointSource::DocTag {
+                tag: "public".to_string(),
+            }],
+        }];
+        result.total_symbols = 3;
+        result.warnings = vec![AnalysisWarning {
+            kind: WarningKind::CircularDependency,
+            message: "Import cycle".to_string(),
+            location: Some(Location::new(PathBuf::from(fixtures::FILE), 0, 20, 1, 1)),
+        }];
+        result
+    }
+
+    fn output() -> Value {
+        let result = result();
+        let writer = fixtures::writer(OutputFormat::Json).with_root(Path::new("/repo/."));
+        serde_json::to_value(writer.json_output(&result, &reported(&result))).unwrap()
+    }
+
+    #[test]
+    fn test_output_matches_schema() {
+        let mut schema = json_schema();
+        strict(&mut schema);
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        let output = output();
+        let errors: Vec<String> = validator
+            .iter_errors(&output)
+            .map(|e| format!("{} at {}", e, e.instance_path()))
+            .collect();
+        assert!(
+            errors.is_empty(),
+            "output violates the schema: {:#?}",
+            errors
+        );
+
+        assert_eq!(output["schemaVersion"], SCHEMA_VERSION);
+        assert_eq!(output["summary"]["byConfidence"]["medium"], 1);
+
+        let helper = &output["deadSymbols"][1];
+        assert_eq!(helper["id"], 1);
+        assert_eq!(helper["kind"], "arrowFunction");
+        assert_eq!(helper["location"]["file"], "src/a.ts");
+        assert_eq!(helper["location"]["endLine"], 7);
+        assert_eq!(helper["location"]["endOffset"], 55);
+        assert_eq!(helper["reason"]["kind"], "transitive");
+        assert_eq!(helper["reason"]["chain"][0]["name"], "root");
+        assert_eq!(helper["killedBy"]["location"]["line"], 1);
+
+        assert_eq!(output["deadSymbols"][0]["impact"]["dominated"][0]["id"], 1);
+        assert_eq!(output["deadSymbols"][0]["trace"]["status"], "neverExecuted");
+        assert!(helper.get("trace").is_none());
+        assert!(output["deadSymbols"][0]["reason"].get("chain").is_none());
+        assert_eq!(output["warnings"][0]["kind"], "circular-dependency");
+        assert_eq!(output["warnings"][0]["location"]["file"], "src/a.ts");
+        assert_eq!(output["entryPoints"][0]["sources"][0]["kind"], "jsdoc");
+    }
+
+    #[test]
+    fn test_published_schema_is_current() {
+        let published: Value =
+            serde_json::from_str(include_str!("../../../schema/analyze-output.v1.json")).unwrap();
+        assert!(
+            published == json_schema(),
+            "schema/analyze-output.v1.json is out of date; regenerate it with `ddd schema`"
+        );
+    }
+
+    #[test]
+    fn test_reason_kinds_match_core() {
+        let reasons = [
+            unreachable(),
+            transitive(&[]),
+            DeadnessReason::UnusedExport,
+            DeadnessReason::UnusedType,
+        ];
+        for reason in &reasons {
+            assert_eq!(
+                serde_json::to_value(JsonReasonKind::from(reason)).unwrap(),
+                reason.kind()
+            );
+        }
+    }
+
+    #[test]
+    fn test_deprecated_symbol_uses_output_layout() {
+        let symbol = dead_symbol(0, "oldApi", 3)
+            .file("/repo/./src/a.ts")
+            .symbol();
+        let caller = dead_symbol(1, "main", 9).file("/repo/src/main.ts").symbol();
+        let dep = DeprecatedSymbol {
+            symbol,
+            note: "use newApi".to_string(),
+            referrers: vec![crate::analysis::deprecated::Referrer {
+                location: caller.location.clone(),
+                symbol: Some(caller),
+            }],
+        };
+
+        let writer = fixtures::writer(OutputFormat::Json);
+        let json = serde_json::to_value(writer.json_deprecated_symbol(&dep)).unwrap();
+
+        assert_eq!(json["kind"], "function");
+        assert_eq!(json["location"]["file"], "src/a.ts");
+        assert_eq!(json["note"], "use newApi");
+        assert_eq!(json["referrers"][0]["name"], "main");
+        assert_eq!(json["referrers"][0]["location"]["file"], "src/main.ts");
+    }
+}
@@ -0,0 +1,326 @@
+//! Markdown summary sized for a pull request comment: counts, the biggest
+//! findings with file links, and the full list folded away in `<details>`.
+
+use super::sarif::encode_uri_path;
+use super::xml::escape;
+use super::{chain_names, format_impact, format_kind, OutputWriter};
+use crate::analysis::deadness::sort_by_impact;
+use crate::core::{AnalysisResult, Confidence, DeadSymbol, DeadnessReason, Result, SymbolId};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+impl OutputWriter {
+    /// Write analysis results as a Markdown summary.
+    pub(super) fn write_markdown(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        self.emit(self.markdown_summary(result, dead_symbols))
+    }
+
+    fn markdown_summary(&self, result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> String {
+        let dead_by_id: HashMap<SymbolId, &DeadSymbol> = result
+            .dead_symbols
+            .iter()
+            .map(|d| (d.symbol.id, d))
+            .collect();
+
+        let mut md = String::new();
+        let _ = writeln!(md, "## Dead code report\n");
+
+        if dead_symbols.is_empty() {
+            let _ = writeln!(
+                md,
+                "No dead code found in {} across {}.",
+                count(result.total_symbols, "symbol"),
+                count(result.total_files, "file")
+            );
+            return md.trim_end().to_string();
+        }
+
+        let files = self.group_by_file(dead_symbols);
+        let _ = writeln!(
+            md,
+            "**{}** in {} ({} analyzed across {}).\n",
+            count(dead_symbols.len(), "dead symbol"),
+            count(files.len(), "file"),
+            count(result.total_symbols, "symbol"),
+            count(result.total_files, "file")
+        );
+        if !result.warnings.is_empty() {
+            let _ = writeln!(
+                md,
+                "{}; run `ddd analyze --verbose` for details.\n",
+                count(result.warnings.len(), "analysis warning")
+            );
+        }
+
+        self.markdown_counts(&mut md, dead_symbols);
+        self.markdown_top(&mut md, dead_symbols);
+
+        if self.group_by_file {
+            for (file, symbols) in &files {
+                let _ = writeln!(
+                    md,
+                    "<details>\n<summary><code>{}</code> ({})</summary>\n",
+                    escape(file),
+                    symbols.len()
+                );
+                let _ = writeln!(md, "| Line | Symbol | Kind | Confidence | Reason |");
+                let _ = writeln!(md, "|---:|---|---|---|---|");
+                for dead in symbols {
+                    let _ = writeln!(
+                        md,
+                        "| {} | {} | {} | {} ({}) | {} |",
+                        self.markdown_link(
+                            file,
+                            dead.symbol.location.line,
+                            &dead.symbol.location.line.to_string()
+                        ),
+                        code(&dead.symbol.name),
+                        cell(&format_kind(dead.symbol.kind)),
+                        dead.confidence.label(),
+                        dead.confidence_score,
+                        self.markdown_reason(dead, &dead_by_id)
+                    );
+                }
+                let _ = writeln!(md, "\n</details>\n");
+            }
+        } else {
+            let _ = writeln!(
+                md,
+                "<details>\n<summary>All dead symbols ({})</summary>\n",
+                dead_symbols.len()
+            );
+            let _ = writeln!(md, "| Location | Symbol | Kind | Confidence | Reason |");
+            let _ = writeln!(md, "|---|---|---|---|---|");
+            for (file, symbols) in &files {
+                for dead in symbols {
+                    let line = dead.symbol.location.line;
+                    let _ = writeln!(
+                        md,
+                        "| {} | {} | {} | {} ({}) | {} |",
+                        self.markdown_link(file, line, &format!("{}:{}", file, line)),
+                        code(&dead.symbol.name),
+                        cell(&format_kind(dead.symbol.kind)),
+                        dead.confidence.label(),
+                        dead.confidence_score,
+                        self.markdown_reason(dead, &dead_by_id)
+                    );
+                }
+            }
+            let _ = writeln!(md, "\n</details>\n");
+        }
+
+        md.trim_end().to_string()
+    }
+
+    /// Count dead symbols by reason and confidence.
+    fn markdown_counts(&self, md: &mut String, dead_symbols: &[&DeadSymbol]) {
+        let column = |confidence: Confidence| match confidence {
+            Confidence::High => 0,
+            Confidence::Medium => 1,
+            Confidence::Low => 2,
+        };
+        // Rows follow `DeadnessReason::index`, named by the reasons found
+        let mut counts = [("", [0; 3]); DeadnessReason::COUNT];
+        let mut totals = [0; 3];
+        for dead in dead_symbols {
+            let row = &mut counts[dead.reason.index()];
+            row.0 = dead.reason.kind();
+            row.1[column(dead.confidence)] += 1;
+            totals[column(dead.confidence)] += 1;
+        }
+
+        let _ = writeln!(md, "| Reason | High | Medium | Low | Total |");
+        let _ = writeln!(md, "|---|---:|---:|---:|---:|");
+        for (reason, [high, medium, low]) in counts {
+            let total = high + medium + low;
+            if total > 0 {
+                let _ = writeln!(
+                    md,
+                    "| {} | {} | {} | {} | {} |",
+                    reason, high, medium, low, total
+                );
+            }
+        }
+        let _ = writeln!(
+            md,
+            "| **Total** | **{}** | **{}** | **{}** | **{}** |\n",
+            totals[0],
+            totals[1],
+            totals[2],
+            dead_symbols.len()
+        );
+    }
+
+    /// List the dead symbols whose removal frees the most code.
+    fn markdown_top(&self, md: &mut String, dead_symbols: &[&DeadSymbol]) {
+        if self.top == 0 {
+            return;
+        }
+
+        let mut ranked: Vec<&DeadSymbol> = dead_symbols.to_vec();
+        sort_by_impact(&mut ranked);
+        ranked.truncate(self.top);
+
+        let _ = writeln!(md, "### Top {} by removal impact\n", ranked.len());
+        let _ = writeln!(md, "| Symbol | Kind | Location | Confidence | Impact |");
+        let _ = writeln!(md, "|---|---|---|---|---|");
+        for dead in ranked {
+            let file = self.workspace_path(&dead.symbol.location.file_path);
+            let line = dead.symbol.location.line;
+            let _ = writeln!(
+                md,
+                "| {} | {} | {} | {} ({}) | {} |",
+                code(&dead.symbol.name),
+                cell(&format_kind(dead.symbol.kind)),
+                self.markdown_link(&file, line, &format!("{}:{}", file, line)),
+                dead.confidence.label(),
+                dead.confidence_score,
+                format_impact(dead)
+            );
+        }
+        let _ = writeln!(md);
+    }
+
+    /// Describe why a symbol is dead, with its kill chain cut to `max_chain_length`.
+    fn markdown_reason(
+        &self,
+        dead: &DeadSymbol,
+        dead_by_id: &HashMap<SymbolId, &DeadSymbol>,
+    ) -> String {
+        let description = cell(&dead.reason.description());
+        let DeadnessReason::Transitive { chain } = &dead.reason else {
+            return description;
+        };
+
+        let names = chain_names(chain, dead_by_id, self.max_chain_length, code);
+        if names.is_empty() {
+            return description;
+        }
+        format!("{}: {}", description, names.join(", "))
+    }
+
+    /// Link to a line of a file, under `--link-base` when given.
+    fn markdown_link(&self, file: &str, line: u32, text: &str) -> String {
+        let path = encode_uri_path(file);
+        let url = match &self.link_base {
+            Some(base) => format!("{}/{}#L{}", base.trim_end_matches('/'), path, line),
+            None => format!("{}#L{}", path, line),
+        };
+        format!(
+            "[{}]({})",
+            cell(text).replace('[', "\\[").replace(']', "\\]"),
+            url
+        )
+    }
+}
+
+/// Escape text for a table cell.
+fn cell(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace('<', "&lt;")
+        .replace(['\r', '\n'], " ")
+}
+
+/// Format a name as inline code inside a table cell.
+fn code(value: &str) -> String {
+    let value = value.replace('|', "\\|").replace(['\r', '\n'], " ");
+    if value.contains('`') {
+        format!("`` {} ``", value)
+    } else {
+        format!("`{}`", value)
+    }
+}
+
+/// Format a count with its noun, e.g. `1 file` or `2 files`.
+fn count(n: usize, noun: &str) -> String {
+    if n == 1 {
+        format!("1 {}", noun)
+    } else {
+        format!("{} {}s", n, noun)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{self, dead_symbol, reported, transitive, unreachable};
+    use crate::config::{OutputConfig, OutputFormat};
+    use crate::core::{AnalysisWarning, RemovalImpact, WarningKind};
+
+    fn result() -> AnalysisResult {
+        let mut root = dead_symbol(0, "root", 3).reason(unreachable()).build();
+        root.impact = Some(RemovalImpact {
+            dominated: vec![SymbolId::new(1)],
+            lines: 12,
+            bytes: 300,
+        });
+        let helper = dead_symbol(1, "a|b", 9)
+            .score(60)
+            .reason(transitive(&[0, 2]))
+            .build();
+        let other = dead_symbol(2, "other", 20).build();
+
+        let mut result = fixtures::result(vec![helper, root, other]);
+        result.total_symbols = 10;
+        result.total_files = 2;
+        result
+    }
+
+    fn writer(group_by_file: bool) -> OutputWriter {
+        let config = OutputConfig {
+            group_by_file,
+            max_chain_length: 1,
+            ..OutputConfig::default()
+        };
+        fixtures::writer(OutputFormat::Markdown)
+            .with_output_config(&config)
+            .with_top(2)
+    }
+
+    #[test]
+    fn test_markdown_summary() {
+        let mut result = result();
+        result.warnings.push(AnalysisWarning {
+            kind: WarningKind::ParseError,
+            message: "Unexpected token".to_string(),
+            location: None,
+        });
+        let md = writer(true)
+            .with_link_base(Some("https://example.com/blob/main/"))
+            .markdown_summary(&result, &reported(&result));
+
+        assert!(md.contains("**3 dead symbols** in 1 file (10 symbols analyzed across 2 files)"));
+        assert!(md.contains("1 analysis warning;"));
+        assert!(md.contains("| unreachable | 1 | 0 | 0 | 1 |"));
+        assert!(md.contains("| transitive | 0 | 1 | 0 | 1 |"));
+        assert!(md.contains("| **Total** | **2** | **1** | **0** | **3** |"));
+
+        // Top 2, biggest removal first
+        assert!(md.contains("### Top 2 by removal impact"));
+        assert!(md.contains(
+            "| `root` | fn | [src/a.ts:3](https://example.com/blob/main/src/a.ts#L3) | high (90) | 12 lines (+1 symbols) |"
+        ));
+
+        assert!(md.contains("<summary><code>src/a.ts</code> (3)</summary>"));
+        assert!(md.contains("| `a\\|b` |"));
+        // Chain cut to one caller
+        assert!(md.contains("transitively dead via 2 callers: `root`, … (+1 more)"));
+        assert_eq!(md.matches("<details>").count(), 1);
+    }
+
+    #[test]
+    fn test_markdown_flat_list() {
+        let result = result();
+        let md = writer(false).markdown_summary(&result, &reported(&result));
+
+        assert!(md.contains("<summary>All dead symbols (3)</summary>"));
+        assert!(md.contains("| [src/a.ts:20](src/a.ts#L20) | `other` |"));
+        assert!(!md.contains("<code>src/a.ts</code>"));
+    }
+}
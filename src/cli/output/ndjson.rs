@@ -0,0 +1,229 @@
+//! Newline-delimited JSON output for `ddd analyze --format ndjson`.
+//!
+//! Each line is one compact JSON object with a `type` field: a `warning` per
+//! warning, a `deadSymbol` per finding, and a closing `summary`. The records
+//! share their layout with the JSON output, so the same schema describes them.
+//!
+//! [`NdjsonStream`] writes records while the analysis runs and flushes every
+//! line, so consumers see warnings as each phase finishes and dead symbols as
+//! soon as scoring is done, without waiting for the rest of the run.
+
+use super::json::{
+    dead_symbol_index, json_summary, JsonDeadSymbol, JsonSummary, JsonWarning, SCHEMA_VERSION,
+};
+use super::OutputWriter;
+use crate::analysis::AnalysisSink;
+use crate::core::{AnalysisResult, AnalysisWarning, DddError, DeadSymbol, Result};
+use serde::Serialize;
+use std::io::Write;
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum NdjsonRecord<'a> {
+    DeadSymbol(&'a JsonDeadSymbol),
+    Warning(&'a JsonWarning),
+    Summary(&'a NdjsonSummary),
+}
+
+/// The closing record, so a consumer knows the output is complete.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct NdjsonSummary {
+    schema_version: u32,
+    tool_version: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    root: Option<String>,
+    #[serde(flatten)]
+    summary: JsonSummary,
+}
+
+/// Writes ndjson records for the findings of a running analysis.
+pub struct NdjsonStream<'w> {
+    writer: &'w mut OutputWriter,
+    report: Box<dyn Fn(&DeadSymbol) -> bool + 'w>,
+    error: Option<DddError>,
+}
+
+impl NdjsonStream<'_> {
+    /// Stop streaming, returning the first write error.
+    pub fn finish(self) -> Result<()> {
+        self.error.map_or(Ok(()), Err)
+    }
+}
+
+impl AnalysisSink for NdjsonStream<'_> {
+    fn dead_symbols(&mut self, dead_symbols: &[DeadSymbol]) {
+        if self.error.is_none() {
+            let reported: Vec<_> = dead_symbols.iter().filter(|d| (self.report)(d)).collect();
+            if let Err(e) = self
+                .writer
+                .write_ndjson_dead_symbols(dead_symbols, &reported)
+            {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    fn warning(&mut self, warning: &AnalysisWarning) {
+        if self.error.is_none() {
+            let record = NdjsonRecord::Warning(&self.writer.json_warning(warning));
+            if let Err(e) = self.writer.emit_record(&record) {
+                self.error = Some(e);
+            }
+        }
+    }
+}
+
+impl OutputWriter {
+    /// Stream records while the analysis runs, for the dead symbols `report`
+    /// accepts. Write the summary with [`OutputWriter::write_ndjson_summary`]
+    /// once the analysis is done.
+    pub fn ndjson_stream<'w>(
+        &'w mut self,
+        report: impl Fn(&DeadSymbol) -> bool + 'w,
+    ) -> NdjsonStream<'w> {
+        NdjsonStream {
+            writer: self,
+            report: Box::new(report),
+            error: None,
+        }
+    }
+
+    /// Write finished analysis results as one JSON object per line.
+    pub(super) fn write_ndjson(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        for warning in &result.warnings {
+            let record = NdjsonRecord::Warning(&self.json_warning(warning));
+            self.emit_record(&record)?;
+        }
+        self.write_ndjson_dead_symbols(&result.dead_symbols, dead_symbols)?;
+        self.write_ndjson_summary(result, dead_symbols)
+    }
+
+    /// Write a record for each of `dead_symbols`, resolving chains through
+    /// every dead symbol in `all`.
+    fn write_ndjson_dead_symbols(
+        &mut self,
+        all: &[DeadSymbol],
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        let symbols = dead_symbol_index(all);
+        for dead in dead_symbols {
+            let record = NdjsonRecord::DeadSymbol(&self.json_dead_symbol(dead, &symbols));
+            self.emit_record(&record)?;
+        }
+        Ok(())
+    }
+
+    /// Write the closing summary record for the reported `dead_symbols`.
+    pub fn write_ndjson_summary(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        let summary = NdjsonSummary {
+            schema_version: SCHEMA_VERSION,
+            tool_version: env!("CARGO_PKG_VERSION"),
+            root: self.root.as_ref().map(|root| root.display().to_string()),
+            summary: json_summary(result, dead_symbols),
+        };
+        self.emit_record(&NdjsonRecord::Summary(&summary))
+    }
+
+    /// Write one record and flush it, so readers see it straight away.
+    fn emit_record(&mut self, record: &NdjsonRecord<'_>) -> Result<()> {
+        let line = serde_json::to_string(record)
+            .map_err(|e| DddError::analysis_error(format!("JSON serialization failed: {}", e)))?;
+        self.emit(line)?;
+        self.out.flush().map_err(|e| self.write_error(e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{dead_symbol, reported, result, writer};
+    use crate::config::OutputFormat;
+    use crate::core::WarningKind;
+    use serde_json::Value;
+
+    #[test]
+    fn test_one_record_per_line() {
+        let mut result = result(vec![
+            dead_symbol(0, "a", 1).build(),
+            dead_symbol(1, "b", 2).build(),
+        ]);
+        result.warnings = vec![AnalysisWarning {
+            kind: WarningKind::ParseError,
+            message: "Unexpected token".to_string(),
+            location: None,
+        }];
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.ndjson");
+        let mut writer = writer(OutputFormat::Ndjson).with_output(&path).unwrap();
+        writer.write_ndjson(&result, &reported(&result)).unwrap();
+        writer.finish().unwrap();
+
+        let output = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        let types: Vec<&str> = records
+            .iter()
+            .map(|r| r["type"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            types,
+            vec!["warning", "deadSymbol", "deadSymbol", "summary"]
+        );
+
+        assert_eq!(records[0]["kind"], "parse-error");
+        assert_eq!(records[2]["name"], "b");
+        assert_eq!(records[2]["location"]["file"], "src/a.ts");
+        assert_eq!(records[3]["schemaVersion"], SCHEMA_VERSION);
+        assert_eq!(records[3]["deadCount"], 2);
+    }
+
+    #[test]
+    fn test_stream_flushes_each_record() {
+        let result = result(vec![
+            dead_symbol(0, "a", 1).score(90).build(),
+            dead_symbol(1, "b", 2).score(10).build(),
+        ]);
+        let warning = AnalysisWarning {
+            kind: WarningKind::ParseError,
+            message: "Unexpected token".to_string(),
+            location: None,
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("out.ndjson");
+        let lines = || std::fs::read_to_string(&path).unwrap().lines().count();
+        let mut writer = writer(OutputFormat::Ndjson).with_output(&path).unwrap();
+        let mut stream = writer.ndjson_stream(|d| d.symbol.name == "a");
+
+        stream.warning(&warning);
+        assert_eq!(lines(), 1);
+        stream.dead_symbols(&result.dead_symbols);
+        assert_eq!(lines(), 2);
+        stream.finish().unwrap();
+
+        let reported: Vec<_> = result.dead_symbols.iter().take(1).collect();
+        writer.write_ndjson_summary(&result, &reported).unwrap();
+        writer.finish().unwrap();
+
+        let output = std::fs::read_to_string(&path).unwrap();
+        let records: Vec<Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(records[1]["name"], "a");
+        assert_eq!(records[2]["type"], "summary");
+        assert_eq!(records[2]["deadCount"], 1);
+    }
+}
@@ -0,0 +1,546 @@
+//! SARIF 2.1.0 output for code scanning tools.
+//!
+//! Each `DeadnessReason` variant is a rule. Results carry physical locations
+//! with line, column and byte offsets, and transitively dead symbols list the
+//! dead code that kills them as related locations and a code flow.
+
+use super::{format_kind, OutputWriter};
+use crate::core::{
+    AnalysisResult, Confidence, DddError, DeadSymbol, DeadnessReason, Location, Result, SymbolId,
+    SymbolKind,
+};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// Base id that relative artifact URIs are resolved against.
+const SRCROOT: &str = "%SRCROOT%";
+
+/// Rules in `DeadnessReason::index` order: id, name, short description, full
+/// description.
+const RULES: [(&str, &str, &str, &str); DeadnessReason::COUNT] = [
+    (
+        "unreachable",
+        "UnreachableCode",
+        "Code is unreachable from any entry point",
+        "No reference path leads to this symbol from an entry point, so it never runs.",
+    ),
+    (
+        "transitive",
+        "TransitivelyDeadCode",
+        "Code is only used by other dead code",
+        "Every reference to this symbol comes from dead code; it becomes unused once that code is removed.",
+    ),
+    (
+        "unused-export",
+        "UnusedExport",
+        "Export is never imported",
+        "The symbol is exported but no analyzed module imports it.",
+    ),
+    (
+        "unused-type",
+        "UnusedType",
+        "Type is never referenced",
+        "The type or interface is not referenced by any analyzed code.",
+    ),
+];
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: Vec<Run>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Run {
+    tool: Tool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    original_uri_base_ids: Option<HashMap<&'static str, ArtifactLocation>>,
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct Tool {
+    driver: Driver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule {
+    id: &'static str,
+    name: &'static str,
+    short_description: Message,
+    full_description: Message,
+    default_configuration: RuleConfiguration,
+    properties: RuleProperties,
+}
+
+#[derive(Serialize)]
+struct RuleConfiguration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    tags: [&'static str; 2],
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: &'static str,
+    rule_index: usize,
+    level: &'static str,
+    rank: f64,
+    message: Message,
+    locations: Vec<ResultLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    related_locations: Vec<ResultLocation>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    code_flows: Vec<CodeFlow>,
+    properties: ResultProperties,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResultProperties {
+    confidence: &'static str,
+    confidence_score: u8,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ResultLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    id: Option<usize>,
+    physical_location: PhysicalLocation,
+    logical_locations: Vec<LogicalLocation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<Message>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation {
+    artifact_location: ArtifactLocation,
+    region: Region,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ArtifactLocation {
+    uri: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    uri_base_id: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: u32,
+    start_column: u32,
+    end_line: u32,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+#[derive(Serialize)]
+struct LogicalLocation {
+    name: String,
+    kind: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CodeFlow {
+    message: Message,
+    thread_flows: Vec<ThreadFlow>,
+}
+
+#[derive(Serialize)]
+struct ThreadFlow {
+    locations: Vec<ThreadFlowLocation>,
+}
+
+#[derive(Serialize)]
+struct ThreadFlowLocation {
+    location: ResultLocation,
+}
+
+impl OutputWriter {
+    /// Write dead symbols as a SARIF log to stdout.
+    pub(super) fn write_sarif(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        let log = self.sarif_log(result, dead_symbols);
+        let json = serde_json::to_string_pretty(&log)
+            .map_err(|e| DddError::analysis_error(format!("SARIF serialization failed: {}", e)))?;
+        self.emit(json)?;
+        Ok(())
+    }
+
+    fn sarif_log(&self, result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> SarifLog {
+        // Chains name symbols by id; all of them are dead themselves
+        let dead_by_id: HashMap<SymbolId, &DeadSymbol> = result
+            .dead_symbols
+            .iter()
+            .map(|d| (d.symbol.id, d))
+            .collect();
+
+        let rules = RULES
+            .iter()
+            .map(|&(id, name, short, full)| Rule {
+                id,
+                name,
+                short_description: text(short),
+                full_description: text(full),
+                default_configuration: RuleConfiguration { level: "warning" },
+                properties: RuleProperties {
+                    tags: ["maintainability", "dead-code"],
+                },
+            })
+            .collect();
+
+        let original_uri_base_ids = self.root.as_deref().map(|root| {
+            let path = path_to_uri(root);
+            let separator = if path.starts_with('/') { "" } else { "/" };
+            let uri = format!(
+                "file://{}{}/",
+                separator,
+                encode_uri_path(path.trim_end_matches('/'))
+            );
+            HashMap::from([(
+                SRCROOT,
+                ArtifactLocation {
+                    uri,
+                    uri_base_id: None,
+                },
+            )])
+        });
+
+        SarifLog {
+            schema: SCHEMA,
+            version: "2.1.0",
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "ddd",
+                        version: env!("CARGO_PKG_VERSION"),
+                        information_uri: env!("CARGO_PKG_REPOSITORY"),
+                        rules,
+                    },
+                },
+                original_uri_base_ids,
+                column_kind: "unicodeCodePoints",
+                results: dead_symbols
+                    .iter()
+                    .map(|d| self.sarif_result(d, &dead_by_id))
+                    .collect(),
+            }],
+        }
+    }
+
+    fn sarif_result(
+        &self,
+        dead: &DeadSymbol,
+        dead_by_id: &HashMap<SymbolId, &DeadSymbol>,
+    ) -> SarifResult {
+        let rule_id = dead.reason.kind();
+        let rule_index = dead.reason.index();
+
+        let chain: Vec<&DeadSymbol> = match &dead.reason {
+            DeadnessReason::Transitive { chain } => chain
+                .iter()
+                .filter_map(|id| dead_by_id.get(id).copied())
+                .collect(),
+            _ => Vec::new(),
+        };
+
+        let related_locations = chain
+            .iter()
+            .enumerate()
+            .map(|(i, caller)| ResultLocation {
+                id: Some(i + 1),
+                message: Some(text(&format!(
+                    "used by dead `{}` ({})",
+                    caller.symbol.name,
+                    format_kind(caller.symbol.kind)
+                ))),
+                ..self.result_location(
+                    &caller.symbol.name,
+                    caller.symbol.kind,
+                    &caller.symbol.location,
+                )
+            })
+            .collect();
+
+        // One flow from the dead code that kills the symbol to the symbol itself
+        let code_flows = if chain.is_empty() {
+            Vec::new()
+        } else {
+            let mut locations: Vec<_> = chain
+                .iter()
+                .map(|caller| ThreadFlowLocation {
+                    location: ResultLocation {
+                        message: Some(text(&format!("`{}` is dead", caller.symbol.name))),
+                        ..self.result_location(
+                            &caller.symbol.name,
+                            caller.symbol.kind,
+                            &caller.symbol.location,
+                        )
+                    },
+                })
+                .collect();
+            locations.push(ThreadFlowLocation {
+                location: ResultLocation {
+                    message: Some(text(&format!(
+                        "so `{}` is only used by dead code",
+                        dead.symbol.name
+                    ))),
+                    ..self.result_location(
+                        &dead.symbol.name,
+                        dead.symbol.kind,
+                        &dead.symbol.location,
+                    )
+                },
+            });
+            vec![CodeFlow {
+                message: text(&format!("Kill chain for `{}`", dead.symbol.name)),
+                thread_flows: vec![ThreadFlow { locations }],
+            }]
+        };
+
+        SarifResult {
+            rule_id,
+            rule_index,
+            level: match dead.confidence {
+                Confidence::High => "warning",
+                Confidence::Medium | Confidence::Low => "note",
+            },
+            rank: f64::from(dead.confidence_score),
+            message: text(&format!(
+                "`{}` ({}) is dead: {}",
+                dead.symbol.name,
+                format_kind(dead.symbol.kind),
+                dead.reason.description()
+            )),
+            locations: vec![self.result_location(
+                &dead.symbol.name,
+                dead.symbol.kind,
+                &dead.symbol.location,
+            )],
+            related_locations,
+            code_flows,
+            properties: ResultProperties {
+                confidence: dead.confidence.label(),
+                confidence_score: dead.confidence_score,
+            },
+        }
+    }
+
+    fn result_location(&self, name: &str, kind: SymbolKind, location: &Location) -> ResultLocation {
+        ResultLocation {
+            id: None,
+            physical_location: PhysicalLocation {
+                artifact_location: self.artifact_location(&location.file_path),
+                region: Region {
+                    start_line: location.line,
+                    start_column: location.column,
+                    end_line: location.end_line.max(location.line),
+                    byte_offset: location.start_offset,
+                    byte_length: location.end_offset.saturating_sub(location.start_offset),
+                },
+            },
+            logical_locations: vec![LogicalLocation {
+                name: name.to_string(),
+                kind: logical_kind(kind),
+            }],
+            message: None,
+        }
+    }
+
+    fn artifact_location(&self, path: &Path) -> ArtifactLocation {
+        match &self.root {
+            Some(_) => ArtifactLocation {
+                uri: encode_uri_path(&self.relative_path(path)),
+                uri_base_id: Some(SRCROOT),
+            },
+            None => ArtifactLocation {
+                uri: encode_uri_path(&path_to_uri(path)),
+                uri_base_id: None,
+            },
+        }
+    }
+}
+
+fn text(message: &str) -> Message {
+    Message {
+        text: message.to_string(),
+    }
+}
+
+/// Map a symbol kind to a SARIF logical location kind.
+fn logical_kind(kind: SymbolKind) -> &'static str {
+    match kind {
+        SymbolKind::Function | SymbolKind::ArrowFunction => "function",
+        SymbolKind::Method => "member",
+        SymbolKind::Class | SymbolKind::Type | SymbolKind::Interface | SymbolKind::Enum => "type",
+        SymbolKind::Variable | SymbolKind::Constant | SymbolKind::EnumMember => "variable",
+        SymbolKind::Namespace => "namespace",
+        SymbolKind::Module => "module",
+    }
+}
+
+/// Join a path's components with `/`, keeping a leading `/` for absolute paths.
+fn path_to_uri(path: &Path) -> String {
+    let normalized = super::normalize_path(path);
+    normalized.to_string_lossy().replace('\\', "/")
+}
+
+/// Percent-encode the characters that may not appear in a URI path.
+pub(super) fn encode_uri_path(path: &str) -> String {
+    let mut encoded = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~/!$&'()*+,;=:@".contains(&byte) {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{dead_symbol, reported, result, transitive, unreachable};
+    use crate::config::OutputFormat;
+    use serde_json::Value;
+
+    fn dead(id: u32, name: &str, score: u8, reason: DeadnessReason) -> DeadSymbol {
+        dead_symbol(id, name, id + 1)
+            .file("/repo/./src/my file.ts")
+            .column(3)
+            .end_line(id + 2)
+            .offsets(id * 20, id * 20 + 12)
+            .score(score)
+            .reason(reason)
+            .build()
+    }
+
+    fn sarif(result: &AnalysisResult) -> Value {
+        let writer = OutputWriter::new(OutputFormat::Sarif, false).with_root(Path::new("/repo/."));
+        serde_json::to_value(writer.sarif_log(result, &reported(result))).unwrap()
+    }
+
+    #[test]
+    fn test_sarif_log_structure() {
+        let result = result(vec![
+            dead(0, "root", 95, unreachable()),
+            dead(1, "helper", 60, transitive(&[0])),
+        ]);
+        let log = sarif(&result);
+
+        let schema: Value = serde_json::from_str(include_str!(
+            "../../../tests/schemas/sarif-2.1.0-subset.json"
+        ))
+        .unwrap();
+        let validator = jsonschema::validator_for(&schema).unwrap();
+        let errors: Vec<String> = validator
+            .iter_errors(&log)
+            .map(|e| format!("{} at {}", e, e.instance_path()))
+            .collect();
+        assert!(
+            errors.is_empty(),
+            "SARIF log violates the schema: {:#?}",
+            errors
+        );
+
+        assert_eq!(log["$schema"], SCHEMA);
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["name"], "ddd");
+        assert_eq!(run["originalUriBaseIds"][SRCROOT]["uri"], "file:///repo/");
+
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), RULES.len());
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        for result in results {
+            // ruleIndex must point at the rule named by ruleId
+            let index = result["ruleIndex"].as_u64().unwrap() as usize;
+            assert_eq!(rules[index]["id"], result["ruleId"]);
+
+            let location = &result["locations"][0]["physicalLocation"];
+            assert_eq!(location["artifactLocation"]["uri"], "src/my%20file.ts");
+            assert_eq!(location["artifactLocation"]["uriBaseId"], SRCROOT);
+            assert!(
+                location["region"]["endLine"].as_u64() >= location["region"]["startLine"].as_u64()
+            );
+        }
+
+        let root = &results[0];
+        assert_eq!(root["ruleId"], "unreachable");
+        assert_eq!(root["level"], "warning");
+        assert_eq!(
+            root["locations"][0]["physicalLocation"]["region"]["byteOffset"],
+            0
+        );
+        assert_eq!(
+            root["locations"][0]["physicalLocation"]["region"]["byteLength"],
+            12
+        );
+        assert!(root.get("relatedLocations").is_none());
+        assert!(root.get("codeFlows").is_none());
+
+        let transitive = &results[1];
+        assert_eq!(transitive["ruleId"], "transitive");
+        assert_eq!(transitive["level"], "note");
+        assert_eq!(transitive["rank"], 60.0);
+        assert_eq!(transitive["relatedLocations"][0]["id"], 1);
+        assert_eq!(
+            transitive["relatedLocations"][0]["logicalLocations"][0]["name"],
+            "root"
+        );
+        let flow = transitive["codeFlows"][0]["threadFlows"][0]["locations"]
+            .as_array()
+            .unwrap();
+        assert_eq!(flow.len(), 2);
+        assert_eq!(flow[1]["location"]["logicalLocations"][0]["name"], "helper");
+    }
+
+    #[test]
+    fn test_rules_follow_reason_index() {
+        let reasons = [
+            unreachable(),
+            transitive(&[]),
+            DeadnessReason::UnusedExport,
+            DeadnessReason::UnusedType,
+        ];
+        for reason in &reasons {
+            assert_eq!(RULES[reason.index()].0, reason.kind());
+        }
+    }
+}
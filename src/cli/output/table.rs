@@ -0,0 +1,295 @@
+//! The dead code table: grouped by file or flat, in a configurable order and
+//! with configurable columns.
+
+use super::{
+    chain_names, format_confidence, format_factors, format_history, format_impact, format_kind,
+    OutputWriter,
+};
+use crate::config::{SortOrder, TableColumn};
+use crate::core::{AnalysisResult, DeadSymbol, DeadnessReason, Result, SymbolId};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::path::Path;
+use tabled::{
+    builder::Builder,
+    settings::{object::Rows, Alignment, Modify, Style},
+};
+
+impl OutputWriter {
+    pub(super) fn write_table(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+        show_chains: bool,
+    ) -> Result<()> {
+        if dead_symbols.is_empty() {
+            self.emit(self.paint("No dead code found!".green().bold()))?;
+            return Ok(());
+        }
+
+        let dead_by_id: HashMap<SymbolId, &DeadSymbol> = result
+            .dead_symbols
+            .iter()
+            .map(|d| (d.symbol.id, d))
+            .collect();
+        let mut symbols = dead_symbols.to_vec();
+        sort_dead_symbols(&mut symbols, self.sort);
+
+        if !self.group_by_file {
+            let columns = self.columns.clone();
+            self.emit(self.render_table(&symbols, &columns))?;
+            return self.write_table_details(&symbols, &dead_by_id, show_chains);
+        }
+
+        // The heading names the file, so a file column would only repeat it
+        let mut columns: Vec<TableColumn> = self
+            .columns
+            .iter()
+            .copied()
+            .filter(|&c| c != TableColumn::File)
+            .collect();
+        if columns.is_empty() {
+            columns = self.columns.clone();
+        }
+
+        // Files appear in the order of their first symbol
+        let mut files: Vec<&Path> = Vec::new();
+        let mut by_file: HashMap<&Path, Vec<&DeadSymbol>> = HashMap::new();
+        for dead in &symbols {
+            let file = dead.symbol.location.file_path.as_path();
+            if !by_file.contains_key(file) {
+                files.push(file);
+            }
+            by_file.entry(file).or_default().push(dead);
+        }
+
+        for file in files {
+            let heading = self.paint(self.relative_path(file).cyan().bold());
+            self.emit(format_args!("\n{}", heading))?;
+            let symbols = &by_file[file];
+            self.emit(self.render_table(symbols, &columns))?;
+            self.write_table_details(symbols, &dead_by_id, show_chains)?;
+        }
+
+        Ok(())
+    }
+
+    fn render_table(&self, symbols: &[&DeadSymbol], columns: &[TableColumn]) -> String {
+        let mut builder = Builder::default();
+        builder.push_record(columns.iter().map(|c| c.title()));
+        for dead in symbols {
+            builder.push_record(columns.iter().map(|&c| self.table_cell(dead, c)));
+        }
+
+        builder
+            .build()
+            .with(Style::rounded())
+            .with(Modify::new(Rows::first()).with(Alignment::center()))
+            .to_string()
+    }
+
+    fn table_cell(&self, dead: &DeadSymbol, column: TableColumn) -> String {
+        let location = &dead.symbol.location;
+        match column {
+            TableColumn::File => self.relative_path(&location.file_path),
+            TableColumn::Line => location.line.to_string(),
+            TableColumn::Name => dead.symbol.name.clone(),
+            TableColumn::Kind => format_kind(dead.symbol.kind),
+            TableColumn::Confidence => self
+                .paint(format_confidence(dead.confidence, dead.confidence_score))
+                .to_string(),
+            TableColumn::Reason => dead.reason.description(),
+            TableColumn::Impact => format_impact(dead),
+        }
+    }
+
+    /// Show how each confidence score was reached, and kill chains if requested.
+    fn write_table_details(
+        &mut self,
+        symbols: &[&DeadSymbol],
+        dead_by_id: &HashMap<SymbolId, &DeadSymbol>,
+        show_chains: bool,
+    ) -> Result<()> {
+        if !self.verbose {
+            return Ok(());
+        }
+
+        for dead in symbols.iter().filter(|d| !d.confidence_factors.is_empty()) {
+            self.emit(format_args!(
+                "  {} {}: {}",
+                self.paint("└─".dimmed()),
+                dead.symbol.name,
+                format_factors(dead)
+            ))?;
+        }
+        for dead in symbols {
+            if let Some(history) = &dead.history {
+                self.emit(format_args!(
+                    "  {} {}: {}",
+                    self.paint("└─".dimmed()),
+                    dead.symbol.name,
+                    format_history(history)
+                ))?;
+            }
+        }
+
+        if show_chains {
+            for dead in symbols {
+                let DeadnessReason::Transitive { chain } = &dead.reason else {
+                    continue;
+                };
+                let names = chain_names(chain, dead_by_id, self.max_chain_length, |name| {
+                    name.to_string()
+                });
+                if !names.is_empty() {
+                    self.emit(format_args!(
+                        "  {} {} chain: {}",
+                        self.paint("└─".dimmed()),
+                        dead.symbol.name,
+                        names.join(" → ")
+                    ))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Sort dead symbols for listing, breaking ties by file and line.
+pub(super) fn sort_dead_symbols(symbols: &mut [&DeadSymbol], order: SortOrder) {
+    let impact = |dead: &DeadSymbol| dead.impact.as_ref().map_or(0, |i| i.lines);
+
+    symbols.sort_by(|a, b| {
+        let by_location = || {
+            let (a, b) = (&a.symbol.location, &b.symbol.location);
+            (&a.file_path, a.line).cmp(&(&b.file_path, b.line))
+        };
+        match order {
+            SortOrder::File => by_location(),
+            SortOrder::Confidence => b
+                .confidence_score
+                .cmp(&a.confidence_score)
+                .then_with(by_location),
+            SortOrder::Impact => impact(b).cmp(&impact(a)).then_with(by_location),
+            SortOrder::Kind => (a.symbol.kind as u8)
+                .cmp(&(b.symbol.kind as u8))
+                .then_with(by_location),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{dead_symbol, reported, result, writer};
+    use crate::config::{OutputConfig, OutputFormat};
+    use crate::core::{RemovalImpact, SymbolKind};
+
+    fn dead(id: u32, file: &str, line: u32, kind: SymbolKind, score: u8, lines: u32) -> DeadSymbol {
+        let mut dead = dead_symbol(id, &format!("s{}", id), line)
+            .file(file)
+            .kind(kind)
+            .score(score)
+            .build();
+        dead.impact = Some(RemovalImpact {
+            dominated: Vec::new(),
+            lines,
+            bytes: lines * 10,
+        });
+        dead
+    }
+
+    fn symbols() -> Vec<DeadSymbol> {
+        vec![
+            dead(0, "/repo/b.ts", 3, SymbolKind::Class, 80, 2),
+            dead(1, "/repo/a.ts", 9, SymbolKind::Function, 95, 1),
+            dead(2, "/repo/b.ts", 1, SymbolKind::Function, 60, 7),
+        ]
+    }
+
+    fn ids(symbols: &[&DeadSymbol]) -> Vec<u32> {
+        symbols.iter().map(|d| d.symbol.id.0).collect()
+    }
+
+    #[test]
+    fn test_sort_orders() {
+        let dead = symbols();
+        let mut sorted: Vec<&DeadSymbol> = dead.iter().collect();
+
+        sort_dead_symbols(&mut sorted, SortOrder::File);
+        assert_eq!(ids(&sorted), vec![1, 2, 0]);
+        sort_dead_symbols(&mut sorted, SortOrder::Confidence);
+        assert_eq!(ids(&sorted), vec![1, 0, 2]);
+        sort_dead_symbols(&mut sorted, SortOrder::Impact);
+        assert_eq!(ids(&sorted), vec![2, 0, 1]);
+        sort_dead_symbols(&mut sorted, SortOrder::Kind);
+        assert_eq!(ids(&sorted), vec![1, 2, 0]);
+    }
+
+    #[test]
+    fn test_flat_layout_with_columns() {
+        let result = result(symbols());
+        let config = OutputConfig {
+            group_by_file: false,
+            sort: SortOrder::File,
+            columns: vec![TableColumn::File, TableColumn::Name],
+            ..OutputConfig::default()
+        };
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("table.txt");
+        let mut writer = writer(OutputFormat::Table)
+            .with_output_config(&config)
+            .with_output(&path)
+            .unwrap();
+        writer
+            .write_table(&result, &reported(&result), false)
+            .unwrap();
+        writer.finish().unwrap();
+
+        let output = std::fs::read_to_string(&path).unwrap();
+        let rows: Vec<&str> = output
+            .lines()
+            .filter(|line| line.starts_with('│'))
+            .collect();
+        assert_eq!(rows.len(), 4);
+        assert!(rows[0].contains("File") && rows[0].contains("Name"));
+        assert!(!rows[0].contains("Line"));
+        assert!(rows[1].contains("a.ts") && rows[1].contains("s1"));
+        assert!(rows[3].contains("b.ts") && rows[3].contains("s0"));
+    }
+
+    #[test]
+    fn test_grouped_headings_are_relative() {
+        let result = result(symbols());
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("table.txt");
+        let mut writer = writer(OutputFormat::Table).with_output(&path).unwrap();
+        writer
+            .write_table(&result, &reported(&result), false)
+            .unwrap();
+        writer.finish().unwrap();
+
+        let output = std::fs::read_to_string(&path).unwrap();
+        let headings: Vec<&str> = output
+            .lines()
+            .filter(|line| line.ends_with(".ts"))
+            .collect();
+        assert_eq!(headings, vec!["b.ts", "a.ts"]);
+    }
+
+    #[test]
+    fn test_file_output_is_plain() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_writer = writer(OutputFormat::Table)
+            .with_output(&dir.path().join("table.txt"))
+            .unwrap();
+
+        assert!(file_writer
+            .paint("No dead code found!".green().bold())
+            .is_plain());
+        // Only the writer sending output to a file loses its colors
+        assert!(!writer(OutputFormat::Table).paint("s0".green()).is_plain());
+    }
+}
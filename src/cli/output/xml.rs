@@ -0,0 +1,230 @@
+//! XML reporters: JUnit for CI test dashboards and Checkstyle for tools that
+//! read lint results. Both group dead symbols by file.
+
+use super::{format_kind, OutputWriter};
+use crate::core::{AnalysisResult, Confidence, DeadSymbol, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+const XML_HEADER: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+impl OutputWriter {
+    /// Write dead symbols as JUnit XML, one failed test case per symbol.
+    pub(super) fn write_junit(
+        &mut self,
+        result: &AnalysisResult,
+        dead_symbols: &[&DeadSymbol],
+    ) -> Result<()> {
+        self.emit(self.junit_xml(result, dead_symbols))?;
+        Ok(())
+    }
+
+    fn junit_xml(&self, result: &AnalysisResult, dead_symbols: &[&DeadSymbol]) -> String {
+        let mut xml = String::new();
+        let _ = writeln!(xml, "{}", XML_HEADER);
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="ddd" tests="{}" failures="{}" time="{:.3}">"#,
+            dead_symbols.len().max(1),
+            dead_symbols.len(),
+            result.duration_ms as f64 / 1000.0
+        );
+
+        // Report a passing test rather than none, which some CI tools treat as an error
+        if dead_symbols.is_empty() {
+            let _ = writeln!(xml, r#"  <testsuite name="ddd" tests="1" failures="0">"#);
+            let _ = writeln!(
+                xml,
+                r#"    <testcase name="no dead code" classname="ddd"/>"#
+            );
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+
+        for (file, symbols) in self.group_by_file(dead_symbols) {
+            let file = escape(&file);
+            let _ = writeln!(
+                xml,
+                r#"  <testsuite name="{}" tests="{}" failures="{}">"#,
+                file,
+                symbols.len(),
+                symbols.len()
+            );
+            for dead in symbols {
+                let location = &dead.symbol.location;
+                let _ = writeln!(
+                    xml,
+                    r#"    <testcase name="{} ({})" classname="{}" file="{}" line="{}">"#,
+                    escape(&dead.symbol.name),
+                    format_kind(dead.symbol.kind),
+                    file,
+                    file,
+                    location.line
+                );
+                let _ = writeln!(
+                    xml,
+                    r#"      <failure message="{}" type="{}">{}:{}:{}: {} ({} confidence, {})</failure>"#,
+                    escape(&dead.reason.description()),
+                    dead.reason.kind(),
+                    file,
+                    location.line,
+                    location.column,
+                    escape(&describe_dead(dead)),
+                    dead.confidence.label(),
+                    dead.confidence_score
+                );
+                let _ = writeln!(xml, "    </testcase>");
+            }
+            let _ = writeln!(xml, "  </testsuite>");
+        }
+
+        xml.push_str("</testsuites>");
+        xml
+    }
+
+    /// Write dead symbols as Checkstyle XML, one error per symbol.
+    pub(super) fn write_checkstyle(&mut self, dead_symbols: &[&DeadSymbol]) -> Result<()> {
+        self.emit(self.checkstyle_xml(dead_symbols))?;
+        Ok(())
+    }
+
+    fn checkstyle_xml(&self, dead_symbols: &[&DeadSymbol]) -> String {
+        let mut xml = String::new();
+        let _ = writeln!(xml, "{}", XML_HEADER);
+        let _ = writeln!(xml, r#"<checkstyle version="4.3">"#);
+
+        for (file, symbols) in self.group_by_file(dead_symbols) {
+            let _ = writeln!(xml, r#"  <file name="{}">"#, escape(&file));
+            for dead in symbols {
+                let severity = match dead.confidence {
+                    Confidence::High => "warning",
+                    Confidence::Medium | Confidence::Low => "info",
+                };
+                let _ = writeln!(
+                    xml,
+                    r#"    <error line="{}" column="{}" severity="{}" message="{}" source="ddd.{}"/>"#,
+                    dead.symbol.location.line,
+                    dead.symbol.location.column,
+                    severity,
+                    escape(&describe_dead(dead)),
+                    dead.reason.kind()
+                );
+            }
+            let _ = writeln!(xml, "  </file>");
+        }
+
+        xml.push_str("</checkstyle>");
+        xml
+    }
+
+    /// Group dead symbols by workspace path, sorted by path and then line.
+    pub(super) fn group_by_file<'a>(
+        &self,
+        dead_symbols: &[&'a DeadSymbol],
+    ) -> BTreeMap<String, Vec<&'a DeadSymbol>> {
+        let mut by_file: BTreeMap<String, Vec<&DeadSymbol>> = BTreeMap::new();
+        for &dead in dead_symbols {
+            by_file
+                .entry(self.workspace_path(&dead.symbol.location.file_path))
+                .or_default()
+                .push(dead);
+        }
+        for symbols in by_file.values_mut() {
+            symbols.sort_by_key(|d| (d.symbol.location.line, d.symbol.location.column));
+        }
+        by_file
+    }
+}
+
+fn describe_dead(dead: &DeadSymbol) -> String {
+    format!(
+        "`{}` ({}) is dead: {}",
+        dead.symbol.name,
+        format_kind(dead.symbol.kind),
+        dead.reason.description()
+    )
+}
+
+/// Escape text for use in XML content and attribute values.
+///
+/// Control characters XML 1.0 cannot represent are dropped.
+pub(super) fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\t' | '\n' | '\r' => escaped.push(c),
+            c if c.is_control() => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::output::fixtures::{dead_symbol, reported, result, writer};
+    use crate::config::OutputFormat;
+
+    #[test]
+    fn test_junit_groups_by_file() {
+        let mut result = result(vec![
+            dead_symbol(0, "later", 9).file("/repo/src/b.ts").build(),
+            dead_symbol(1, "isEqual<T>", 4).score(60).build(),
+            dead_symbol(2, "first", 2).file("/repo/src/b.ts").build(),
+        ]);
+        result.duration_ms = 1500;
+        let xml = writer(OutputFormat::Junit).junit_xml(&result, &reported(&result));
+
+        assert!(xml.starts_with(XML_HEADER));
+        assert!(xml.contains(r#"<testsuites name="ddd" tests="3" failures="3" time="1.500">"#));
+        assert!(xml.contains(r#"<testsuite name="src/a.ts" tests="1" failures="1">"#));
+        assert!(xml.contains(r#"<testcase name="isEqual&lt;T&gt; (fn)" classname="src/a.ts""#));
+        assert!(xml.contains(r#"type="unused-export""#));
+
+        let a = xml.find(r#"name="src/a.ts""#).unwrap();
+        let b = xml.find(r#"name="src/b.ts""#).unwrap();
+        let first = xml.find("first (fn)").unwrap();
+        let later = xml.find("later (fn)").unwrap();
+        assert!(a < b && b < first && first < later);
+        assert!(xml.ends_with("</testsuites>"));
+    }
+
+    #[test]
+    fn test_junit_without_dead_code_passes() {
+        let result = result(Vec::new());
+        let xml = writer(OutputFormat::Junit).junit_xml(&result, &[]);
+
+        assert!(xml.contains(r#"tests="1" failures="0""#));
+        assert!(xml.contains(r#"<testcase name="no dead code" classname="ddd"/>"#));
+    }
+
+    #[test]
+    fn test_checkstyle() {
+        let result = result(vec![
+            dead_symbol(0, "helper", 4).build(),
+            dead_symbol(1, "maybe", 7).score(60).build(),
+        ]);
+        let xml = writer(OutputFormat::Checkstyle).checkstyle_xml(&reported(&result));
+
+        assert!(xml.contains(r#"<file name="src/a.ts">"#));
+        assert!(xml.contains(
+            r#"<error line="4" column="1" severity="warning" message="`helper` (fn) is dead: exported but never imported" source="ddd.unused-export"/>"#
+        ));
+        assert!(xml.contains(r#"line="7" column="1" severity="info""#));
+        assert_eq!(xml.matches("<file ").count(), 1);
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(
+            escape(r#"a<b & "c" 'd'>"#),
+            "a&lt;b &amp; &quot;c&quot; &apos;d&apos;&gt;"
+        );
+        assert_eq!(escape("bell\u{7}"), "bell");
+    }
+}
@@ -1,6 +1,6 @@
 //! Configuration loading from ddd.toml or package.json.
 
-use super::schema::{Config, EntryConfig};
+use super::schema::{compile_glob, Config};
 use crate::core::{DddError, Result};
 use std::path::{Path, PathBuf};
 
@@ -11,13 +11,17 @@ const CONFIG_FILES: &[&str] = &["ddd.toml", ".dddrc.toml", "ddd.json", ".dddrc.j
 pub fn load_config(start_dir: &Path) -> Result<(Config, Option<PathBuf>)> {
     // Search for config file
     if let Some(config_path) = find_config_file(start_dir) {
-        let config = load_config_file(&config_path)?;
+        let mut config = load_config_file(&config_path)?;
+        config.validate()?;
+        config.base_dir = config_path.parent().map(Path::to_path_buf);
         return Ok((config, Some(config_path)));
     }
 
     // Try to load from package.json
     if let Some(pkg_path) = find_package_json(start_dir) {
-        if let Some(config) = load_from_package_json(&pkg_path)? {
+        if let Some(mut config) = load_from_package_json(&pkg_path)? {
+            config.validate()?;
+            config.base_dir = pkg_path.parent().map(Path::to_path_buf);
             return Ok((config, Some(pkg_path)));
         }
     }
@@ -66,7 +70,8 @@ fn find_package_json(start_dir: &Path) -> Option<PathBuf> {
 
 /// Load configuration from a config file.
 fn load_config_file(path: &Path) -> Result<Config> {
-    let content = std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+    let content =
+        std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
 
     let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("");
 
@@ -86,7 +91,8 @@ fn load_config_file(path: &Path) -> Result<Config> {
 
 /// Try to load ddd configuration from package.json "ddd" field.
 fn load_from_package_json(path: &Path) -> Result<Option<Config>> {
-    let content = std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+    let content =
+        std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
 
     let pkg: serde_json::Value = serde_json::from_str(&content).map_err(|e| {
         DddError::config_error(format!("Failed to parse {}: {}", path.display(), e))
@@ -103,22 +109,34 @@ fn load_from_package_json(path: &Path) -> Result<Option<Config>> {
     Ok(None)
 }
 
-/// Extract entry points from package.json.
-pub fn extract_entry_points_from_package_json(path: &Path) -> Result<EntryConfig> {
-    let content = std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+/// An entry file declared in package.json, with the field that declared it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageJsonEntry {
+    /// The declaring field, e.g. `main`, `bin.cli` or `exports["./utils"].import`.
+    pub field: String,
+    pub path: PathBuf,
+}
+
+/// Extract entry files from package.json, recording which field declared each one.
+pub fn extract_package_json_entries(path: &Path) -> Result<Vec<PackageJsonEntry>> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
 
     let pkg: PackageJson = serde_json::from_str(&content).map_err(|e| {
         DddError::config_error(format!("Failed to parse {}: {}", path.display(), e))
     })?;
 
-    let mut entry_files = Vec::new();
+    let mut entries = Vec::new();
     let pkg_dir = path.parent().unwrap_or(Path::new("."));
 
     // Main entry point
     if let Some(main) = &pkg.main {
         let main_path = pkg_dir.join(main);
         if main_path.exists() || likely_typescript_file(&main_path) {
-            entry_files.push(normalize_entry_path(pkg_dir, main));
+            entries.push(PackageJsonEntry {
+                field: "main".to_string(),
+                path: normalize_entry_path(pkg_dir, main),
+            });
         }
     }
 
@@ -126,24 +144,38 @@ pub fn extract_entry_points_from_package_json(path: &Path) -> Result<EntryConfig
     if let Some(module) = &pkg.module {
         let module_path = pkg_dir.join(module);
         if module_path.exists() || likely_typescript_file(&module_path) {
-            entry_files.push(normalize_entry_path(pkg_dir, module));
+            entries.push(PackageJsonEntry {
+                field: "module".to_string(),
+                path: normalize_entry_path(pkg_dir, module),
+            });
         }
     }
 
     // Types entry point
     if let Some(types) = &pkg.types {
-        entry_files.push(normalize_entry_path(pkg_dir, types));
+        entries.push(PackageJsonEntry {
+            field: "types".to_string(),
+            path: normalize_entry_path(pkg_dir, types),
+        });
     }
 
     // Bin entries
     if let Some(bin) = &pkg.bin {
         match bin {
             BinField::Single(path) => {
-                entry_files.push(normalize_entry_path(pkg_dir, path));
+                entries.push(PackageJsonEntry {
+                    field: "bin".to_string(),
+                    path: normalize_entry_path(pkg_dir, path),
+                });
             }
             BinField::Map(map) => {
-                for path in map.values() {
-                    entry_files.push(normalize_entry_path(pkg_dir, path));
+                let mut names: Vec<_> = map.keys().collect();
+                names.sort();
+                for name in names {
+                    entries.push(PackageJsonEntry {
+                        field: format!("bin.{}", name),
+                        path: normalize_entry_path(pkg_dir, &map[name]),
+                    });
                 }
             }
         }
@@ -151,15 +183,67 @@ pub fn extract_entry_points_from_package_json(path: &Path) -> Result<EntryConfig
 
     // Exports field (complex)
     if let Some(exports) = &pkg.exports {
-        extract_exports_entry_points(exports, pkg_dir, &mut entry_files);
+        extract_exports_entry_points(exports, "exports", pkg_dir, &mut entries);
+    }
+
+    Ok(entries)
+}
+
+/// The package.json `sideEffects` field, as understood by webpack and Rollup.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(untagged)]
+pub enum SideEffectsField {
+    /// `true` or `false` for every file in the package.
+    All(bool),
+    /// Only files matching these globs have side effects.
+    #[serde(deserialize_with = "deserialize_side_effects_globs")]
+    Files(Vec<glob::Pattern>),
+}
+
+impl SideEffectsField {
+    /// Check whether a file in the package at `pkg_dir` is declared side-effectful.
+    pub fn includes(&self, pkg_dir: &Path, file: &Path) -> bool {
+        match self {
+            SideEffectsField::All(value) => *value,
+            SideEffectsField::Files(patterns) => {
+                let Ok(relative) = file.strip_prefix(pkg_dir) else {
+                    return false;
+                };
+                let relative = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+
+                patterns.iter().any(|p| p.matches(&relative))
+            }
+        }
     }
+}
 
-    Ok(EntryConfig {
-        files: entry_files,
-        patterns: Vec::new(),
-        auto_detect: true,
-        exports: Vec::new(),
-    })
+/// Compile `sideEffects` globs once, skipping invalid ones as bundlers do.
+/// Bundlers match slash-free patterns against any directory, like
+/// [`compile_glob`].
+fn deserialize_side_effects_globs<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Vec<glob::Pattern>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let globs: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(globs.iter().filter_map(|g| compile_glob(g).ok()).collect())
+}
+
+/// Read the `sideEffects` field from package.json, if present.
+pub fn extract_side_effects_field(path: &Path) -> Result<Option<SideEffectsField>> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| DddError::io_error(path.to_path_buf(), e))?;
+
+    let pkg: PackageJson = serde_json::from_str(&content).map_err(|e| {
+        DddError::config_error(format!("Failed to parse {}: {}", path.display(), e))
+    })?;
+
+    Ok(pkg.side_effects)
 }
 
 /// Normalize an entry path, trying TypeScript extensions if .js is specified.
@@ -204,27 +288,33 @@ fn likely_typescript_file(path: &Path) -> bool {
 /// Extract entry points from package.json exports field.
 fn extract_exports_entry_points(
     exports: &serde_json::Value,
+    field: &str,
     pkg_dir: &Path,
-    entry_files: &mut Vec<PathBuf>,
+    entries: &mut Vec<PackageJsonEntry>,
 ) {
     match exports {
         serde_json::Value::String(path) => {
-            entry_files.push(normalize_entry_path(pkg_dir, path));
+            entries.push(PackageJsonEntry {
+                field: field.to_string(),
+                path: normalize_entry_path(pkg_dir, path),
+            });
         }
         serde_json::Value::Object(map) => {
             for (key, value) in map {
                 // Handle conditional exports
                 if key == "import" || key == "require" || key == "default" || key == "types" {
-                    extract_exports_entry_points(value, pkg_dir, entry_files);
+                    let field = format!("{}.{}", field, key);
+                    extract_exports_entry_points(value, &field, pkg_dir, entries);
                 } else if key.starts_with('.') {
                     // Subpath export
-                    extract_exports_entry_points(value, pkg_dir, entry_files);
+                    let field = format!("{}[\"{}\"]", field, key);
+                    extract_exports_entry_points(value, &field, pkg_dir, entries);
                 }
             }
         }
         serde_json::Value::Array(arr) => {
             for item in arr {
-                extract_exports_entry_points(item, pkg_dir, entry_files);
+                extract_exports_entry_points(item, field, pkg_dir, entries);
             }
         }
         _ => {}
@@ -239,6 +329,8 @@ struct PackageJson {
     types: Option<String>,
     bin: Option<BinField>,
     exports: Option<serde_json::Value>,
+    #[serde(rename = "sideEffects")]
+    side_effects: Option<SideEffectsField>,
 }
 
 /// The "bin" field can be a string or a map.
@@ -290,6 +382,95 @@ mod tests {
         assert!(!config.entry.auto_detect);
     }
 
+    #[test]
+    fn test_invalid_confidence_rule_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("ddd.toml"),
+            r#"
+            [[confidence.rules]]
+            name = "^(unclosed"
+            delta = -10
+            description = "legacy helpers"
+            "#,
+        )
+        .unwrap();
+
+        let err = load_config(temp_dir.path()).unwrap_err().to_string();
+        assert!(err.contains("legacy helpers"), "{}", err);
+        assert!(err.contains("name regex"), "{}", err);
+    }
+
+    #[test]
+    fn test_invalid_allow_eval_glob_is_rejected() {
+        let temp_dir = TempDir::new().unwrap();
+        std::fs::write(
+            temp_dir.path().join("ddd.toml"),
+            r#"
+            [analysis]
+            allowEval = ["src/[unclosed"]
+            "#,
+        )
+        .unwrap();
+
+        let err = load_config(temp_dir.path()).unwrap_err().to_string();
+        assert!(err.contains("allowEval"), "{}", err);
+    }
+
+    #[test]
+    fn test_package_json_entries_record_field() {
+        let temp_dir = TempDir::new().unwrap();
+        let pkg_path = temp_dir.path().join("package.json");
+
+        std::fs::write(temp_dir.path().join("index.ts"), "").unwrap();
+        std::fs::write(
+            &pkg_path,
+            r#"{
+                "main": "index.js",
+                "bin": { "tool": "cli.js" },
+                "exports": { "./utils": { "import": "utils.js" } }
+            }"#,
+        )
+        .unwrap();
+
+        let entries = extract_package_json_entries(&pkg_path).unwrap();
+        let fields: Vec<_> = entries.iter().map(|e| e.field.as_str()).collect();
+
+        assert_eq!(
+            fields,
+            vec!["main", "bin.tool", "exports[\"./utils\"].import"]
+        );
+        assert_eq!(entries[0].path, temp_dir.path().join("index.ts"));
+    }
+
+    #[test]
+    fn test_side_effects_field() {
+        let temp_dir = TempDir::new().unwrap();
+        let pkg_path = temp_dir.path().join("package.json");
+
+        std::fs::write(
+            &pkg_path,
+            r#"{ "sideEffects": ["./src/polyfill.ts", "*.css"] }"#,
+        )
+        .unwrap();
+
+        let field = extract_side_effects_field(&pkg_path).unwrap().unwrap();
+        let pkg_dir = temp_dir.path();
+
+        assert!(field.includes(pkg_dir, &pkg_dir.join("src/polyfill.ts")));
+        assert!(field.includes(pkg_dir, &pkg_dir.join("src/styles/theme.css")));
+        assert!(!field.includes(pkg_dir, &pkg_dir.join("src/utils.ts")));
+
+        std::fs::write(&pkg_path, r#"{ "sideEffects": false }"#).unwrap();
+        assert_eq!(
+            extract_side_effects_field(&pkg_path).unwrap(),
+            Some(SideEffectsField::All(false))
+        );
+
+        std::fs::write(&pkg_path, r#"{ "name": "pkg" }"#).unwrap();
+        assert_eq!(extract_side_effects_field(&pkg_path).unwrap(), None);
+    }
+
     #[test]
     fn test_generate_default_config() {
         let config_str = generate_default_config();
@@ -1,8 +1,10 @@
 //! Configuration schema for ddd.
 
+use crate::core::{DddError, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
-use std::path::PathBuf;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
 
 /// Main configuration structure.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -35,6 +37,22 @@ pub struct Config {
     /// Plugin configuration.
     #[serde(default)]
     pub plugins: PluginsConfig,
+
+    /// Confidence scoring configuration.
+    #[serde(default)]
+    pub confidence: ConfidenceConfig,
+
+    /// Git history configuration.
+    #[serde(default)]
+    pub history: HistoryConfig,
+
+    /// Settings for subsets of the tree, applied in order.
+    #[serde(default)]
+    pub overrides: Vec<ConfigOverride>,
+
+    /// Directory that override globs are relative to: the config file's.
+    #[serde(skip)]
+    pub base_dir: Option<PathBuf>,
 }
 
 impl Default for Config {
@@ -62,12 +80,16 @@ impl Default for Config {
             output: OutputConfig::default(),
             analysis: AnalysisConfig::default(),
             plugins: PluginsConfig::default(),
+            confidence: ConfidenceConfig::default(),
+            history: HistoryConfig::default(),
+            overrides: Vec::new(),
+            base_dir: None,
         }
     }
 }
 
 /// Entry point configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct EntryConfig {
     /// Explicit entry point files.
@@ -87,11 +109,23 @@ pub struct EntryConfig {
     pub exports: Vec<String>,
 }
 
+impl Default for EntryConfig {
+    fn default() -> Self {
+        Self {
+            files: Vec::new(),
+            patterns: Vec::new(),
+            auto_detect: true,
+            exports: Vec::new(),
+        }
+    }
+}
+
 /// Output configuration.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OutputConfig {
-    /// Output format: table, json, or compact.
+    /// Output format: table, json, ndjson, compact, sarif, github, gitlab,
+    /// junit, checkstyle, html, or markdown.
     #[serde(default)]
     pub format: OutputFormat,
 
@@ -110,6 +144,14 @@ pub struct OutputConfig {
     /// Group output by file.
     #[serde(default = "default_true")]
     pub group_by_file: bool,
+
+    /// Order of dead symbols in table and compact output.
+    #[serde(default)]
+    pub sort: SortOrder,
+
+    /// Columns shown in table output, in order.
+    #[serde(default = "default_columns")]
+    pub columns: Vec<TableColumn>,
 }
 
 impl Default for OutputConfig {
@@ -120,6 +162,8 @@ impl Default for OutputConfig {
             show_chains: true,
             max_chain_length: 5,
             group_by_file: true,
+            sort: SortOrder::Impact,
+            columns: default_columns(),
         }
     }
 }
@@ -131,11 +175,93 @@ pub enum OutputFormat {
     #[default]
     Table,
     Json,
+    Ndjson,
     Compact,
+    Sarif,
+    Github,
+    Gitlab,
+    Junit,
+    Checkstyle,
+    Html,
+    Markdown,
+}
+
+impl OutputFormat {
+    /// Get the format's name as used on the command line.
+    pub fn name(&self) -> &'static str {
+        match self {
+            OutputFormat::Table => "table",
+            OutputFormat::Json => "json",
+            OutputFormat::Ndjson => "ndjson",
+            OutputFormat::Compact => "compact",
+            OutputFormat::Sarif => "sarif",
+            OutputFormat::Github => "github",
+            OutputFormat::Gitlab => "gitlab",
+            OutputFormat::Junit => "junit",
+            OutputFormat::Checkstyle => "checkstyle",
+            OutputFormat::Html => "html",
+            OutputFormat::Markdown => "markdown",
+        }
+    }
+}
+
+/// Order of dead symbols in table and compact output.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    /// By file path, then line.
+    File,
+    /// Highest confidence score first.
+    Confidence,
+    /// Most lines freed by removal first.
+    #[default]
+    Impact,
+    /// By symbol kind, then file and line.
+    Kind,
+}
+
+/// A column of the dead code table.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum TableColumn {
+    File,
+    Line,
+    Name,
+    Kind,
+    Confidence,
+    Reason,
+    Impact,
+}
+
+impl TableColumn {
+    /// Get the column's header.
+    pub fn title(&self) -> &'static str {
+        match self {
+            TableColumn::File => "File",
+            TableColumn::Line => "Line",
+            TableColumn::Name => "Name",
+            TableColumn::Kind => "Kind",
+            TableColumn::Confidence => "Confidence",
+            TableColumn::Reason => "Reason",
+            TableColumn::Impact => "Impact",
+        }
+    }
 }
 
 /// Confidence level filter.
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Serialize,
+    Deserialize,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    clap::ValueEnum,
+)]
 #[serde(rename_all = "lowercase")]
 pub enum ConfidenceLevel {
     Low,
@@ -186,6 +312,10 @@ pub struct AnalysisConfig {
     /// Patterns for symbols to ignore.
     #[serde(default)]
     pub ignore_patterns: Vec<String>,
+
+    /// Globs for files whose `eval` usage is known to be safe.
+    #[serde(default)]
+    pub allow_eval: Vec<String>,
 }
 
 impl Default for AnalysisConfig {
@@ -200,12 +330,413 @@ impl Default for AnalysisConfig {
             ignore_patterns: vec![
                 "^_".to_string(), // Private by convention
             ],
+            allow_eval: Vec::new(),
         }
     }
 }
 
+/// Confidence scoring configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfidenceConfig {
+    /// Score adjustments applied by the built-in rules.
+    #[serde(default)]
+    pub weights: ConfidenceWeights,
+
+    /// Minimum score for medium confidence.
+    #[serde(default = "default_medium_threshold")]
+    pub medium_threshold: u8,
+
+    /// Minimum score for high confidence.
+    #[serde(default = "default_high_threshold")]
+    pub high_threshold: u8,
+
+    /// Custom rules applied after the built-in ones.
+    #[serde(default)]
+    pub rules: Vec<ConfidenceRule>,
+}
+
+impl Default for ConfidenceConfig {
+    fn default() -> Self {
+        Self {
+            weights: ConfidenceWeights::default(),
+            medium_threshold: 50,
+            high_threshold: 80,
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl ConfidenceConfig {
+    /// Map a score to a confidence level using the configured thresholds.
+    pub fn level(&self, score: u8) -> crate::core::Confidence {
+        if score >= self.high_threshold {
+            crate::core::Confidence::High
+        } else if score >= self.medium_threshold {
+            crate::core::Confidence::Medium
+        } else {
+            crate::core::Confidence::Low
+        }
+    }
+}
+
+/// Score adjustments for the built-in confidence rules.
+///
+/// Negative values lower confidence that a symbol is dead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct ConfidenceWeights {
+    /// Symbol has decorators.
+    pub decorated: i32,
+    /// Symbol is exported.
+    pub exported: i32,
+    /// Symbol's file calls `eval`.
+    pub file_eval: i32,
+    /// Symbol's module imports, or is imported by, a module that uses `eval`.
+    pub connected_eval: i32,
+    /// Symbol is only referenced by other dead code.
+    pub transitive: i32,
+    /// Symbol is a type or interface.
+    pub type_only: i32,
+    /// Symbol name starts with a single underscore.
+    pub private_convention: i32,
+    /// Symbol is affected by `eval` or `new Function`.
+    pub eval_pattern: i32,
+    /// Symbol is affected by `Reflect` usage.
+    pub reflect_pattern: i32,
+    /// Symbol is affected by computed or string property access.
+    pub property_access_pattern: i32,
+    /// Symbol is affected by object iteration.
+    pub object_iteration_pattern: i32,
+    /// Symbol is affected by a dynamic import or require.
+    pub dynamic_import_pattern: i32,
+    /// Symbol is an anonymous default export.
+    pub default_export: i32,
+    /// Symbol is a class method.
+    pub method: i32,
+    /// Symbol's initializer has side effects.
+    pub side_effects: i32,
+    /// Coverage data shows the symbol never ran.
+    pub never_executed: i32,
+    /// Coverage data shows the symbol ran despite being statically dead.
+    pub executed: i32,
+    /// A runtime trace covers the symbol's file, and it never ran.
+    pub never_traced: i32,
+    /// Symbol's lines are older than `history.staleAfter` days.
+    pub stale: i32,
+    /// Symbol's lines are newer than `history.newWithin` days.
+    pub new_code: i32,
+}
+
+impl Default for ConfidenceWeights {
+    fn default() -> Self {
+        Self {
+            decorated: -20,
+            exported: -10,
+            file_eval: -30,
+            connected_eval: -15,
+            transitive: -5,
+            type_only: 5,
+            private_convention: 5,
+            eval_pattern: -40,
+            reflect_pattern: -30,
+            property_access_pattern: -20,
+            object_iteration_pattern: -15,
+            dynamic_import_pattern: -25,
+            default_export: -10,
+            method: -5,
+            side_effects: -10,
+            never_executed: 10,
+            executed: -40,
+            never_traced: 15,
+            stale: 10,
+            new_code: -10,
+        }
+    }
+}
+
+/// A user-defined confidence adjustment.
+///
+/// Every condition that is set must match for `delta` to apply.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfidenceRule {
+    /// Glob matched against the symbol's file path relative to the config
+    /// file's directory, e.g. `src/legacy/**`.
+    #[serde(default)]
+    pub path: Option<String>,
+
+    /// Regex matched against the symbol name.
+    #[serde(default)]
+    pub name: Option<String>,
+
+    /// Symbol kind, e.g. `function` or `class`.
+    #[serde(default)]
+    pub kind: Option<crate::core::SymbolKind>,
+
+    /// Score adjustment.
+    pub delta: i32,
+
+    /// Explanation shown in confidence breakdowns.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+impl ConfidenceRule {
+    /// Compile the `path` glob and the `name` regex.
+    pub fn compile(&self) -> Result<(Option<glob::Pattern>, Option<regex_lite::Regex>)> {
+        let invalid = |what: &str, e: &dyn std::fmt::Display| {
+            DddError::config_error(format!(
+                "Invalid {} in confidence rule \"{}\": {}",
+                what,
+                self.description(),
+                e
+            ))
+        };
+
+        let path = match &self.path {
+            Some(path) => Some(compile_glob(path).map_err(|e| invalid("path glob", &e))?),
+            None => None,
+        };
+        let name = match &self.name {
+            Some(name) => {
+                Some(regex_lite::Regex::new(name).map_err(|e| invalid("name regex", &e))?)
+            }
+            None => None,
+        };
+
+        Ok((path, name))
+    }
+
+    /// Get the rule's description, falling back to its conditions.
+    pub fn description(&self) -> String {
+        if let Some(description) = &self.description {
+            return description.clone();
+        }
+
+        let mut conditions = Vec::new();
+        if let Some(path) = &self.path {
+            conditions.push(format!("path `{}`", path));
+        }
+        if let Some(name) = &self.name {
+            conditions.push(format!("name `{}`", name));
+        }
+        if let Some(kind) = &self.kind {
+            conditions.push(format!("kind {}", kind.as_str()));
+        }
+
+        if conditions.is_empty() {
+            "custom rule".to_string()
+        } else {
+            conditions.join(", ")
+        }
+    }
+}
+
+/// Git history configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryConfig {
+    /// Read `git blame` to date dead symbols.
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Only report dead code untouched for at least this many days.
+    #[serde(default)]
+    pub min_age: u64,
+
+    /// Days after which untouched code counts as stale.
+    #[serde(default = "default_stale_after")]
+    pub stale_after: u64,
+
+    /// Days within which changed code counts as new.
+    #[serde(default = "default_new_within")]
+    pub new_within: u64,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            min_age: 0,
+            stale_after: 180,
+            new_within: 14,
+        }
+    }
+}
+
+impl HistoryConfig {
+    /// Whether git history is needed, either directly or for `minAge`.
+    pub fn is_active(&self) -> bool {
+        self.enabled || self.min_age > 0
+    }
+
+    /// Classify code by the number of days since it last changed.
+    pub fn freshness(&self, age_days: u64) -> crate::core::Freshness {
+        if age_days < self.new_within {
+            crate::core::Freshness::New
+        } else if age_days >= self.stale_after {
+            crate::core::Freshness::Stale
+        } else {
+            crate::core::Freshness::Active
+        }
+    }
+}
+
+/// Settings that apply only to files matching `files`.
+///
+/// Unset fields keep the value from the main config; lists replace it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigOverride {
+    /// Globs for the files this override applies to, e.g. `src/legacy/**`.
+    pub files: Vec<String>,
+
+    /// Globs for files to leave out even though they match `files`.
+    #[serde(default)]
+    pub excluded_files: Vec<String>,
+
+    /// Leave matching files out of the analysis.
+    #[serde(default)]
+    pub skip: Option<bool>,
+
+    /// Minimum confidence level to report.
+    #[serde(default)]
+    pub min_confidence: Option<ConfidenceLevel>,
+
+    /// Entry point settings.
+    #[serde(default)]
+    pub entry: EntryOverride,
+
+    /// Analysis settings.
+    #[serde(default)]
+    pub analysis: AnalysisOverride,
+
+    /// Plugin settings.
+    #[serde(default)]
+    pub plugins: PluginsOverride,
+}
+
+/// Entry point settings that an override can change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryOverride {
+    /// Whether package.json entry files among the matching files are entry points.
+    #[serde(default)]
+    pub auto_detect: Option<bool>,
+
+    /// Exported symbols in the matching files to consider as entry points.
+    #[serde(default)]
+    pub exports: Option<Vec<String>>,
+}
+
+/// Analysis settings that an override can change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisOverride {
+    /// Include type-only dead code (interfaces, type aliases).
+    #[serde(default)]
+    pub include_types: Option<bool>,
+
+    /// Symbols to always consider alive.
+    #[serde(default)]
+    pub ignore_symbols: Option<HashSet<String>>,
+
+    /// Patterns for symbols to ignore.
+    #[serde(default)]
+    pub ignore_patterns: Option<Vec<String>>,
+}
+
+/// Plugin settings that an override can change.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PluginsOverride {
+    /// Enabled plugins.
+    #[serde(default)]
+    pub enabled: Option<Vec<String>>,
+
+    /// Disabled plugins.
+    #[serde(default)]
+    pub disabled: Option<Vec<String>>,
+
+    /// Auto-detect plugins from package.json.
+    #[serde(default)]
+    pub auto_detect: Option<bool>,
+}
+
+impl ConfigOverride {
+    /// Apply the override's settings on top of a config.
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(level) = self.min_confidence {
+            config.output.min_confidence = level;
+        }
+
+        if let Some(auto_detect) = self.entry.auto_detect {
+            config.entry.auto_detect = auto_detect;
+        }
+        if let Some(exports) = &self.entry.exports {
+            config.entry.exports = exports.clone();
+        }
+
+        if let Some(include_types) = self.analysis.include_types {
+            config.analysis.include_types = include_types;
+        }
+        if let Some(ignore_symbols) = &self.analysis.ignore_symbols {
+            config.analysis.ignore_symbols = ignore_symbols.clone();
+        }
+        if let Some(ignore_patterns) = &self.analysis.ignore_patterns {
+            config.analysis.ignore_patterns = ignore_patterns.clone();
+        }
+
+        if let Some(enabled) = &self.plugins.enabled {
+            config.plugins.enabled = enabled.clone();
+        }
+        if let Some(disabled) = &self.plugins.disabled {
+            config.plugins.disabled = disabled.clone();
+        }
+        if let Some(auto_detect) = self.plugins.auto_detect {
+            config.plugins.auto_detect = auto_detect;
+        }
+    }
+}
+
+/// A list of config globs, compiled once to match many paths.
+#[derive(Debug, Clone)]
+pub struct GlobList(Vec<glob::Pattern>);
+
+impl GlobList {
+    pub fn new(globs: &[String]) -> Self {
+        // Config::validate rejects invalid globs when the config loads
+        Self(
+            globs
+                .iter()
+                .filter_map(|glob| compile_glob(glob).ok())
+                .collect(),
+        )
+    }
+
+    /// Check if a path relative to the config file's directory matches any glob.
+    pub fn matches(&self, relative: &Path) -> bool {
+        let path = relative.to_string_lossy();
+        self.0.iter().any(|p| p.matches(&path))
+    }
+}
+
+/// Compile a glob from the config, to match paths relative to the config
+/// file's directory.
+///
+/// Globs without a slash, like `*.test.ts`, match in any directory.
+pub fn compile_glob(glob: &str) -> std::result::Result<glob::Pattern, glob::PatternError> {
+    let glob = glob.strip_prefix("./").unwrap_or(glob);
+    if glob.contains('/') {
+        glob::Pattern::new(glob)
+    } else {
+        glob::Pattern::new(&format!("**/{}", glob))
+    }
+}
+
 /// Plugin configuration.
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PluginsConfig {
     /// Enabled plugins.
@@ -233,6 +764,19 @@ pub struct PluginsConfig {
     pub express: ExpressConfig,
 }
 
+impl Default for PluginsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: Vec::new(),
+            disabled: Vec::new(),
+            auto_detect: true,
+            nextjs: NextJsConfig::default(),
+            jest: JestConfig::default(),
+            express: ExpressConfig::default(),
+        }
+    }
+}
+
 /// Next.js plugin configuration.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -277,11 +821,75 @@ fn default_chain_length() -> usize {
     5
 }
 
+fn default_columns() -> Vec<TableColumn> {
+    vec![
+        TableColumn::File,
+        TableColumn::Line,
+        TableColumn::Name,
+        TableColumn::Kind,
+        TableColumn::Confidence,
+        TableColumn::Reason,
+        TableColumn::Impact,
+    ]
+}
+
 fn default_max_depth() -> usize {
     50
 }
 
+fn default_medium_threshold() -> u8 {
+    50
+}
+
+fn default_high_threshold() -> u8 {
+    80
+}
+
+fn default_stale_after() -> u64 {
+    180
+}
+
+fn default_new_within() -> u64 {
+    14
+}
+
 impl Config {
+    /// Check the patterns in the config, so a typo fails loudly instead of
+    /// silently disabling a rule.
+    pub fn validate(&self) -> Result<()> {
+        let confidence = &self.confidence;
+        if confidence.medium_threshold > confidence.high_threshold {
+            return Err(DddError::config_error(format!(
+                "confidence.mediumThreshold ({}) must not exceed confidence.highThreshold ({})",
+                confidence.medium_threshold, confidence.high_threshold
+            )));
+        }
+        for rule in &self.confidence.rules {
+            rule.compile()?;
+        }
+        for pattern in &self.analysis.allow_eval {
+            check_glob(pattern, "analysis.allowEval")?;
+        }
+        for pattern in &self.analysis.ignore_patterns {
+            check_regex(pattern, "analysis.ignorePatterns")?;
+        }
+        for (i, o) in self.overrides.iter().enumerate() {
+            for pattern in &o.files {
+                check_glob(pattern, &format!("overrides[{}].files", i))?;
+            }
+            for pattern in &o.excluded_files {
+                check_glob(pattern, &format!("overrides[{}].excludedFiles", i))?;
+            }
+            for pattern in o.analysis.ignore_patterns.iter().flatten() {
+                check_regex(
+                    pattern,
+                    &format!("overrides[{}].analysis.ignorePatterns", i),
+                )?;
+            }
+        }
+        Ok(())
+    }
+
     /// Create a minimal config for quick analysis.
     pub fn minimal() -> Self {
         Self {
@@ -290,8 +898,8 @@ impl Config {
         }
     }
 
-    /// Check if a file path should be included in analysis.
-    pub fn should_include(&self, path: &std::path::Path) -> bool {
+    /// Check a file path against the `include` and `exclude` globs.
+    fn matches_include_patterns(&self, path: &Path) -> bool {
         let path_str = path.to_string_lossy();
 
         // Check excludes first
@@ -321,21 +929,237 @@ impl Config {
         false
     }
 
-    /// Check if a symbol name should be ignored.
-    pub fn should_ignore_symbol(&self, name: &str) -> bool {
-        if self.analysis.ignore_symbols.contains(name) {
-            return true;
+    /// Check if a file path should be included in analysis.
+    ///
+    /// To check many files, use a [`ConfigMatcher`].
+    pub fn should_include(&self, path: &Path) -> bool {
+        ConfigMatcher::new(self).should_include(path)
+    }
+
+    /// Get the overrides that apply to a file, in order, with their indices.
+    ///
+    /// To check many files, use a [`ConfigMatcher`].
+    pub fn matching_overrides(&self, path: &Path) -> Vec<(usize, &ConfigOverride)> {
+        ConfigMatcher::new(self).matching_overrides(path).collect()
+    }
+
+    /// Get a file's path relative to the config file's directory, or `None`
+    /// for files outside it.
+    pub fn relative_path(&self, path: &Path) -> Option<PathBuf> {
+        match &self.base_dir {
+            Some(base) => path
+                .strip_prefix(base)
+                .ok()
+                .map(|p| p.components().collect()),
+            None => Some(path.components().collect()),
         }
+    }
 
-        for pattern in &self.analysis.ignore_patterns {
-            if let Ok(re) = regex_lite::Regex::new(pattern) {
-                if re.is_match(name) {
-                    return true;
-                }
+    /// Get the effective config for a file, with matching overrides applied.
+    pub fn for_path(&self, path: &Path) -> Cow<'_, Config> {
+        let overrides = self.matching_overrides(path);
+        if overrides.is_empty() {
+            return Cow::Borrowed(self);
+        }
+
+        let mut config = self.clone();
+        for (_, o) in overrides {
+            o.apply(&mut config);
+        }
+        Cow::Owned(config)
+    }
+}
+
+/// The override and `analysis.allowEval` globs of a config, compiled once to
+/// check many files.
+pub struct ConfigMatcher<'a> {
+    config: &'a Config,
+    /// `files` and `excludedFiles` of each override, in order.
+    overrides: Vec<(GlobList, GlobList)>,
+    allow_eval: GlobList,
+}
+
+impl<'a> ConfigMatcher<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            overrides: config
+                .overrides
+                .iter()
+                .map(|o| (GlobList::new(&o.files), GlobList::new(&o.excluded_files)))
+                .collect(),
+            allow_eval: GlobList::new(&config.analysis.allow_eval),
+        }
+    }
+
+    /// Check if a file path should be included in analysis.
+    pub fn should_include(&self, path: &Path) -> bool {
+        if !self.config.matches_include_patterns(path) {
+            return false;
+        }
+
+        // The last override that says anything decides
+        let skip = self
+            .matching_overrides(path)
+            .filter_map(|(_, o)| o.skip)
+            .last()
+            .unwrap_or(false);
+        !skip
+    }
+
+    /// Get the overrides that apply to a file, in order, with their indices.
+    ///
+    /// Files outside the config file's directory match no overrides.
+    pub fn matching_overrides(
+        &self,
+        path: &Path,
+    ) -> impl Iterator<Item = (usize, &'a ConfigOverride)> + '_ {
+        let relative = self.config.relative_path(path);
+        self.config
+            .overrides
+            .iter()
+            .zip(&self.overrides)
+            .enumerate()
+            .filter(move |(_, (_, (files, excluded)))| {
+                relative
+                    .as_deref()
+                    .is_some_and(|p| files.matches(p) && !excluded.matches(p))
+            })
+            .map(|(i, (o, _))| (i, o))
+    }
+
+    /// Get the minimum confidence to report for a file.
+    ///
+    /// A level given on the command line wins; otherwise the last matching
+    /// override's, then `output.minConfidence`.
+    pub fn min_confidence_for(&self, path: &Path, cli: Option<ConfidenceLevel>) -> ConfidenceLevel {
+        cli.or_else(|| {
+            self.matching_overrides(path)
+                .filter_map(|(_, o)| o.min_confidence)
+                .last()
+        })
+        .unwrap_or(self.config.output.min_confidence)
+    }
+
+    /// Check if a file matches `analysis.allowEval`, relative to the config
+    /// file's directory.
+    pub fn allows_eval(&self, path: &Path) -> bool {
+        self.config
+            .relative_path(path)
+            .is_some_and(|relative| self.allow_eval.matches(&relative))
+    }
+}
+
+/// Check that a glob in the config compiles, naming the setting it is in.
+fn check_glob(pattern: &str, setting: &str) -> Result<()> {
+    glob::Pattern::new(pattern).map(|_| ()).map_err(|e| {
+        DddError::config_error(format!(
+            "Invalid glob \"{}\" in {}: {}",
+            pattern, setting, e
+        ))
+    })
+}
+
+/// Check that a regex in the config compiles, naming the setting it is in.
+fn check_regex(pattern: &str, setting: &str) -> Result<()> {
+    regex_lite::Regex::new(pattern).map(|_| ()).map_err(|e| {
+        DddError::config_error(format!(
+            "Invalid regex \"{}\" in {}: {}",
+            pattern, setting, e
+        ))
+    })
+}
+
+/// Effective configs for files, resolved once per file.
+///
+/// Files matching the same overrides share one resolved config, so the config
+/// is cloned once per combination of overrides rather than once per lookup.
+pub struct FileConfigs<'a> {
+    config: &'a Config,
+    matcher: ConfigMatcher<'a>,
+    resolved: Vec<FileConfig<'a>>,
+    by_overrides: HashMap<Vec<usize>, usize>,
+    by_file: HashMap<PathBuf, usize>,
+}
+
+impl<'a> FileConfigs<'a> {
+    pub fn new(config: &'a Config) -> Self {
+        Self {
+            config,
+            matcher: ConfigMatcher::new(config),
+            resolved: Vec::new(),
+            by_overrides: HashMap::new(),
+            by_file: HashMap::new(),
+        }
+    }
+
+    /// Get the effective config for a file.
+    pub fn get(&mut self, path: &Path) -> &FileConfig<'a> {
+        if let Some(&index) = self.by_file.get(path) {
+            return &self.resolved[index];
+        }
+
+        let overrides: Vec<usize> = self
+            .matcher
+            .matching_overrides(path)
+            .map(|(i, _)| i)
+            .collect();
+        let index = match self.by_overrides.get(&overrides) {
+            Some(&index) => index,
+            None => {
+                self.resolved.push(FileConfig::new(self.config, &overrides));
+                self.by_overrides.insert(overrides, self.resolved.len() - 1);
+                self.resolved.len() - 1
             }
+        };
+        self.by_file.insert(path.to_path_buf(), index);
+        &self.resolved[index]
+    }
+}
+
+/// A file's effective config, with its symbol ignore patterns compiled.
+pub struct FileConfig<'a> {
+    config: Cow<'a, Config>,
+    ignore_patterns: Vec<regex_lite::Regex>,
+}
+
+impl<'a> FileConfig<'a> {
+    fn new(config: &'a Config, overrides: &[usize]) -> Self {
+        let config = if overrides.is_empty() {
+            Cow::Borrowed(config)
+        } else {
+            let mut effective = config.clone();
+            for &i in overrides {
+                config.overrides[i].apply(&mut effective);
+            }
+            Cow::Owned(effective)
+        };
+        // Config::validate rejects invalid patterns when the config loads
+        let ignore_patterns = config
+            .analysis
+            .ignore_patterns
+            .iter()
+            .filter_map(|pattern| regex_lite::Regex::new(pattern).ok())
+            .collect();
+
+        Self {
+            config,
+            ignore_patterns,
         }
+    }
 
-        false
+    /// Check if a symbol declared in the file should be ignored.
+    pub fn should_ignore_symbol(&self, name: &str) -> bool {
+        self.config.analysis.ignore_symbols.contains(name)
+            || self.ignore_patterns.iter().any(|re| re.is_match(name))
+    }
+}
+
+impl std::ops::Deref for FileConfig<'_> {
+    type Target = Config;
+
+    fn deref(&self) -> &Config {
+        &self.config
     }
 }
 
@@ -347,17 +1171,244 @@ mod tests {
     fn test_default_config() {
         let config = Config::default();
         assert!(config.entry.auto_detect);
+        assert!(config.plugins.auto_detect);
         assert!(config.output.show_chains);
         assert_eq!(config.output.min_confidence, ConfidenceLevel::High);
     }
 
+    #[test]
+    fn test_plugins_auto_detect_by_default() {
+        let config: Config = toml::from_str("exclude = [\"dist/**\"]").unwrap();
+        assert!(config.plugins.auto_detect);
+
+        let config: Config = toml::from_str("[plugins]\nenabled = [\"jest\"]").unwrap();
+        assert!(config.plugins.auto_detect);
+        assert_eq!(config.plugins.enabled, vec!["jest"]);
+    }
+
     #[test]
     fn test_should_include() {
         let config = Config::default();
 
-        assert!(config.should_include(std::path::Path::new("src/foo.ts")));
-        assert!(!config.should_include(std::path::Path::new("node_modules/foo.ts")));
-        assert!(!config.should_include(std::path::Path::new("src/foo.test.ts")));
+        assert!(config.should_include(Path::new("src/foo.ts")));
+        assert!(!config.should_include(Path::new("node_modules/foo.ts")));
+        assert!(!config.should_include(Path::new("src/foo.test.ts")));
+    }
+
+    #[test]
+    fn test_confidence_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [confidence]
+            highThreshold = 90
+
+            [confidence.weights]
+            exported = -25
+
+            [[confidence.rules]]
+            path = "src/legacy/**"
+            kind = "function"
+            delta = -25
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config.confidence.weights.exported, -25);
+        assert_eq!(config.confidence.weights.decorated, -20);
+        assert_eq!(config.confidence.medium_threshold, 50);
+        assert_eq!(config.confidence.level(85), crate::core::Confidence::Medium);
+        assert_eq!(config.confidence.level(90), crate::core::Confidence::High);
+        assert_eq!(
+            config.confidence.rules[0].kind,
+            Some(crate::core::SymbolKind::Function)
+        );
+    }
+
+    #[test]
+    fn test_history_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [history]
+            minAge = 90
+            staleAfter = 365
+            "#,
+        )
+        .unwrap();
+
+        assert!(!config.history.enabled);
+        assert!(config.history.is_active());
+        assert_eq!(config.history.freshness(3), crate::core::Freshness::New);
+        assert_eq!(
+            config.history.freshness(200),
+            crate::core::Freshness::Active
+        );
+        assert_eq!(config.history.freshness(365), crate::core::Freshness::Stale);
+    }
+
+    #[test]
+    fn test_output_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [output]
+            groupByFile = false
+            sort = "confidence"
+            columns = ["file", "name", "confidence"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(!config.output.group_by_file);
+        assert_eq!(config.output.sort, SortOrder::Confidence);
+        assert_eq!(
+            config.output.columns,
+            vec![
+                TableColumn::File,
+                TableColumn::Name,
+                TableColumn::Confidence
+            ]
+        );
+        assert_eq!(Config::default().output.sort, SortOrder::Impact);
+    }
+
+    #[test]
+    fn test_overrides() {
+        let config: Config = toml::from_str(
+            r#"
+            [[overrides]]
+            files = ["src/legacy/**"]
+            excludedFiles = ["src/legacy/keep/**"]
+            minConfidence = "low"
+
+            [overrides.analysis]
+            includeTypes = false
+            ignorePatterns = ["^legacy"]
+
+            [[overrides]]
+            files = ["src/legacy/generated/**"]
+            skip = true
+            "#,
+        )
+        .unwrap();
+
+        let mut config = config;
+        config.base_dir = Some(PathBuf::from("/repo"));
+        let mut configs = FileConfigs::new(&config);
+        let matcher = ConfigMatcher::new(&config);
+
+        let legacy = Path::new("/repo/./src/legacy/a.ts");
+        let effective = config.for_path(legacy);
+        assert!(!effective.analysis.include_types);
+        assert_eq!(effective.output.min_confidence, ConfidenceLevel::Low);
+        assert!(configs.get(legacy).should_ignore_symbol("legacyHelper"));
+        // Overrides replace lists rather than merging them
+        assert!(!configs.get(legacy).should_ignore_symbol("_private"));
+        assert_eq!(
+            matcher.min_confidence_for(legacy, None),
+            ConfidenceLevel::Low
+        );
+        assert_eq!(
+            matcher.min_confidence_for(legacy, Some(ConfidenceLevel::Medium)),
+            ConfidenceLevel::Medium
+        );
+
+        let kept = Path::new("/repo/./src/legacy/keep/b.ts");
+        assert!(matches!(config.for_path(kept), Cow::Borrowed(_)));
+        assert_eq!(
+            matcher.min_confidence_for(kept, None),
+            ConfidenceLevel::High
+        );
+        assert!(configs.get(kept).should_ignore_symbol("_private"));
+        assert!(!configs.get(kept).should_ignore_symbol("legacyHelper"));
+
+        assert!(config.should_include(legacy));
+        assert!(!config.should_include(Path::new("/repo/src/legacy/generated/c.ts")));
+    }
+
+    #[test]
+    fn test_override_globs_are_relative_to_config() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [[overrides]]
+            files = ["legacy/**", "*.gen.ts"]
+            skip = true
+            "#,
+        )
+        .unwrap();
+        config.base_dir = Some(PathBuf::from("/tmp/legacy/proj"));
+
+        // Directories above the config file are not part of the match
+        assert!(config.should_include(Path::new("/tmp/legacy/proj/src/a.ts")));
+        assert!(!config.should_include(Path::new("/tmp/legacy/proj/legacy/a.ts")));
+        assert!(!config.should_include(Path::new("/tmp/legacy/proj/./src/api.gen.ts")));
+        // Nor are files outside it
+        assert!(config.should_include(Path::new("/elsewhere/legacy/a.ts")));
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_patterns() {
+        let invalid = |toml: &str| {
+            let config: Config = toml::from_str(toml).unwrap();
+            config.validate().unwrap_err().to_string()
+        };
+
+        let error = invalid("[analysis]\nignorePatterns = [\"(\"]");
+        assert!(error.contains("analysis.ignorePatterns"), "{}", error);
+
+        let error = invalid(
+            r#"
+            [[overrides]]
+            files = ["src/**"]
+
+            [[overrides]]
+            files = ["src/[legacy/**"]
+            "#,
+        );
+        assert!(error.contains("overrides[1].files"), "{}", error);
+
+        let error = invalid(
+            r#"
+            [[overrides]]
+            files = ["src/**"]
+            analysis = { ignorePatterns = ["("] }
+            "#,
+        );
+        assert!(
+            error.contains("overrides[0].analysis.ignorePatterns"),
+            "{}",
+            error
+        );
+
+        let error = invalid("[confidence]\nmediumThreshold = 90\nhighThreshold = 70");
+        assert!(error.contains("confidence.mediumThreshold"), "{}", error);
+    }
+
+    #[test]
+    fn test_file_configs_share_resolved_configs() {
+        let mut config: Config = toml::from_str(
+            r#"
+            [[overrides]]
+            files = ["src/legacy/**"]
+            entry.autoDetect = false
+            "#,
+        )
+        .unwrap();
+        config.base_dir = Some(PathBuf::from("/repo"));
+        let mut configs = FileConfigs::new(&config);
+
+        assert!(
+            !configs
+                .get(Path::new("/repo/src/legacy/a.ts"))
+                .entry
+                .auto_detect
+        );
+        assert!(
+            !configs
+                .get(Path::new("/repo/src/legacy/b.ts"))
+                .entry
+                .auto_detect
+        );
+        assert!(configs.get(Path::new("/repo/src/c.ts")).entry.auto_detect);
+        assert_eq!(configs.resolved.len(), 2);
     }
 
     #[test]
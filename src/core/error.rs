@@ -21,7 +21,10 @@ pub enum DddError {
     },
 
     #[error("Failed to resolve import: {specifier} from {from_file}")]
-    #[diagnostic(code(ddd::resolve_error), help("Check that the module exists and the path is correct"))]
+    #[diagnostic(
+        code(ddd::resolve_error),
+        help("Check that the module exists and the path is correct")
+    )]
     ResolveError {
         specifier: String,
         from_file: PathBuf,
@@ -39,6 +42,14 @@ pub enum DddError {
         source: std::io::Error,
     },
 
+    #[error("Failed to write output: {path}")]
+    #[diagnostic(code(ddd::output_error))]
+    OutputError {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+
     #[error("Failed to read directory: {path}")]
     #[diagnostic(code(ddd::io_error))]
     DirectoryError {
@@ -48,7 +59,10 @@ pub enum DddError {
     },
 
     #[error("No TypeScript/JavaScript files found in: {path}")]
-    #[diagnostic(code(ddd::no_files), help("Specify a directory containing .ts, .tsx, .js, or .jsx files"))]
+    #[diagnostic(
+        code(ddd::no_files),
+        help("Specify a directory containing .ts, .tsx, .js, or .jsx files")
+    )]
     NoFilesFound { path: PathBuf },
 
     #[error("Invalid glob pattern: {pattern}")]
@@ -65,7 +79,52 @@ pub enum DddError {
 
     #[error("Plugin error: {plugin_name} - {message}")]
     #[diagnostic(code(ddd::plugin_error))]
-    PluginError { plugin_name: String, message: String },
+    PluginError {
+        plugin_name: String,
+        message: String,
+    },
+
+    #[error("Invalid coverage file {path}: {message}")]
+    #[diagnostic(
+        code(ddd::invalid_coverage),
+        help("Supported formats are Istanbul coverage-final.json, lcov and V8 JSON coverage")
+    )]
+    InvalidCoverage { path: PathBuf, message: String },
+
+    #[error("Invalid runtime trace {path}: {message}")]
+    #[diagnostic(
+        code(ddd::invalid_trace),
+        help("Each line must be a JSON object with \"file\", one of \"line\", \"offset\" or \"name\", and optionally \"hits\"")
+    )]
+    InvalidTrace { path: PathBuf, message: String },
+
+    #[error("Cannot read git history in {path}: {message}")]
+    #[diagnostic(
+        code(ddd::git_history),
+        help("Run ddd inside a git repository, or drop --git-history, --min-age and [history] settings")
+    )]
+    GitHistory { path: PathBuf, message: String },
+
+    #[error("The {format} format is not available for `ddd {command}`")]
+    #[diagnostic(
+        code(ddd::unsupported_format),
+        help("Use --format table, json or compact")
+    )]
+    UnsupportedFormat { format: String, command: String },
+
+    #[error("Symbol not found: {spec}")]
+    #[diagnostic(
+        code(ddd::symbol_not_found),
+        help("Use the form <file>:<name>, e.g. src/utils.ts:formatDate")
+    )]
+    SymbolNotFound { spec: String },
+
+    #[error("No symbols found in: {path}")]
+    #[diagnostic(
+        code(ddd::no_symbols),
+        help("Paths are relative to the analyzed directory, e.g. src/utils.ts")
+    )]
+    NoSymbolsInPath { path: PathBuf },
 }
 
 /// Details about a parse error.
@@ -119,6 +178,11 @@ impl DddError {
         Self::IoError { path, source }
     }
 
+    /// Create an IO error for writing output.
+    pub fn output_error(path: PathBuf, source: std::io::Error) -> Self {
+        Self::OutputError { path, source }
+    }
+
     /// Create an IO error for directory operations.
     pub fn directory_error(path: PathBuf, source: std::io::Error) -> Self {
         Self::DirectoryError { path, source }
@@ -143,6 +207,48 @@ impl DddError {
             message: message.into(),
         }
     }
+
+    /// Create an invalid coverage error.
+    pub fn invalid_coverage(path: PathBuf, message: impl Into<String>) -> Self {
+        Self::InvalidCoverage {
+            path,
+            message: message.into(),
+        }
+    }
+
+    /// Create an invalid runtime trace error.
+    pub fn invalid_trace(path: PathBuf, message: impl Into<String>) -> Self {
+        Self::InvalidTrace {
+            path,
+            message: message.into(),
+        }
+    }
+
+    /// Create a git history error.
+    pub fn git_history(path: PathBuf, message: impl Into<String>) -> Self {
+        Self::GitHistory {
+            path,
+            message: message.into(),
+        }
+    }
+
+    /// Create an unsupported output format error.
+    pub fn unsupported_format(format: impl Into<String>, command: impl Into<String>) -> Self {
+        Self::UnsupportedFormat {
+            format: format.into(),
+            command: command.into(),
+        }
+    }
+
+    /// Create a symbol not found error.
+    pub fn symbol_not_found(spec: impl Into<String>) -> Self {
+        Self::SymbolNotFound { spec: spec.into() }
+    }
+
+    /// Create an error for a path filter that matches no symbols.
+    pub fn no_symbols_in_path(path: PathBuf) -> Self {
+        Self::NoSymbolsInPath { path }
+    }
 }
 
 /// Extension trait for converting std::io::Result to DddError.
@@ -167,12 +273,7 @@ mod tests {
 
     #[test]
     fn test_error_display() {
-        let err = DddError::parse_error(
-            PathBuf::from("test.ts"),
-            "Unexpected token",
-            10,
-            5,
-        );
+        let err = DddError::parse_error(PathBuf::from("test.ts"), "Unexpected token", 10, 5);
         assert!(err.to_string().contains("test.ts"));
     }
 
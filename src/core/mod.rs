@@ -1,7 +1,9 @@
 //! Core types and error definitions.
 
 pub mod error;
+pub mod paths;
 pub mod types;
 
 pub use error::*;
+pub use paths::*;
 pub use types::*;
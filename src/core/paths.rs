@@ -0,0 +1,24 @@
+//! Path helpers shared by analysis messages and output.
+
+use std::path::{Component, Path, PathBuf};
+
+/// Drop `.` components, so `root/./src/a.ts` and `root/src/a.ts` compare equal.
+pub fn normalize_path(path: &Path) -> PathBuf {
+    path.components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .collect()
+}
+
+/// Get a path relative to `root` when it is inside it, with `/` separators.
+pub fn relative_to(root: Option<&Path>, path: &Path) -> String {
+    let path = normalize_path(path);
+    let relative = root
+        .and_then(|root| path.strip_prefix(normalize_path(root)).ok())
+        .unwrap_or(&path);
+
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
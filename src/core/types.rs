@@ -16,7 +16,19 @@ impl SymbolId {
 }
 
 /// The kind of symbol being tracked.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    serde::Serialize,
+    serde::Deserialize,
+    schemars::JsonSchema,
+)]
+#[serde(rename_all = "camelCase")]
+#[schemars(rename = "symbolKind")]
 pub enum SymbolKind {
     Function,
     ArrowFunction,
@@ -33,6 +45,24 @@ pub enum SymbolKind {
 }
 
 impl SymbolKind {
+    /// Get a stable identifier for the kind of symbol, as serialized.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SymbolKind::Function => "function",
+            SymbolKind::ArrowFunction => "arrowFunction",
+            SymbolKind::Class => "class",
+            SymbolKind::Method => "method",
+            SymbolKind::Variable => "variable",
+            SymbolKind::Constant => "constant",
+            SymbolKind::Type => "type",
+            SymbolKind::Interface => "interface",
+            SymbolKind::Enum => "enum",
+            SymbolKind::EnumMember => "enumMember",
+            SymbolKind::Namespace => "namespace",
+            SymbolKind::Module => "module",
+        }
+    }
+
     /// Returns true if this symbol kind can have side effects when defined.
     pub fn can_have_side_effects(&self) -> bool {
         matches!(
@@ -50,27 +80,52 @@ pub struct Location {
     pub end_offset: u32,
     pub line: u32,
     pub column: u32,
+    /// Line containing `end_offset`.
+    pub end_line: u32,
+    /// Start of the whole declaration, including keywords such as `export`
+    /// before the name. Equal to `start_offset` unless set.
+    pub declaration_start: u32,
 }
 
 impl Location {
-    pub fn new(file_path: PathBuf, start_offset: u32, end_offset: u32, line: u32, column: u32) -> Self {
+    pub fn new(
+        file_path: PathBuf,
+        start_offset: u32,
+        end_offset: u32,
+        line: u32,
+        column: u32,
+    ) -> Self {
         Self {
             file_path,
             start_offset,
             end_offset,
             line,
             column,
+            end_line: line,
+            declaration_start: start_offset,
         }
     }
 
+    /// Set the line containing `end_offset`.
+    pub fn with_end_line(mut self, end_line: u32) -> Self {
+        self.end_line = end_line;
+        self
+    }
+
+    /// Set the start of the whole declaration.
+    pub fn with_declaration_start(mut self, declaration_start: u32) -> Self {
+        self.declaration_start = declaration_start;
+        self
+    }
+
+    /// Number of lines spanned by this location.
+    pub fn line_count(&self) -> u32 {
+        self.end_line.saturating_sub(self.line) + 1
+    }
+
     /// Format as "path:line:column" for display.
     pub fn display(&self) -> String {
-        format!(
-            "{}:{}:{}",
-            self.file_path.display(),
-            self.line,
-            self.column
-        )
+        format!("{}:{}:{}", self.file_path.display(), self.line, self.column)
     }
 }
 
@@ -89,6 +144,10 @@ pub struct TrackedSymbol {
     pub has_decorators: bool,
     /// Whether this symbol's definition has observable side effects.
     pub has_side_effects: bool,
+    /// Whether a `ddd-ignore` comment suppresses this symbol.
+    pub suppressed: bool,
+    /// API tags from the symbol's leading JSDoc comment.
+    pub doc_tags: DocTags,
     /// The file ID this symbol belongs to.
     pub file_id: FileId,
 }
@@ -111,13 +170,32 @@ impl TrackedSymbol {
             is_entry_point: false,
             has_decorators: false,
             has_side_effects: false,
+            suppressed: false,
+            doc_tags: DocTags::default(),
             file_id,
         }
     }
+
+    /// Whether this symbol is exported as public API; `@internal` exports are not.
+    pub fn is_public_export(&self) -> bool {
+        self.exported && !self.doc_tags.internal
+    }
+}
+
+/// API tags read from a leading JSDoc comment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DocTags {
+    /// The `public` or `api` tag, if either is present.
+    pub public: Option<String>,
+    /// Tagged `@internal`.
+    pub internal: bool,
+    /// Text of the `@deprecated` tag, empty if it has none.
+    pub deprecated: Option<String>,
 }
 
 /// The kind of reference between symbols.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
 pub enum ReferenceKind {
     /// Direct function call: `foo()`.
     Call,
@@ -143,6 +221,25 @@ pub enum ReferenceKind {
     Decorator,
 }
 
+impl ReferenceKind {
+    /// Get a human-readable label.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ReferenceKind::Call => "call",
+            ReferenceKind::Instantiation => "new",
+            ReferenceKind::PropertyAccess => "property access",
+            ReferenceKind::TypeReference => "type reference",
+            ReferenceKind::Import => "import",
+            ReferenceKind::Export => "export",
+            ReferenceKind::ReExport => "re-export",
+            ReferenceKind::JsxElement => "jsx",
+            ReferenceKind::Extends => "extends",
+            ReferenceKind::Implements => "implements",
+            ReferenceKind::Decorator => "decorator",
+        }
+    }
+}
+
 /// A reference from one symbol to another.
 #[derive(Debug, Clone)]
 pub struct SymbolReference {
@@ -196,6 +293,142 @@ pub struct FileInfo {
     pub has_dynamic_eval: bool,
     /// Symbols defined in this file.
     pub symbols: Vec<SymbolId>,
+    /// Inline `ddd-ignore` comments in this file.
+    pub suppressions: Vec<Suppression>,
+}
+
+/// An import of one file by another.
+#[derive(Debug, Clone)]
+pub struct ImportEdge {
+    pub from: FileId,
+    pub to: FileId,
+    /// The import or re-export statement.
+    pub location: Location,
+    /// Loaded when the importing file is evaluated, rather than by `import()`
+    /// or only for types.
+    pub eager: bool,
+}
+
+/// An inline comment that suppresses dead code findings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    pub kind: SuppressionKind,
+    /// Location of the comment.
+    pub location: Location,
+    /// Text following the directive.
+    pub reason: Option<String>,
+    /// Symbols the comment covers.
+    pub symbols: Vec<SymbolId>,
+}
+
+/// What an inline suppression comment covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionKind {
+    /// `// ddd-ignore-next-line`: symbols declared on the following line.
+    NextLine,
+    /// `// ddd-ignore <reason>`: the following declaration and everything inside it.
+    Declaration,
+    /// `/* ddd-ignore-file */`: every symbol in the file.
+    File,
+}
+
+impl SuppressionKind {
+    /// Get the comment directive.
+    pub fn directive(&self) -> &'static str {
+        match self {
+            SuppressionKind::NextLine => "ddd-ignore-next-line",
+            SuppressionKind::Declaration => "ddd-ignore",
+            SuppressionKind::File => "ddd-ignore-file",
+        }
+    }
+}
+
+/// The rule that made a symbol an entry point.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryPointSource {
+    /// Exported from a file listed in `entry.files`.
+    ConfigFile { path: PathBuf },
+    /// Exported from a file matching an `entry.patterns` glob.
+    ConfigPattern { pattern: String },
+    /// Named in `entry.exports`.
+    ConfigExport { name: String },
+    /// Exported from an entry file declared in a package.json field
+    /// (`main`, `bin`, `exports`, ...).
+    PackageJson { field: String, path: PathBuf },
+    /// Matched by a framework plugin's entry pattern.
+    Framework { plugin: String, pattern: String },
+    /// Referenced by a top-level statement with side effects.
+    SideEffect { location: Location },
+    /// Observed executing in a runtime trace.
+    RuntimeTrace { hits: u64 },
+    /// Tagged `@public` or `@api` in its JSDoc comment.
+    DocTag { tag: String },
+}
+
+impl EntryPointSource {
+    /// Get a stable identifier for the kind of source.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            EntryPointSource::ConfigFile { .. } => "config-file",
+            EntryPointSource::ConfigPattern { .. } => "config-pattern",
+            EntryPointSource::ConfigExport { .. } => "config-export",
+            EntryPointSource::PackageJson { .. } => "package-json",
+            EntryPointSource::Framework { .. } => "framework",
+            EntryPointSource::SideEffect { .. } => "side-effect",
+            EntryPointSource::RuntimeTrace { .. } => "runtime-trace",
+            EntryPointSource::DocTag { .. } => "jsdoc",
+        }
+    }
+
+    pub fn description(&self) -> String {
+        match self {
+            EntryPointSource::ConfigFile { path } => {
+                format!("config entry file {}", path.display())
+            }
+            EntryPointSource::ConfigPattern { pattern } => {
+                format!("config entry pattern `{}`", pattern)
+            }
+            EntryPointSource::ConfigExport { name } => {
+                format!("config entry export `{}`", name)
+            }
+            EntryPointSource::PackageJson { field, path } => {
+                format!("package.json `{}` ({})", field, path.display())
+            }
+            EntryPointSource::Framework { plugin, pattern } => {
+                format!("{} plugin pattern `{}`", plugin, pattern)
+            }
+            EntryPointSource::SideEffect { location } => {
+                format!(
+                    "side-effecting statement at {}:{}",
+                    location.file_path.display(),
+                    location.line
+                )
+            }
+            EntryPointSource::RuntimeTrace { hits } => {
+                format!("executed {} times in runtime trace", hits)
+            }
+            EntryPointSource::DocTag { tag } => format!("`@{}` JSDoc tag", tag),
+        }
+    }
+}
+
+/// A top-level statement that runs when its module is loaded.
+#[derive(Debug, Clone)]
+pub struct SideEffectRoot {
+    pub file_id: FileId,
+    pub location: Location,
+    /// Symbols the statement keeps alive.
+    pub references: Vec<SymbolId>,
+    /// Whether the statement has side effects syntactically. Other statements
+    /// are roots only in files package.json declares side-effectful.
+    pub detected: bool,
+}
+
+/// An entry point together with the rules that marked it.
+#[derive(Debug, Clone)]
+pub struct EntryPoint {
+    pub symbol: TrackedSymbol,
+    pub sources: Vec<EntryPointSource>,
 }
 
 /// Dynamic pattern that reduces confidence in analysis.
@@ -219,7 +452,7 @@ pub enum DynamicPatternKind {
     Reflect,
     /// `Object.keys/values/entries` iteration.
     ObjectIteration,
-    /// String literal property access that might match symbols.
+    /// String literal that names symbols, e.g. `handlers['onSave']`.
     StringPropertyAccess,
     /// `require()` with non-literal argument.
     DynamicRequire,
@@ -236,10 +469,20 @@ pub struct CallGraph {
     pub references: Vec<SymbolReference>,
     /// Entry point symbols that are always considered live.
     pub entry_points: HashSet<SymbolId>,
+    /// Rules that marked each entry point.
+    pub entry_point_sources: HashMap<SymbolId, Vec<EntryPointSource>>,
     /// Dynamic patterns detected during analysis.
     pub dynamic_patterns: Vec<DynamicPattern>,
+    /// Top-level side-effecting statements.
+    pub side_effect_roots: Vec<SideEffectRoot>,
     /// Files in the analysis.
     pub files: HashMap<FileId, FileInfo>,
+    /// File-level import graph: file -> files it imports.
+    pub file_imports: HashMap<FileId, Vec<FileId>>,
+    /// The import statements behind `file_imports`, one per pair of files.
+    pub import_edges: Vec<ImportEdge>,
+    /// Problems found while building the graph, reported with the analysis.
+    pub warnings: Vec<AnalysisWarning>,
     /// Reverse index: symbol -> symbols that reference it.
     pub incoming_refs: HashMap<SymbolId, Vec<SymbolId>>,
     /// Forward index: symbol -> symbols it references.
@@ -262,8 +505,13 @@ impl CallGraph {
             symbols: HashMap::new(),
             references: Vec::new(),
             entry_points: HashSet::new(),
+            entry_point_sources: HashMap::new(),
             dynamic_patterns: Vec::new(),
+            side_effect_roots: Vec::new(),
             files: HashMap::new(),
+            file_imports: HashMap::new(),
+            import_edges: Vec::new(),
+            warnings: Vec::new(),
             incoming_refs: HashMap::new(),
             outgoing_refs: HashMap::new(),
             next_symbol_id: 0,
@@ -299,14 +547,8 @@ impl CallGraph {
         let from_id = reference.from_id;
         let to_id = reference.to_id;
 
-        self.incoming_refs
-            .entry(to_id)
-            .or_default()
-            .push(from_id);
-        self.outgoing_refs
-            .entry(from_id)
-            .or_default()
-            .push(to_id);
+        self.incoming_refs.entry(to_id).or_default().push(from_id);
+        self.outgoing_refs.entry(from_id).or_default().push(to_id);
 
         self.references.push(reference);
     }
@@ -316,14 +558,56 @@ impl CallGraph {
         self.files.insert(file.id, file);
     }
 
-    /// Mark a symbol as an entry point.
-    pub fn mark_entry_point(&mut self, id: SymbolId) {
+    /// Record that one file imports another.
+    pub fn add_file_import(&mut self, from: FileId, to: FileId) {
+        let imports = self.file_imports.entry(from).or_default();
+        if !imports.contains(&to) {
+            imports.push(to);
+        }
+    }
+
+    /// Record an import statement from one file to another.
+    pub fn add_import_edge(&mut self, edge: ImportEdge) {
+        self.add_file_import(edge.from, edge.to);
+        self.import_edges.push(edge);
+    }
+
+    /// Get the files that the given file imports.
+    pub fn get_file_imports(&self, file_id: FileId) -> &[FileId] {
+        self.file_imports
+            .get(&file_id)
+            .map_or(&[], |v| v.as_slice())
+    }
+
+    /// Add a dynamic pattern that lowers confidence for the symbols it affects.
+    pub fn add_dynamic_pattern(&mut self, pattern: DynamicPattern) {
+        self.dynamic_patterns.push(pattern);
+    }
+
+    /// Add a top-level side-effecting statement.
+    pub fn add_side_effect_root(&mut self, root: SideEffectRoot) {
+        self.side_effect_roots.push(root);
+    }
+
+    /// Mark a symbol as an entry point, recording the rule responsible.
+    pub fn mark_entry_point(&mut self, id: SymbolId, source: EntryPointSource) {
         self.entry_points.insert(id);
+        let sources = self.entry_point_sources.entry(id).or_default();
+        if !sources.contains(&source) {
+            sources.push(source);
+        }
         if let Some(symbol) = self.symbols.get_mut(&id) {
             symbol.is_entry_point = true;
         }
     }
 
+    /// Get the rules that made the given symbol an entry point.
+    pub fn get_entry_point_sources(&self, id: SymbolId) -> &[EntryPointSource] {
+        self.entry_point_sources
+            .get(&id)
+            .map_or(&[], |v| v.as_slice())
+    }
+
     /// Find an exported symbol by name in a file.
     pub fn find_export(&self, file_id: FileId, name: &str) -> Option<SymbolId> {
         self.files.get(&file_id).and_then(|file| {
@@ -348,6 +632,31 @@ impl CallGraph {
         self.outgoing_refs.get(&id).map_or(&[], |v| v.as_slice())
     }
 
+    /// Collect all entry points with their sources, sorted by file and line.
+    pub fn collect_entry_points(&self) -> Vec<EntryPoint> {
+        let mut entry_points: Vec<EntryPoint> = self
+            .entry_points
+            .iter()
+            .filter_map(|id| {
+                let symbol = self.symbols.get(id)?;
+                Some(EntryPoint {
+                    symbol: symbol.clone(),
+                    sources: self.get_entry_point_sources(*id).to_vec(),
+                })
+            })
+            .collect();
+
+        entry_points.sort_by(|a, b| {
+            a.symbol
+                .location
+                .file_path
+                .cmp(&b.symbol.location.file_path)
+                .then(a.symbol.location.line.cmp(&b.symbol.location.line))
+        });
+
+        entry_points
+    }
+
     /// Get total symbol count.
     pub fn symbol_count(&self) -> usize {
         self.symbols.len()
@@ -360,7 +669,11 @@ impl CallGraph {
 }
 
 /// Confidence level for dead code detection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, schemars::JsonSchema,
+)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "confidence")]
 pub enum Confidence {
     /// 0-49: Likely false positive, many dynamic patterns detected.
     Low,
@@ -403,9 +716,7 @@ impl Confidence {
 #[derive(Debug, Clone)]
 pub enum DeadnessReason {
     /// No references found from entry points.
-    Unreachable {
-        explanation: String,
-    },
+    Unreachable { explanation: String },
     /// Dead because all callers are dead.
     Transitive {
         /// Chain of dead symbols leading to this one.
@@ -418,6 +729,29 @@ pub enum DeadnessReason {
 }
 
 impl DeadnessReason {
+    /// Number of kinds of reason.
+    pub const COUNT: usize = 4;
+
+    /// Position of the kind of reason, from 0 to `COUNT - 1`.
+    pub fn index(&self) -> usize {
+        match self {
+            DeadnessReason::Unreachable { .. } => 0,
+            DeadnessReason::Transitive { .. } => 1,
+            DeadnessReason::UnusedExport => 2,
+            DeadnessReason::UnusedType => 3,
+        }
+    }
+
+    /// Short identifier for the kind of reason, e.g. `unused-export`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DeadnessReason::Unreachable { .. } => "unreachable",
+            DeadnessReason::Transitive { .. } => "transitive",
+            DeadnessReason::UnusedExport => "unused-export",
+            DeadnessReason::UnusedType => "unused-type",
+        }
+    }
+
     pub fn description(&self) -> String {
         match self {
             DeadnessReason::Unreachable { explanation } => explanation.clone(),
@@ -430,6 +764,17 @@ impl DeadnessReason {
     }
 }
 
+/// Code freed by removing a root dead symbol.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemovalImpact {
+    /// Dead symbols kept in place only by this one.
+    pub dominated: Vec<SymbolId>,
+    /// Lines freed, including the symbol itself.
+    pub lines: u32,
+    /// Bytes freed, including the symbol itself.
+    pub bytes: u32,
+}
+
 /// A symbol that has been determined to be dead.
 #[derive(Debug, Clone)]
 pub struct DeadSymbol {
@@ -439,24 +784,38 @@ pub struct DeadSymbol {
     pub confidence: Confidence,
     /// Numeric confidence score (0-100).
     pub confidence_score: u8,
+    /// Score before confidence rules were applied.
+    pub base_confidence: u8,
+    /// Confidence rules applied to the base score, in order.
+    pub confidence_factors: Vec<ConfidenceFactor>,
     /// Why this symbol is dead.
     pub reason: DeadnessReason,
     /// If transitively dead, which dead symbol caused this.
     pub killed_by: Option<SymbolId>,
+    /// For root dead symbols, the code freed by removing them.
+    pub impact: Option<RemovalImpact>,
+    /// Runtime evidence from coverage data, if any was provided.
+    pub execution: Option<Execution>,
+    /// Production evidence from a runtime trace, if any was provided.
+    pub trace: Option<Execution>,
+    /// When the symbol's lines last changed, if git history was read.
+    pub history: Option<GitHistory>,
 }
 
 impl DeadSymbol {
-    pub fn new(
-        symbol: TrackedSymbol,
-        confidence_score: u8,
-        reason: DeadnessReason,
-    ) -> Self {
+    pub fn new(symbol: TrackedSymbol, confidence_score: u8, reason: DeadnessReason) -> Self {
         Self {
             symbol,
             confidence: Confidence::from_score(confidence_score),
             confidence_score,
+            base_confidence: confidence_score,
+            confidence_factors: Vec::new(),
             reason,
             killed_by: None,
+            impact: None,
+            execution: None,
+            trace: None,
+            history: None,
         }
     }
 
@@ -471,17 +830,110 @@ impl DeadSymbol {
             symbol,
             confidence: Confidence::from_score(confidence_score),
             confidence_score,
+            base_confidence: confidence_score,
+            confidence_factors: Vec::new(),
             reason: DeadnessReason::Transitive { chain },
             killed_by: Some(killed_by),
+            impact: None,
+            execution: None,
+            trace: None,
+            history: None,
+        }
+    }
+}
+
+/// Runtime evidence about whether a symbol's code ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+#[serde(tag = "status", rename_all = "camelCase")]
+#[schemars(rename = "execution")]
+pub enum Execution {
+    /// The symbol's code ran this many times.
+    Executed { hits: u64 },
+    /// Coverage data covers the symbol, and it never ran.
+    NeverExecuted,
+}
+
+impl Execution {
+    /// Get the number of recorded executions.
+    pub fn hits(&self) -> u64 {
+        match self {
+            Execution::Executed { hits } => *hits,
+            Execution::NeverExecuted => 0,
+        }
+    }
+}
+
+/// Git history of a dead symbol's lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GitHistory {
+    /// Unix timestamp of the most recent change.
+    pub last_modified: u64,
+    /// Whole days since the most recent change.
+    pub age_days: u64,
+    /// Distinct authors of committed lines.
+    pub authors: usize,
+    /// Whether the code is new, actively changing, or stale.
+    pub freshness: Freshness,
+}
+
+/// How recently a symbol's code changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "lowercase")]
+#[schemars(rename = "freshness")]
+pub enum Freshness {
+    /// Recently added or not yet committed; possibly not wired up yet.
+    New,
+    /// Changed recently enough to be in active development.
+    Active,
+    /// Untouched for a long time.
+    Stale,
+}
+
+impl Freshness {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Freshness::New => "new",
+            Freshness::Active => "active",
+            Freshness::Stale => "stale",
         }
     }
 }
 
+/// A confidence rule that applied to a dead symbol.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfidenceFactor {
+    /// Stable rule identifier, matching its `[confidence.weights]` key.
+    pub rule: String,
+    pub description: String,
+    /// Score adjustment.
+    pub delta: i32,
+}
+
+impl ConfidenceFactor {
+    pub fn new(rule: impl Into<String>, description: impl Into<String>, delta: i32) -> Self {
+        Self {
+            rule: rule.into(),
+            description: description.into(),
+            delta,
+        }
+    }
+}
+
+impl std::fmt::Display for ConfidenceFactor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {:+}", self.description, self.delta)
+    }
+}
+
 /// Result of dead code analysis.
 #[derive(Debug)]
 pub struct AnalysisResult {
     /// Dead symbols found.
     pub dead_symbols: Vec<DeadSymbol>,
+    /// Entry points the analysis started from.
+    pub entry_points: Vec<EntryPoint>,
+    /// Statically alive symbols that a runtime trace never saw execute.
+    pub never_executed: Vec<TrackedSymbol>,
     /// Total symbols analyzed.
     pub total_symbols: usize,
     /// Total files analyzed.
@@ -528,7 +980,9 @@ pub struct AnalysisWarning {
 }
 
 /// Types of analysis warnings.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, schemars::JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+#[schemars(rename = "warningKind")]
 pub enum WarningKind {
     /// eval() or similar detected.
     DynamicCodeExecution,
@@ -540,6 +994,25 @@ pub enum WarningKind {
     CircularDependency,
     /// Configuration issue.
     ConfigWarning,
+    /// Statically dead code that runtime data shows was executed.
+    ExecutedDeadCode,
+    /// A `ddd-ignore` comment that suppresses nothing dead.
+    UnusedSuppression,
+}
+
+impl WarningKind {
+    /// Get a stable identifier for the kind of warning.
+    pub fn name(&self) -> &'static str {
+        match self {
+            WarningKind::DynamicCodeExecution => "dynamic-code-execution",
+            WarningKind::ParseError => "parse-error",
+            WarningKind::UnresolvedImport => "unresolved-import",
+            WarningKind::CircularDependency => "circular-dependency",
+            WarningKind::ConfigWarning => "config-warning",
+            WarningKind::ExecutedDeadCode => "executed-dead-code",
+            WarningKind::UnusedSuppression => "unused-suppression",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -556,6 +1029,26 @@ mod tests {
         assert_eq!(Confidence::from_score(100), Confidence::High);
     }
 
+    #[test]
+    fn test_symbol_kind_names_match_serialization() {
+        for kind in [
+            SymbolKind::Function,
+            SymbolKind::ArrowFunction,
+            SymbolKind::Class,
+            SymbolKind::Method,
+            SymbolKind::Variable,
+            SymbolKind::Constant,
+            SymbolKind::Type,
+            SymbolKind::Interface,
+            SymbolKind::Enum,
+            SymbolKind::EnumMember,
+            SymbolKind::Namespace,
+            SymbolKind::Module,
+        ] {
+            assert_eq!(serde_json::to_value(kind).unwrap(), kind.as_str());
+        }
+    }
+
     #[test]
     fn test_call_graph_allocation() {
         let mut graph = CallGraph::new();
@@ -567,6 +1060,40 @@ mod tests {
         assert_eq!(id2.0, 1);
     }
 
+    #[test]
+    fn test_entry_point_sources_recorded() {
+        let mut graph = CallGraph::new();
+        let file_id = graph.alloc_file_id();
+        let sym = graph.alloc_symbol_id();
+
+        graph.add_symbol(TrackedSymbol::new(
+            sym,
+            "main".to_string(),
+            SymbolKind::Function,
+            Location::new(PathBuf::from("index.ts"), 0, 10, 1, 1),
+            file_id,
+        ));
+
+        let source = EntryPointSource::ConfigExport {
+            name: "main".to_string(),
+        };
+        graph.mark_entry_point(sym, source.clone());
+        graph.mark_entry_point(sym, source.clone());
+        graph.mark_entry_point(
+            sym,
+            EntryPointSource::ConfigPattern {
+                pattern: "*.ts".to_string(),
+            },
+        );
+
+        assert!(graph.symbols[&sym].is_entry_point);
+        assert_eq!(graph.get_entry_point_sources(sym).len(), 2);
+
+        let entries = graph.collect_entry_points();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].sources[0], source);
+    }
+
     #[test]
     fn test_call_graph_references() {
         let mut graph = CallGraph::new();
@@ -575,13 +1102,7 @@ mod tests {
         let sym1 = graph.alloc_symbol_id();
         let sym2 = graph.alloc_symbol_id();
 
-        let location = Location::new(
-            PathBuf::from("test.ts"),
-            0,
-            10,
-            1,
-            1,
-        );
+        let location = Location::new(PathBuf::from("test.ts"), 0, 10, 1, 1);
 
         graph.add_symbol(TrackedSymbol::new(
             sym1,
@@ -52,11 +52,18 @@ fn run() -> Result<i32> {
             commands::run_init(&args, &path)?;
             Ok(0)
         }
-        Commands::Analyze(args) => {
-            Ok(commands::run_analyze(&args, &path, &config, cli.verbose)?)
-        }
-        Commands::Watch(args) => {
-            Ok(commands::run_watch(&args, &path, &config)?)
+        Commands::Analyze(args) => Ok(commands::run_analyze(&args, &path, &config, cli.verbose)?),
+        Commands::Watch(args) => Ok(commands::run_watch(&args, &path, &config)?),
+        Commands::Why(args) => Ok(commands::run_why(&args, &path, &config)?),
+        Commands::Entries(args) => Ok(commands::run_entries(&args, &path, &config)?),
+        Commands::Explain(args) => Ok(commands::run_explain(&args, &path, &config)?),
+        Commands::Deprecated(args) => Ok(commands::run_deprecated(&args, &path, &config)?),
+        Commands::Config(args) => Ok(commands::run_config(&args, &path, &config)?),
+        Commands::Graph(args) => Ok(commands::run_graph(&args, &path, &config)?),
+        Commands::Cycles(args) => Ok(commands::run_cycles(&args, &path, &config)?),
+        Commands::Schema => {
+            commands::run_schema()?;
+            Ok(0)
         }
     }
 }